@@ -6,6 +6,7 @@ pub mod errors;
 pub mod mongodb;
 pub mod mysql;
 pub mod postgres;
+pub mod sqlite;
 pub mod utils;
 
 #[derive(Debug, PartialOrd, PartialEq, Ord, Eq)]
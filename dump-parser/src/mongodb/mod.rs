@@ -1,8 +1,7 @@
 use bson::Document;
 use crc::crc64;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::io::{BufReader, Error, ErrorKind, Read};
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read};
 
 /// Four bytes that are always present at the beginning of the archive.
 const MAGIC_BYTES: [u8; 4] = [0x6d, 0xe2, 0x99, 0x81];
@@ -48,9 +47,14 @@ pub struct Namespace {
 
 // Prefixes are "<db_name>.<collection_name>"
 pub type Prefix = String;
-pub type Collection = Vec<Document>;
-pub type PrefixedCollections = HashMap<Prefix, Collection>;
-/// # Archive
+
+impl Metadata {
+    pub fn prefix(&self) -> Prefix {
+        format!("{}.{}", self.db, self.collection)
+    }
+}
+
+/// # ArchiveReader
 /// reference: https://github.com/mongodb/mongo-tools-common/blob/v4.2/archive/archive.go
 ///
 /// mongodump/mongorestore "archives" are binary files with the following structure:
@@ -89,21 +93,19 @@ pub type PrefixedCollections = HashMap<Prefix, Collection>;
 /// // |    seperator bytes    |
 /// // +-----------------------+
 /// ```
-#[derive(Debug, Clone)]
-pub struct Archive {
+///
+/// The header and the metadata documents are read on creation,
+/// then the documents are read one by one - the whole archive is never loaded in memory.
+pub struct ArchiveReader<R: Read> {
+    reader: BufReader<R>,
     header: Header,
     metadata_docs: Vec<Metadata>,
-    namespace_docs: Vec<Namespace>,
-    prefixed_collections: PrefixedCollections, // prefix is <db_name>.<collection_name>
 }
-impl Archive {
-    pub fn from_reader<R: Read>(mut reader: BufReader<R>) -> Result<Archive, Error> {
+
+impl<R: Read> ArchiveReader<R> {
+    pub fn from_reader(mut reader: BufReader<R>) -> Result<ArchiveReader<R>, Error> {
         let mut buf: [u8; 4] = [0; 4];
-        let mut num_blocks = 0;
-        let mut vec_eofs = Vec::with_capacity(num_blocks * 2);
         let mut metadata_docs = vec![];
-        let mut namespace_docs = vec![];
-        let mut prefixed_collections = HashMap::new();
 
         // read magic bytes
         reader.read_exact(&mut buf)?;
@@ -122,57 +124,131 @@ impl Archive {
         while let Ok(collection_metadata_doc) = bson::from_reader(&mut reader) {
             let metadata_doc = Metadata::from(collection_metadata_doc);
             metadata_docs.push(metadata_doc);
-            num_blocks += 1;
         }
 
-        if num_blocks > 0 {
-            // read blocks
-            loop {
-                // read namespace header
-                let namespace_doc: Namespace = bson::from_reader(&mut reader).map_err(|err| {
+        Ok(ArchiveReader {
+            reader,
+            header,
+            metadata_docs,
+        })
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    pub fn metadata_docs(&self) -> &Vec<Metadata> {
+        &self.metadata_docs
+    }
+
+    /// read the blocks and callback the document function with each document and the metadata of its collection
+    pub fn read_documents<F>(&mut self, mut document: F) -> Result<(), Error>
+    where
+        F: FnMut(&Metadata, Document) -> Result<(), Error>,
+    {
+        let num_blocks = self.metadata_docs.len();
+        let mut num_eofs = 0;
+
+        // when we've seen as much EOFs as there are blocks, we're done.
+        while num_eofs < num_blocks {
+            // read namespace header
+            let namespace_doc: Namespace = bson::from_reader(&mut self.reader).map_err(|err| {
+                Error::new(
+                    ErrorKind::Other,
+                    format!("Error reading block header: {}", err),
+                )
+            })?;
+
+            if namespace_doc.eof {
+                // if this namespace is a footer (eof == true), that would mean the collection just ended
+                num_eofs += 1;
+            }
+
+            let metadata_doc = self
+                .metadata_docs
+                .iter()
+                .find(|metadata_doc| {
+                    metadata_doc.db == namespace_doc.db
+                        && metadata_doc.collection == namespace_doc.collection
+                })
+                .ok_or_else(|| {
                     Error::new(
-                        ErrorKind::Other,
-                        format!("Error reading block header: {}", err),
+                        ErrorKind::InvalidData,
+                        format!(
+                            "Missing metadata for collection {}.{}",
+                            namespace_doc.db, namespace_doc.collection
+                        ),
                     )
                 })?;
-                namespace_docs.push(namespace_doc.clone()); // TODO can we avoid cloning here?
-                vec_eofs.push(namespace_doc.eof);
-                // read block data
-                let mut collection_docs = vec![];
-                while let Ok(collection_doc) = Document::from_reader(&mut reader) {
-                    collection_docs.push(collection_doc.clone());
-                }
-                if !namespace_doc.eof {
-                    // if this namespace was a footer (eof == true), that would mean the collection just ended
-                    prefixed_collections.insert(
-                        format!("{}.{}", namespace_doc.db, namespace_doc.collection),
-                        collection_docs,
-                    );
-                }
-                // when we've seen as much EOFs as there are blocks, we're done.
-                if vec_eofs.iter().filter(|&&eof| eof).count() == num_blocks {
-                    break;
-                }
+
+            // read block data
+            while let Ok(collection_doc) = Document::from_reader(&mut self.reader) {
+                document(metadata_doc, collection_doc)?;
             }
         }
-        Ok(Archive {
+
+        Ok(())
+    }
+
+    /// get back the reader - positioned right after the archive
+    pub fn into_inner(self) -> BufReader<R> {
+        self.reader
+    }
+}
+
+/// # ArchiveWriter
+/// Write the documents of one or more collections as a mongodump/mongorestore archive (see `ArchiveReader`).
+///
+/// The documents are buffered as bytes until `flush` is called, then each collection is written in a single block,
+/// with its CRC64 checksum.
+pub struct ArchiveWriter {
+    header: Header,
+    collections: Vec<(Metadata, Vec<u8>)>,
+    size: usize,
+}
+
+impl ArchiveWriter {
+    pub fn new(header: Header) -> Self {
+        ArchiveWriter {
             header,
-            metadata_docs,
-            namespace_docs,
-            prefixed_collections,
-        })
+            collections: vec![],
+            size: 0,
+        }
     }
 
-    pub fn alter_docs<F>(&mut self, alter_fn: F)
-    where
-        F: FnOnce(&mut PrefixedCollections),
-    {
-        alter_fn(&mut self.prefixed_collections);
+    /// add the collection to the archive - even if it does not have any document
+    pub fn write_collection(&mut self, metadata_doc: &Metadata) {
+        let _ = self.collection_bytes(metadata_doc);
     }
 
-    pub fn into_bytes(mut self) -> Result<Vec<u8>, Error> {
-        let mut new_crc64_checksums: HashMap<Prefix, i64> = HashMap::new();
-        let mut buf = Vec::new();
+    pub fn write_document(&mut self, metadata_doc: &Metadata, document: &Document) -> Result<(), Error> {
+        let collection_bytes = self.collection_bytes(metadata_doc);
+        let len = collection_bytes.len();
+
+        document.to_writer(&mut *collection_bytes).map_err(|err| {
+            Error::new(
+                ErrorKind::Other,
+                format!("Error writing prefixed doc: {}", err),
+            )
+        })?;
+
+        let written_bytes = collection_bytes.len() - len;
+        self.size += written_bytes;
+        Ok(())
+    }
+
+    /// size in bytes of the buffered documents
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collections.is_empty()
+    }
+
+    /// write the archive with the buffered collections, and reset the buffer
+    pub fn flush(&mut self) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::with_capacity(self.size);
         buf.extend_from_slice(&MAGIC_BYTES);
         bson::to_document(&self.header)
             .unwrap()
@@ -183,7 +259,7 @@ impl Archive {
                     format!("Error writing namespace header: {}", err),
                 )
             })?;
-        for metadata_doc in &self.metadata_docs {
+        for (metadata_doc, _) in &self.collections {
             bson::to_document(&metadata_doc)
                 .unwrap()
                 .to_writer(&mut buf)
@@ -195,96 +271,210 @@ impl Archive {
                 })?;
         }
         buf.extend_from_slice(&SEPERATOR_BYTES);
-        for namespace_doc in &mut self.namespace_docs {
-            if !namespace_doc.eof {
-                // then this is a collection header
-                bson::to_document(&namespace_doc)
-                    .unwrap()
-                    .to_writer(&mut buf)
-                    .map_err(|err| {
-                        Error::new(
-                            ErrorKind::Other,
-                            format!("Error writing block header: {}", err),
-                        )
-                    })?;
-            }
-            let prefix = format!("{}.{}", namespace_doc.db, namespace_doc.collection);
-            if let Some(docs) = self.prefixed_collections.get(&prefix) {
-                let mut collection_bytestream: Vec<u8> = Vec::new();
-                for doc in docs {
-                    doc.to_writer(&mut collection_bytestream).map_err(|err| {
-                        Error::new(
-                            ErrorKind::Other,
-                            format!("Error writing prefixed doc: {}", err),
-                        )
-                    })?;
-                }
-                // revalidate crc64 checksum
-                let crc64_checksum = crc64::checksum_ecma(&collection_bytestream);
-                new_crc64_checksums.insert(prefix.clone(), crc64_checksum as i64);
-                buf.extend_from_slice(&collection_bytestream);
-                self.prefixed_collections.remove_entry(&prefix);
-            } else {
-                // then we've seen the prefix before, which means we've already written the docs
-                // this also means this namespace is a footer (eof == true).
-                // all that's left is to update the crc64 checksum
-                if let Some(&crc64_checksum) = new_crc64_checksums.get(&prefix) {
-                    namespace_doc.crc = crc64_checksum;
-                    bson::to_document(&namespace_doc)
-                        .unwrap()
-                        .to_writer(&mut buf)
-                        .map_err(|err| {
-                            Error::new(
-                                ErrorKind::Other,
-                                format!("Error writing block header: {}", err),
-                            )
-                        })?;
-                }
-            }
+        for (metadata_doc, collection_bytestream) in &self.collections {
+            let mut namespace_doc = Namespace {
+                db: metadata_doc.db.clone(),
+                collection: metadata_doc.collection.clone(),
+                eof: false,
+                crc: 0,
+            };
+
+            // collection header
+            write_namespace_doc(&namespace_doc, &mut buf)?;
+            buf.extend_from_slice(collection_bytestream);
+            buf.extend_from_slice(&SEPERATOR_BYTES);
+
+            // collection footer with the crc64 checksum of the collection documents
+            namespace_doc.eof = true;
+            namespace_doc.crc = crc64::checksum_ecma(collection_bytestream) as i64;
+            write_namespace_doc(&namespace_doc, &mut buf)?;
             buf.extend_from_slice(&SEPERATOR_BYTES);
         }
 
+        self.collections.clear();
+        self.size = 0;
+
         Ok(buf)
     }
+
+    fn collection_bytes(&mut self, metadata_doc: &Metadata) -> &mut Vec<u8> {
+        let idx = match self.collections.iter().position(|(collection_metadata_doc, _)| {
+            collection_metadata_doc.db == metadata_doc.db
+                && collection_metadata_doc.collection == metadata_doc.collection
+        }) {
+            Some(idx) => idx,
+            None => {
+                self.collections.push((metadata_doc.clone(), vec![]));
+                self.collections.len() - 1
+            }
+        };
+
+        &mut self.collections[idx].1
+    }
+}
+
+fn write_namespace_doc(namespace_doc: &Namespace, buf: &mut Vec<u8>) -> Result<(), Error> {
+    bson::to_document(namespace_doc)
+        .unwrap()
+        .to_writer(buf)
+        .map_err(|err| {
+            Error::new(
+                ErrorKind::Other,
+                format!("Error writing block header: {}", err),
+            )
+        })
+}
+
+/// Merge archives written one after the other (each one can be followed by a separator byte like a new line)
+/// into a single archive that mongorestore can read.
+pub fn merge_archives<R: Read>(mut reader: BufReader<R>) -> Result<Vec<u8>, Error> {
+    let mut archive_writer: Option<ArchiveWriter> = None;
+
+    while !reader.fill_buf()?.is_empty() {
+        let mut archive_reader = ArchiveReader::from_reader(reader)?;
+
+        let writer = archive_writer
+            .get_or_insert_with(|| ArchiveWriter::new(archive_reader.header().clone()));
+
+        for metadata_doc in archive_reader.metadata_docs() {
+            writer.write_collection(metadata_doc);
+        }
+
+        archive_reader.read_documents(|metadata_doc, document| {
+            writer.write_document(metadata_doc, &document)
+        })?;
+
+        reader = archive_reader.into_inner();
+
+        // skip the byte separating two archives
+        if !reader.fill_buf()?.is_empty() {
+            reader.consume(1);
+        }
+    }
+
+    match archive_writer {
+        Some(mut archive_writer) => archive_writer.flush(),
+        None => Ok(vec![]),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{mongodb::Archive, utils::decode_hex};
+    use crate::{
+        mongodb::{merge_archives, ArchiveReader, ArchiveWriter},
+        utils::decode_hex,
+    };
+    use bson::Document;
     use std::{fmt::Write, io::BufReader};
 
+    // archive should contain a single collection "Users" in db "test2" with a single document: {name: "John", age: 42}
+    const DUMP_STR: &str = "6de299816600000010636f6e63757272656e745f636f6c6c656374696f6e7300040000000276657273696f6e0004000000302e3100027365727665725f76657273696f6e0006000000352e302e360002746f6f6c5f76657273696f6e00080000003130302e352e32000003010000026462000600000074657374320002636f6c6c656374696f6e0006000000557365727300026d6574616461746100ad0000007b22696e6465786573223a5b7b2276223a7b22246e756d626572496e74223a2232227d2c226b6579223a7b225f6964223a7b22246e756d626572496e74223a2231227d7d2c226e616d65223a225f69645f227d5d2c2275756964223a223732306531616132326231373435643739663139373530626162323933303837222c22636f6c6c656374696f6e4e616d65223a225573657273222c2274797065223a22636f6c6c656374696f6e227d001073697a6500000000000274797065000b000000636f6c6c656374696f6e0000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f46000012435243000000000000000000002e000000075f696400623f23928e7f1feed4d5e3e1026e616d6500050000004a6f686e0010616765002a00000000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f4600011243524300ff2a87dec3c86e6e00ffffffff";
+
+    fn to_hex(bytes: Vec<u8>) -> String {
+        let mut out = String::new();
+        for byte in bytes {
+            write!(out, "{:02x}", byte).unwrap();
+        }
+        out
+    }
+
+    fn read_documents(bytes: &[u8]) -> Vec<(String, Document)> {
+        let mut archive_reader = ArchiveReader::from_reader(BufReader::new(bytes)).unwrap();
+        let mut documents = vec![];
+        archive_reader
+            .read_documents(|metadata_doc, document| {
+                documents.push((metadata_doc.prefix(), document));
+                Ok(())
+            })
+            .unwrap();
+        documents
+    }
+
     #[test]
     fn mongo_archive_parsing() {
-        // archive should contain a single collection "Users" in db "test2" with a single document: {name: "John", age: 42}
-        let dump_str = "6de299816600000010636f6e63757272656e745f636f6c6c656374696f6e7300040000000276657273696f6e0004000000302e3100027365727665725f76657273696f6e0006000000352e302e360002746f6f6c5f76657273696f6e00080000003130302e352e32000003010000026462000600000074657374320002636f6c6c656374696f6e0006000000557365727300026d6574616461746100ad0000007b22696e6465786573223a5b7b2276223a7b22246e756d626572496e74223a2232227d2c226b6579223a7b225f6964223a7b22246e756d626572496e74223a2231227d7d2c226e616d65223a225f69645f227d5d2c2275756964223a223732306531616132326231373435643739663139373530626162323933303837222c22636f6c6c656374696f6e4e616d65223a225573657273222c2274797065223a22636f6c6c656374696f6e227d001073697a6500000000000274797065000b000000636f6c6c656374696f6e0000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f46000012435243000000000000000000002e000000075f696400623f23928e7f1feed4d5e3e1026e616d6500050000004a6f686e0010616765002a00000000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f4600011243524300ff2a87dec3c86e6e00ffffffff";
-        let hexdump = decode_hex(dump_str).unwrap();
+        let hexdump = decode_hex(DUMP_STR).unwrap();
         let reader = BufReader::new(hexdump.as_slice());
-        let archive = Archive::from_reader(reader);
-        assert!(archive.is_ok());
-        let archive = archive.unwrap();
-        assert!(archive.prefixed_collections.contains_key("test2.Users"));
-        let decoded_collection = archive.prefixed_collections.get("test2.Users").unwrap();
-        assert_eq!(
-            decoded_collection.first().unwrap().get_str("name").unwrap(),
-            "John"
-        );
-        assert_eq!(
-            decoded_collection.first().unwrap().get_i32("age").unwrap(),
-            42
-        );
+        let archive_reader = ArchiveReader::from_reader(reader);
+        assert!(archive_reader.is_ok());
+        let archive_reader = archive_reader.unwrap();
+        assert_eq!(archive_reader.metadata_docs().len(), 1);
+        assert_eq!(archive_reader.metadata_docs()[0].prefix(), "test2.Users");
+
+        let documents = read_documents(hexdump.as_slice());
+        assert_eq!(documents.len(), 1);
+        let (prefix, document) = documents.first().unwrap();
+        assert_eq!(prefix, "test2.Users");
+        assert_eq!(document.get_str("name").unwrap(), "John");
+        assert_eq!(document.get_i32("age").unwrap(), 42);
     }
 
     #[test]
     fn mongo_archive_to_bytes() {
-        let dump_str = "6de299816600000010636f6e63757272656e745f636f6c6c656374696f6e7300040000000276657273696f6e0004000000302e3100027365727665725f76657273696f6e0006000000352e302e360002746f6f6c5f76657273696f6e00080000003130302e352e32000003010000026462000600000074657374320002636f6c6c656374696f6e0006000000557365727300026d6574616461746100ad0000007b22696e6465786573223a5b7b2276223a7b22246e756d626572496e74223a2232227d2c226b6579223a7b225f6964223a7b22246e756d626572496e74223a2231227d7d2c226e616d65223a225f69645f227d5d2c2275756964223a223732306531616132326231373435643739663139373530626162323933303837222c22636f6c6c656374696f6e4e616d65223a225573657273222c2274797065223a22636f6c6c656374696f6e227d001073697a6500000000000274797065000b000000636f6c6c656374696f6e0000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f46000012435243000000000000000000002e000000075f696400623f23928e7f1feed4d5e3e1026e616d6500050000004a6f686e0010616765002a00000000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f4600011243524300ff2a87dec3c86e6e00ffffffff";
-        let hexdump = decode_hex(dump_str).unwrap();
+        let hexdump = decode_hex(DUMP_STR).unwrap();
         let reader = BufReader::new(hexdump.as_slice());
-        let archive = Archive::from_reader(reader).unwrap();
-        let vec_bytes = archive.into_bytes().unwrap();
-        let mut out = String::new();
-        for byte in vec_bytes {
-            write!(out, "{:02x}", byte).unwrap();
-        }
-        assert_eq!(out.as_str(), dump_str);
+        let mut archive_reader = ArchiveReader::from_reader(reader).unwrap();
+        let mut archive_writer = ArchiveWriter::new(archive_reader.header().clone());
+
+        archive_reader
+            .read_documents(|metadata_doc, document| {
+                archive_writer.write_document(metadata_doc, &document)
+            })
+            .unwrap();
+
+        assert!(!archive_writer.is_empty());
+        assert!(archive_writer.size() > 0);
+
+        let vec_bytes = archive_writer.flush().unwrap();
+        assert_eq!(to_hex(vec_bytes).as_str(), DUMP_STR);
+
+        // the buffer is reset
+        assert!(archive_writer.is_empty());
+        assert_eq!(archive_writer.size(), 0);
+    }
+
+    #[test]
+    fn mongo_archive_with_empty_collection_to_bytes() {
+        let hexdump = decode_hex(DUMP_STR).unwrap();
+        let reader = BufReader::new(hexdump.as_slice());
+        let archive_reader = ArchiveReader::from_reader(reader).unwrap();
+        let mut archive_writer = ArchiveWriter::new(archive_reader.header().clone());
+
+        archive_writer.write_collection(&archive_reader.metadata_docs()[0]);
+        let vec_bytes = archive_writer.flush().unwrap();
+
+        let archive_reader = ArchiveReader::from_reader(BufReader::new(vec_bytes.as_slice())).unwrap();
+        assert_eq!(archive_reader.metadata_docs().len(), 1);
+        assert!(read_documents(vec_bytes.as_slice()).is_empty());
+    }
+
+    #[test]
+    fn mongo_merge_archives() {
+        let hexdump = decode_hex(DUMP_STR).unwrap();
+
+        // a single archive followed by a new line
+        let mut bytes = hexdump.clone();
+        bytes.push(b'\n');
+        let merged_bytes = merge_archives(BufReader::new(bytes.as_slice())).unwrap();
+        assert_eq!(to_hex(merged_bytes).as_str(), DUMP_STR);
+
+        // two archives of the same collection
+        let mut bytes = hexdump.clone();
+        bytes.push(b'\n');
+        bytes.extend_from_slice(hexdump.as_slice());
+        bytes.push(b'\n');
+        let merged_bytes = merge_archives(BufReader::new(bytes.as_slice())).unwrap();
+
+        let archive_reader =
+            ArchiveReader::from_reader(BufReader::new(merged_bytes.as_slice())).unwrap();
+        assert_eq!(archive_reader.metadata_docs().len(), 1);
+
+        let documents = read_documents(merged_bytes.as_slice());
+        assert_eq!(documents.len(), 2);
+        assert!(documents
+            .iter()
+            .all(|(prefix, document)| prefix == "test2.Users"
+                && document.get_str("name").unwrap() == "John"));
+
+        assert!(merge_archives(BufReader::new(&b""[..])).unwrap().is_empty());
     }
 }
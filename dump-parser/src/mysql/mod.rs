@@ -715,6 +715,172 @@ pub fn get_column_values_from_insert_into_query(tokens: &Vec<Token>) -> Vec<&Tok
         .collect::<Vec<_>>()
 }
 
+pub fn get_column_values_str_from_insert_into_query(tokens: &Vec<Token>) -> Vec<String> {
+    get_column_values_from_insert_into_query(&tokens)
+        .iter()
+        .filter_map(|x| match *x {
+            Token::Word(word) => Some(word.value.clone()),
+            Token::SingleQuotedString(word) => Some(word.clone()),
+            Token::Number(value, _) => Some(value.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+}
+
+/// words that can follow the first word of a column type, e.g. `int unsigned`
+const COLUMN_TYPE_WORDS: [&str; 4] = ["UNSIGNED", "ZEROFILL", "PRECISION", "VARYING"];
+
+/// words starting an index or a table constraint instead of a column definition
+const TABLE_CONSTRAINT_WORDS: [&str; 9] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "FOREIGN",
+    "UNIQUE",
+    "KEY",
+    "INDEX",
+    "FULLTEXT",
+    "SPATIAL",
+    "CHECK",
+];
+
+/// Get the column names and types of a `CREATE TABLE `<table>` (`<column>` <type> ...);` query.
+/// Types are in lowercase and without their modifiers: `varchar(40)` -> `varchar`,
+/// `int(11) unsigned` -> `int unsigned`.
+pub fn get_column_types_from_create_table_query(tokens: &Vec<Token>) -> Vec<(String, String)> {
+    if !match_keyword_at_position(Create, &tokens, 0)
+        || !match_keyword_at_position(Table, &tokens, 2)
+    {
+        // it means that the query is not a CREATE TABLE.. one
+        return Vec::new();
+    }
+
+    // split the column definitions on the commas which are not in parentheses
+    let mut definitions: Vec<Vec<&Token>> = vec![];
+    let mut definition = vec![];
+    let mut depth = 0;
+
+    for token in tokens.iter().skip_while(|token| **token != Token::LParen) {
+        match token {
+            Token::LParen => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            Token::RParen => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            Token::Comma if depth == 1 => {
+                definitions.push(definition);
+                definition = vec![];
+                continue;
+            }
+            Token::Whitespace(_) => continue,
+            _ => {}
+        }
+
+        definition.push(token);
+    }
+
+    definitions.push(definition);
+
+    definitions
+        .into_iter()
+        .filter_map(|definition| {
+            let mut tokens = definition.into_iter();
+
+            let column_name = match tokens.next() {
+                Some(Token::SingleQuotedString(name)) => name.to_string(),
+                Some(Token::Word(word))
+                    if !TABLE_CONSTRAINT_WORDS.contains(&word.value.to_uppercase().as_str()) =>
+                {
+                    word.value.to_string()
+                }
+                _ => return None,
+            };
+
+            let mut column_type = match tokens.next() {
+                Some(Token::Word(word)) => word.value.to_lowercase(),
+                _ => return None,
+            };
+
+            let mut depth = 0;
+
+            for token in tokens {
+                match token {
+                    Token::LParen => depth += 1,
+                    Token::RParen => depth -= 1,
+                    _ if depth > 0 => {}
+                    Token::Word(word)
+                        if COLUMN_TYPE_WORDS.contains(&word.value.to_uppercase().as_str()) =>
+                    {
+                        column_type.push(' ');
+                        column_type.push_str(word.value.to_lowercase().as_str());
+                    }
+                    _ => break,
+                }
+            }
+
+            Some((column_name, column_type))
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Unescape the value of a single quoted string as it is tokenized, e.g. `It\'s` -> `It's`.
+/// `\%` and `\_` keep their backslash - https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
+pub fn unescape_string_value(value: &str) -> String {
+    let mut unescaped_value = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped_value.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('0') => unescaped_value.push('\0'),
+            Some('b') => unescaped_value.push('\x08'),
+            Some('n') => unescaped_value.push('\n'),
+            Some('r') => unescaped_value.push('\r'),
+            Some('t') => unescaped_value.push('\t'),
+            Some('Z') => unescaped_value.push('\x1A'),
+            Some(ch) if ch == '%' || ch == '_' => {
+                unescaped_value.push('\\');
+                unescaped_value.push(ch);
+            }
+            // any other char following a backslash is taken literally
+            Some(ch) => unescaped_value.push(ch),
+            None => unescaped_value.push('\\'),
+        }
+    }
+
+    unescaped_value
+}
+
+/// Escape a value to write it into a single quoted string - as mysqldump does.
+pub fn escape_string_value(value: &str) -> String {
+    let mut escaped_value = String::with_capacity(value.len());
+
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped_value.push_str("\\\\"),
+            '\'' => escaped_value.push_str("\\'"),
+            '"' => escaped_value.push_str("\\\""),
+            '\0' => escaped_value.push_str("\\0"),
+            '\n' => escaped_value.push_str("\\n"),
+            '\r' => escaped_value.push_str("\\r"),
+            '\x1A' => escaped_value.push_str("\\Z"),
+            ch => escaped_value.push(ch),
+        }
+    }
+
+    escaped_value
+}
+
 pub fn get_tokens_from_query_str(query: &str) -> Vec<Token> {
     // query by query
     let mut tokenizer = Tokenizer::new(query);
@@ -744,9 +910,11 @@ pub fn trim_pre_whitespaces(tokens: Vec<Token>) -> Vec<Token> {
 #[cfg(test)]
 mod tests {
     use crate::mysql::{
-        get_column_names_from_insert_into_query, get_column_values_from_insert_into_query,
+        escape_string_value, get_column_names_from_insert_into_query,
+        get_column_types_from_create_table_query, get_column_values_from_insert_into_query,
         get_single_quoted_string_value_at_position, get_tokens_from_query_str,
-        match_keyword_at_position, trim_pre_whitespaces, Token, Tokenizer, Whitespace,
+        match_keyword_at_position, trim_pre_whitespaces, unescape_string_value, Token, Tokenizer,
+        Whitespace,
     };
 
     #[test]
@@ -1082,4 +1250,47 @@ VALUES ('Romaric', true);
             ]
         );
     }
+
+    #[test]
+    fn test_get_column_types_from_create_table_query() {
+        let q = "CREATE TABLE `city` (
+  `ID` int(11) unsigned NOT NULL AUTO_INCREMENT,
+  `Name` char(35) NOT NULL DEFAULT '',
+  `Info` json DEFAULT NULL,
+  `CreatedAt` datetime(6) DEFAULT CURRENT_TIMESTAMP(6),
+  `Kind` enum('big','small') NOT NULL,
+  PRIMARY KEY (`ID`),
+  KEY `CountryCode` (`CountryCode`),
+  CONSTRAINT `city_ibfk_1` FOREIGN KEY (`CountryCode`) REFERENCES `country` (`Code`)
+) ENGINE=InnoDB AUTO_INCREMENT=4080 DEFAULT CHARSET=latin1;";
+
+        let tokens = get_tokens_from_query_str(q);
+
+        assert_eq!(
+            get_column_types_from_create_table_query(&tokens),
+            vec![
+                ("ID".to_string(), "int unsigned".to_string()),
+                ("Name".to_string(), "char".to_string()),
+                ("Info".to_string(), "json".to_string()),
+                ("CreatedAt".to_string(), "datetime".to_string()),
+                ("Kind".to_string(), "enum".to_string()),
+            ]
+        );
+
+        let tokens = get_tokens_from_query_str("INSERT INTO `city` VALUES (1);");
+        assert!(get_column_types_from_create_table_query(&tokens).is_empty());
+    }
+
+    #[test]
+    fn test_escape_string_value() {
+        assert_eq!(unescape_string_value("People\\'s Republic"), "People's Republic");
+        assert_eq!(
+            unescape_string_value("{\\\"lang\\\": \\\"fr\\\"}\\nC:\\\\ 100\\%"),
+            "{\"lang\": \"fr\"}\nC:\\ 100\\%"
+        );
+
+        // values dumped by mysqldump are written back as they are
+        let value = "{\\\"lang\\\": \\\"fr\\\"}\\r\\nIt\\'s\\0\\Z C:\\\\";
+        assert_eq!(escape_string_value(unescape_string_value(value).as_str()), value);
+    }
 }
@@ -4,8 +4,11 @@ use std::str::Chars;
 
 use crate::postgres::Keyword::{
     Add, Alter, Constraint, Copy, Create, Database, Foreign, From, Function, Insert,
-    Into as KeywordInto, Key, NoKeyword, Not, Null, Only, Primary, References, Replace, Table,
+    Into as KeywordInto, Key, NoKeyword, Not, Null, Only, Primary, References, Replace, Stdin,
+    Table,
 };
+use crate::DumpFileError;
+use crate::DumpFileError::MalFormatted;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Token {
@@ -144,6 +147,7 @@ impl Token {
                     "REFERENCES" => References,
                     "KEY" => Key,
                     "FUNCTION" => Function,
+                    "STDIN" => Stdin,
                     _ => NoKeyword,
                 }
             } else {
@@ -190,6 +194,7 @@ pub enum Keyword {
     References,
     Key,
     Function,
+    Stdin,
     NoKeyword,
 }
 
@@ -779,6 +784,257 @@ pub fn get_column_values_str_from_insert_into_query(tokens: &Vec<Token>) -> Vec<
         .collect::<Vec<_>>()
 }
 
+/// words that can follow the first word of a column type, e.g. `timestamp without time zone`
+const COLUMN_TYPE_WORDS: [&str; 6] = ["VARYING", "PRECISION", "WITH", "WITHOUT", "TIME", "ZONE"];
+
+/// words starting a table constraint instead of a column definition
+const TABLE_CONSTRAINT_WORDS: [&str; 7] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "FOREIGN",
+    "UNIQUE",
+    "CHECK",
+    "EXCLUDE",
+    "LIKE",
+];
+
+/// Get the column names and types of a `CREATE TABLE <database>.<table> (<column> <type> ...);` query.
+/// Types are in lowercase and without their modifiers: `character varying(40)` -> `character varying`,
+/// `integer[]` -> `integer[]`.
+pub fn get_column_types_from_create_table_query(tokens: &Vec<Token>) -> Vec<(String, String)> {
+    if !match_keyword_at_position(Create, &tokens, 0)
+        || !match_keyword_at_position(Table, &tokens, 2)
+    {
+        // it means that the query is not a CREATE TABLE.. one
+        return Vec::new();
+    }
+
+    // split the column definitions on the commas which are not in parentheses
+    let mut definitions: Vec<Vec<&Token>> = vec![];
+    let mut definition = vec![];
+    let mut depth = 0;
+
+    for token in tokens.iter().skip_while(|token| **token != Token::LParen) {
+        match token {
+            Token::LParen => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            Token::RParen => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            Token::Comma if depth == 1 => {
+                definitions.push(definition);
+                definition = vec![];
+                continue;
+            }
+            Token::Whitespace(_) => continue,
+            _ => {}
+        }
+
+        definition.push(token);
+    }
+
+    definitions.push(definition);
+
+    definitions
+        .into_iter()
+        .filter_map(|definition| {
+            let mut tokens = definition.into_iter();
+
+            let column_name = match tokens.next() {
+                Some(Token::Word(word))
+                    if word.quote_style.is_some()
+                        || !TABLE_CONSTRAINT_WORDS
+                            .contains(&word.value.to_uppercase().as_str()) =>
+                {
+                    format!(
+                        "{quote_style}{value}{quote_style}",
+                        value = word.value.as_str(),
+                        quote_style = match word.quote_style {
+                            Some(quote) => quote.to_string(),
+                            None => "".to_string(),
+                        }
+                    ) // column name with escaping
+                }
+                _ => return None,
+            };
+
+            let mut column_type = match tokens.next() {
+                Some(Token::Word(word)) => word.value.to_lowercase(),
+                _ => return None,
+            };
+
+            let mut depth = 0;
+
+            for token in tokens {
+                match token {
+                    Token::LParen | Token::LBracket => depth += 1,
+                    Token::RParen => depth -= 1,
+                    Token::RBracket => {
+                        depth -= 1;
+                        if depth == 0 {
+                            column_type.push_str("[]");
+                        }
+                    }
+                    _ if depth > 0 => {}
+                    Token::Word(word)
+                        if COLUMN_TYPE_WORDS.contains(&word.value.to_uppercase().as_str()) =>
+                    {
+                        column_type.push(' ');
+                        column_type.push_str(word.value.to_lowercase().as_str());
+                    }
+                    _ => break,
+                }
+            }
+
+            Some((column_name, column_type))
+        })
+        .collect::<Vec<_>>()
+}
+
+/// check if the query is a `COPY <database>.<table> (<columns>) FROM stdin;` one
+pub fn is_copy_from_stdin_query(tokens: &Vec<Token>) -> bool {
+    if !match_keyword_at_position(Keyword::Copy, &tokens, 0) {
+        return false;
+    }
+
+    tokens
+        .iter()
+        .filter(|token| match token {
+            Token::Whitespace(_) | Token::SemiColon => false,
+            _ => true,
+        })
+        .rev()
+        .take(2)
+        .map(|token| match token {
+            Token::Word(word) => word.keyword.clone(),
+            _ => NoKeyword,
+        })
+        .eq(vec![Stdin, From])
+}
+
+pub fn get_column_names_from_copy_query(tokens: &Vec<Token>) -> Vec<String> {
+    if !match_keyword_at_position(Keyword::Copy, &tokens, 0) {
+        // it means that the query is not a COPY.. one
+        return Vec::new();
+    }
+
+    tokens
+        .iter()
+        .skip_while(|token| match **token {
+            Token::LParen => false,
+            _ => true,
+        })
+        .take_while(|token| match **token {
+            Token::RParen => false,
+            _ => true,
+        })
+        .filter_map(|token| match token {
+            Token::Word(word) => {
+                Some(format!(
+                    "{quote_style}{value}{quote_style}",
+                    value = word.value.as_str(),
+                    quote_style = match word.quote_style {
+                        Some(quote) => quote.to_string(),
+                        None => "".to_string(),
+                    }
+                )) // column name with escaping
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Parse a row of a `COPY ... FROM stdin;` block (text format).
+/// Values are separated by tabs, `\N` is NULL and special chars are escaped with a backslash.
+/// The row is malformatted when its escaped bytes are not valid UTF-8.
+pub fn get_column_values_from_copy_row(row: &str) -> Result<Vec<Option<String>>, DumpFileError> {
+    row.split('\t')
+        .map(|value| match value {
+            "\\N" => Ok(None),
+            value => unescape_copy_value(value).map(Some),
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Escape a value to write it into a `COPY ... FROM stdin;` block (text format).
+pub fn escape_copy_value(value: &str) -> String {
+    let mut escaped_value = String::with_capacity(value.len());
+
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped_value.push_str("\\\\"),
+            '\n' => escaped_value.push_str("\\n"),
+            '\r' => escaped_value.push_str("\\r"),
+            '\t' => escaped_value.push_str("\\t"),
+            '\x08' => escaped_value.push_str("\\b"),
+            '\x0C' => escaped_value.push_str("\\f"),
+            '\x0B' => escaped_value.push_str("\\v"),
+            ch => escaped_value.push(ch),
+        }
+    }
+
+    escaped_value
+}
+
+fn unescape_copy_value(value: &str) -> Result<String, DumpFileError> {
+    // octal and hexadecimal escapes are bytes - a multibyte character is made of several escapes
+    let mut unescaped_value: Vec<u8> = Vec::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    let mut buf = [0u8; 4];
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped_value.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+
+        match chars.next() {
+            Some('b') => unescaped_value.push(b'\x08'),
+            Some('f') => unescaped_value.push(b'\x0C'),
+            Some('n') => unescaped_value.push(b'\n'),
+            Some('r') => unescaped_value.push(b'\r'),
+            Some('t') => unescaped_value.push(b'\t'),
+            Some('v') => unescaped_value.push(b'\x0B'),
+            Some(digit) if digit.is_digit(8) => {
+                // \digits - up to 3 octal digits
+                let mut code = digit.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|ch| ch.to_digit(8)) {
+                        Some(next_digit) => {
+                            code = code * 8 + next_digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                unescaped_value.push(code as u8);
+            }
+            Some('x') if chars.peek().map(|ch| ch.is_ascii_hexdigit()) == Some(true) => {
+                // \xdigits - up to 2 hexadecimal digits
+                let mut code = chars.next().unwrap().to_digit(16).unwrap();
+                if let Some(next_digit) = chars.peek().and_then(|ch| ch.to_digit(16)) {
+                    code = code * 16 + next_digit;
+                    chars.next();
+                }
+                unescaped_value.push(code as u8);
+            }
+            // any other char following a backslash is taken literally
+            Some(ch) => unescaped_value.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes()),
+            None => unescaped_value.push(b'\\'),
+        }
+    }
+
+    // the escaped bytes are rejected as the raw ones when they are not a valid character
+    String::from_utf8(unescaped_value).map_err(|_| MalFormatted)
+}
+
 pub fn get_tokens_from_query_str(query: &str) -> Vec<Token> {
     // query by query
     let mut tokenizer = Tokenizer::new(query);
@@ -808,8 +1064,11 @@ pub fn trim_pre_whitespaces(tokens: Vec<Token>) -> Vec<Token> {
 #[cfg(test)]
 mod tests {
     use crate::postgres::{
-        get_column_names_from_insert_into_query, get_column_values_from_insert_into_query,
-        trim_pre_whitespaces, Token, Tokenizer, Whitespace,
+        escape_copy_value, get_column_names_from_copy_query,
+        get_column_names_from_insert_into_query, get_column_types_from_create_table_query,
+        get_column_values_from_copy_row, get_column_values_from_insert_into_query,
+        get_tokens_from_query_str, is_copy_from_stdin_query, trim_pre_whitespaces, Token,
+        Tokenizer, Whitespace,
     };
 
     #[test]
@@ -1028,4 +1287,124 @@ VALUES ('Romaric', true);
             ]
         );
     }
+
+    #[test]
+    fn test_copy_from_stdin_query() {
+        let q = "COPY public.customers (customer_id, company_name, \"contactName\") FROM stdin;";
+
+        let mut tokenizer = Tokenizer::new(q);
+        let tokens_result = tokenizer.tokenize();
+        assert_eq!(tokens_result.is_ok(), true);
+
+        let tokens = trim_pre_whitespaces(tokens_result.unwrap());
+        assert!(is_copy_from_stdin_query(&tokens));
+
+        let column_names = get_column_names_from_copy_query(&tokens);
+        assert_eq!(
+            column_names,
+            vec!["customer_id", "company_name", "\"contactName\""]
+        );
+
+        let q = "COPY public.customers (customer_id) TO stdout;";
+        let tokens = trim_pre_whitespaces(Tokenizer::new(q).tokenize().unwrap());
+        assert!(!is_copy_from_stdin_query(&tokens));
+
+        let q = "INSERT INTO public.customers (customer_id) VALUES (1);";
+        let tokens = trim_pre_whitespaces(Tokenizer::new(q).tokenize().unwrap());
+        assert!(!is_copy_from_stdin_query(&tokens));
+        assert!(get_column_names_from_copy_query(&tokens).is_empty());
+    }
+
+    #[test]
+    fn test_copy_row_values() {
+        let column_values =
+            get_column_values_from_copy_row("1\tJohn\\tDoe\t\\N\tline\\nbreak\t\\\\").unwrap();
+
+        assert_eq!(
+            column_values,
+            vec![
+                Some("1".to_string()),
+                Some("John\tDoe".to_string()),
+                None,
+                Some("line\nbreak".to_string()),
+                Some("\\".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            get_column_values_from_copy_row("\\101\\x42").unwrap(),
+            vec![Some("AB".to_string())]
+        );
+
+        assert_eq!(
+            get_column_values_from_copy_row("").unwrap(),
+            vec![Some("".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_escape_copy_value() {
+        assert_eq!(escape_copy_value("John Doe"), "John Doe");
+        assert_eq!(
+            escape_copy_value("John\tDoe\nline\\"),
+            "John\\tDoe\\nline\\\\"
+        );
+
+        let value = "tab\there\r\nand \\N";
+        let escaped_value = escape_copy_value(value);
+        assert_eq!(
+            get_column_values_from_copy_row(escaped_value.as_str()).unwrap(),
+            vec![Some(value.to_string())]
+        );
+    }
+
+    #[test]
+    fn test_unescape_copy_value_with_escaped_bytes() {
+        // 'é' is 0xc3 0xa9 in UTF-8
+        assert_eq!(
+            get_column_values_from_copy_row("caf\\303\\251\tcaf\\xc3\\xa9\t\\101\\x42").unwrap(),
+            vec![
+                Some("café".to_string()),
+                Some("café".to_string()),
+                Some("AB".to_string())
+            ]
+        );
+
+        // a byte which is not a valid UTF-8 character on its own
+        assert!(get_column_values_from_copy_row("caf\\351").is_err());
+    }
+
+    #[test]
+    fn test_get_column_types_from_create_table_query() {
+        let q = r#"
+CREATE TABLE public.orders (
+    order_id smallint NOT NULL,
+    "Customer" character varying(40),
+    order_date timestamp(6) with time zone DEFAULT now(),
+    details jsonb,
+    tags text[] DEFAULT '{}'::text[],
+    amount numeric(10,2),
+    CONSTRAINT amount_check CHECK ((amount > 0))
+);"#;
+
+        let tokens = get_tokens_from_query_str(q);
+
+        assert_eq!(
+            get_column_types_from_create_table_query(&tokens),
+            vec![
+                ("order_id".to_string(), "smallint".to_string()),
+                ("\"Customer\"".to_string(), "character varying".to_string()),
+                (
+                    "order_date".to_string(),
+                    "timestamp with time zone".to_string()
+                ),
+                ("details".to_string(), "jsonb".to_string()),
+                ("tags".to_string(), "text[]".to_string()),
+                ("amount".to_string(), "numeric".to_string()),
+            ]
+        );
+
+        let tokens = get_tokens_from_query_str("SELECT 1;");
+        assert!(get_column_types_from_create_table_query(&tokens).is_empty());
+    }
 }
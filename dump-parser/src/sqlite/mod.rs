@@ -0,0 +1,1138 @@
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::iter::Peekable;
+use std::str;
+use std::str::Chars;
+
+use crate::utils::ListQueryResult;
+use crate::DumpFileError;
+use crate::DumpFileError::{MalFormatted, ReadError};
+
+use crate::sqlite::Keyword::{
+    Create, Exists, If, Insert, Into as KeywordInto, NoKeyword, Not, Null, Table, Values,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Token {
+    /// A signed numeric literal, as it is written: i.e: -1.5e3, 0x1F
+    Number(String),
+    /// A keyword (like INSERT) or an optionally quoted identifier
+    Word(Word),
+    /// Whitespace (space, tab, etc) and comments
+    Whitespace(Whitespace),
+    /// A character that could not be tokenized
+    Char(char),
+    /// Single quoted string, with its quotes still escaped: i.e: 'It''s'
+    SingleQuotedString(String),
+    /// Blob literal: i.e.: X'deadbeef'
+    HexStringLiteral(String),
+    /// Comma
+    Comma,
+    /// Left parenthesis `(`
+    LParen,
+    /// Right parenthesis `)`
+    RParen,
+    /// Period (used for schema qualified names)
+    Period,
+    /// SemiColon `;` ending a statement
+    SemiColon,
+}
+
+impl Token {
+    pub fn make_keyword(keyword: &str) -> Self {
+        Token::make_word(keyword, None)
+    }
+
+    pub fn make_word(word: &str, quote_style: Option<char>) -> Self {
+        let word_uppercase = word.to_uppercase();
+        Token::Word(Word {
+            value: word.to_string(),
+            quote_style,
+            keyword: if quote_style.is_none() {
+                match word_uppercase.as_str() {
+                    "CREATE" => Create,
+                    "TABLE" => Table,
+                    "INSERT" => Insert,
+                    "INTO" => KeywordInto,
+                    "VALUES" => Values,
+                    "IF" => If,
+                    "NOT" => Not,
+                    "EXISTS" => Exists,
+                    "NULL" => Null,
+                    _ => NoKeyword,
+                }
+            } else {
+                NoKeyword
+            },
+        })
+    }
+}
+
+/// write the token back as it is in the query
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Word(word) => match word.quote_style {
+                None => write!(f, "{}", word.value),
+                Some('[') => write!(f, "[{}]", word.value),
+                Some(quote) => {
+                    let escaped_quote = format!("{}{}", quote, quote);
+                    let value = word.value.replace(quote, escaped_quote.as_str());
+                    write!(f, "{}{}{}", quote, value, quote)
+                }
+            },
+            Token::Whitespace(Whitespace::Space) => write!(f, " "),
+            Token::Whitespace(Whitespace::Newline) => writeln!(f),
+            Token::Whitespace(Whitespace::Tab) => write!(f, "\t"),
+            Token::Whitespace(Whitespace::SingleLineComment { comment, prefix }) => {
+                write!(f, "{}{}", prefix, comment)
+            }
+            Token::Whitespace(Whitespace::MultiLineComment(comment)) => {
+                write!(f, "/*{}*/", comment)
+            }
+            Token::Char(ch) => write!(f, "{}", ch),
+            Token::SingleQuotedString(value) => write!(f, "'{}'", value),
+            Token::HexStringLiteral(value) => write!(f, "X'{}'", value),
+            Token::Comma => write!(f, ","),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+            Token::Period => write!(f, "."),
+            Token::SemiColon => write!(f, ";"),
+        }
+    }
+}
+
+/// A keyword (like INSERT) or an optionally quoted SQL identifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Word {
+    /// The value of the token, without the enclosing quotes, and with the escaped quotes unescaped.
+    pub value: String,
+    /// An identifier can be quoted with `"`, `` ` `` or `[]` in SQLite
+    pub quote_style: Option<char>,
+    /// If the word was not quoted and it matched one of the known keywords,
+    /// this will have one of the values from Keyword, otherwise NoKeyword
+    pub keyword: Keyword,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Keyword {
+    Create,
+    Table,
+    Insert,
+    Into,
+    Values,
+    If,
+    Not,
+    Exists,
+    Null,
+    NoKeyword,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Whitespace {
+    Space,
+    Newline,
+    Tab,
+    SingleLineComment { comment: String, prefix: String },
+    MultiLineComment(String),
+}
+
+/// Tokenizer error
+#[derive(Debug, PartialEq)]
+pub struct TokenizerError {
+    pub message: String,
+    pub line: u64,
+    pub col: u64,
+}
+
+impl fmt::Display for TokenizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at Line: {}, Column {}",
+            self.message, self.line, self.col
+        )
+    }
+}
+
+/// SQL Tokenizer
+pub struct Tokenizer<'a> {
+    query: &'a str,
+    line: u64,
+    col: u64,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Create a new DUMP SQL tokenizer for the specified DUMP SQL statement
+    pub fn new<S: Into<&'a str>>(query: S) -> Self {
+        Self {
+            query: query.into(),
+            line: 1,
+            col: 1,
+        }
+    }
+
+    /// Tokenize the statement and produce a vector of tokens
+    pub fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
+        let mut peekable = self.query.chars().peekable();
+
+        let mut tokens: Vec<Token> = vec![];
+
+        while let Some(token) = self.next_token(&mut peekable)? {
+            match &token {
+                Token::Whitespace(Whitespace::Newline) => {
+                    self.line += 1;
+                    self.col = 1;
+                }
+
+                Token::Whitespace(Whitespace::Tab) => self.col += 4,
+                _ => self.col += 1,
+            }
+
+            tokens.push(token);
+        }
+
+        Ok(tokens)
+    }
+
+    /// Get the next token or return None
+    fn next_token(&self, chars: &mut Peekable<Chars<'_>>) -> Result<Option<Token>, TokenizerError> {
+        match chars.peek() {
+            Some(&ch) => match ch {
+                ' ' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Space)),
+                '\t' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Tab)),
+                '\n' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Newline)),
+                '\r' => {
+                    // Emit a single Whitespace::Newline token for \r and \r\n
+                    chars.next();
+                    if let Some('\n') = chars.peek() {
+                        chars.next();
+                    }
+                    Ok(Some(Token::Whitespace(Whitespace::Newline)))
+                }
+                x @ 'x' | x @ 'X' => {
+                    chars.next(); // consume, to check the next char
+                    match chars.peek() {
+                        Some('\'') => {
+                            // X'...' - a blob literal
+                            let s = self.tokenize_single_quoted_string(chars)?;
+                            Ok(Some(Token::HexStringLiteral(s)))
+                        }
+                        _ => {
+                            // regular identifier starting with an "X"
+                            let s = self.tokenize_word(x, chars);
+                            Ok(Some(Token::make_word(&s, None)))
+                        }
+                    }
+                }
+                // identifier or keyword
+                ch if is_identifier_start(ch) => {
+                    chars.next(); // consume the first char
+                    let s = self.tokenize_word(ch, chars);
+                    Ok(Some(Token::make_word(&s, None)))
+                }
+                // quoted identifier
+                quote @ '"' | quote @ '`' => {
+                    let s = self.tokenize_quoted_identifier(chars)?;
+                    Ok(Some(Token::make_word(&s, Some(quote))))
+                }
+                '[' => {
+                    chars.next(); // consume the '['
+                    let s = peeking_take_while(chars, |ch| ch != ']');
+
+                    match chars.next() {
+                        Some(_) => Ok(Some(Token::make_word(&s, Some('[')))),
+                        None => self.tokenizer_error("Unterminated bracket quoted identifier"),
+                    }
+                }
+                // string
+                '\'' => {
+                    let s = self.tokenize_single_quoted_string(chars)?;
+                    Ok(Some(Token::SingleQuotedString(s)))
+                }
+                // numbers and period
+                '0'..='9' | '.' => self.tokenize_number_literal(chars, None),
+                // punctuation
+                '(' => self.consume_and_return(chars, Token::LParen),
+                ')' => self.consume_and_return(chars, Token::RParen),
+                ',' => self.consume_and_return(chars, Token::Comma),
+                ';' => self.consume_and_return(chars, Token::SemiColon),
+                '-' => {
+                    chars.next(); // consume the '-'
+                    match chars.peek() {
+                        Some('-') => {
+                            chars.next(); // consume the second '-', starting a single-line comment
+                            let comment = self.tokenize_single_line_comment(chars);
+                            Ok(Some(Token::Whitespace(Whitespace::SingleLineComment {
+                                prefix: "--".to_owned(),
+                                comment,
+                            })))
+                        }
+                        // This is still not exhaustive as "SELECT - 1" would return a numeric -1.
+                        Some('0'..='9') | Some('.') => {
+                            self.tokenize_number_literal(chars, Some('-'))
+                        }
+                        // a regular '-' operator
+                        _ => Ok(Some(Token::Char('-'))),
+                    }
+                }
+                '/' => {
+                    chars.next(); // consume the '/'
+                    match chars.peek() {
+                        Some('*') => {
+                            chars.next(); // consume the '*', starting a multi-line comment
+                            self.tokenize_multiline_comment(chars)
+                        }
+                        // a regular '/' operator
+                        _ => Ok(Some(Token::Char('/'))),
+                    }
+                }
+                other => self.consume_and_return(chars, Token::Char(other)),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn tokenizer_error<R>(&self, message: impl Into<String>) -> Result<R, TokenizerError> {
+        Err(TokenizerError {
+            message: message.into(),
+            col: self.col,
+            line: self.line,
+        })
+    }
+
+    // Consume characters until newline
+    fn tokenize_single_line_comment(&self, chars: &mut Peekable<Chars<'_>>) -> String {
+        let mut comment = peeking_take_while(chars, |ch| ch != '\n');
+        if let Some(ch) = chars.next() {
+            assert_eq!(ch, '\n');
+            comment.push(ch);
+        }
+        comment
+    }
+
+    /// Tokenize an identifier or keyword, after the first char is already consumed.
+    fn tokenize_word(&self, first_char: char, chars: &mut Peekable<Chars<'_>>) -> String {
+        let mut s = first_char.to_string();
+        s.push_str(&peeking_take_while(chars, is_identifier_part));
+        s
+    }
+
+    /// Read a single quoted string, starting with the opening quote - the escaped quotes `''` are kept.
+    fn tokenize_single_quoted_string(
+        &self,
+        chars: &mut Peekable<Chars<'_>>,
+    ) -> Result<String, TokenizerError> {
+        let mut s = String::new();
+        chars.next(); // consume the opening quote
+
+        // there are no backslash escapes in SQLite - https://www.sqlite.org/lang_expr.html#literal_values_constants_
+        while let Some(ch) = chars.next() {
+            if ch != '\'' {
+                s.push(ch);
+                continue;
+            }
+
+            if chars.peek() == Some(&'\'') {
+                // the quote is escaped by another quote
+                chars.next();
+                s.push_str("''");
+                continue;
+            }
+
+            return Ok(s);
+        }
+
+        self.tokenizer_error("Unterminated string literal")
+    }
+
+    /// Read a `"` or `` ` `` quoted identifier, starting with the opening quote - the escaped quotes are unescaped.
+    fn tokenize_quoted_identifier(
+        &self,
+        chars: &mut Peekable<Chars<'_>>,
+    ) -> Result<String, TokenizerError> {
+        let mut s = String::new();
+        let quote_char = chars.next().expect("opening quote character"); // consume the opening quote
+
+        while let Some(ch) = chars.next() {
+            if ch != quote_char {
+                s.push(ch);
+                continue;
+            }
+
+            if chars.peek() == Some(&quote_char) {
+                // the quote is escaped by another quote
+                chars.next();
+                s.push(quote_char);
+                continue;
+            }
+
+            return Ok(s);
+        }
+
+        self.tokenizer_error("Unterminated quoted identifier")
+    }
+
+    // Read a signed number literal - decimal with an optional exponent, or hexadecimal
+    fn tokenize_number_literal(
+        &self,
+        chars: &mut Peekable<Chars<'_>>,
+        sign: Option<char>,
+    ) -> Result<Option<Token>, TokenizerError> {
+        let mut s = match sign {
+            Some(ch) => ch.to_string(),
+            None => String::new(),
+        };
+
+        s += &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+
+        // match hexadecimal integer that starts with 0x
+        if s.trim_start_matches('-') == "0" && matches!(chars.peek(), Some('x') | Some('X')) {
+            s.push(chars.next().unwrap());
+            s += &peeking_take_while(chars, |ch| ch.is_ascii_hexdigit());
+            return Ok(Some(Token::Number(s)));
+        }
+
+        // match one period
+        if let Some('.') = chars.peek() {
+            s.push('.');
+            chars.next();
+        }
+        s += &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+
+        // No number -> Token::Period
+        if s == "." {
+            return Ok(Some(Token::Period));
+        }
+
+        // match the exponent
+        if let Some('e') | Some('E') = chars.peek() {
+            s.push(chars.next().unwrap());
+
+            if let Some('+') | Some('-') = chars.peek() {
+                s.push(chars.next().unwrap());
+            }
+
+            s += &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+        }
+
+        Ok(Some(Token::Number(s)))
+    }
+
+    fn tokenize_multiline_comment(
+        &self,
+        chars: &mut Peekable<Chars<'_>>,
+    ) -> Result<Option<Token>, TokenizerError> {
+        let mut s = String::new();
+
+        // SQLite comments are not nested
+        loop {
+            match chars.next() {
+                Some('*') if chars.peek() == Some(&'/') => {
+                    chars.next();
+                    break Ok(Some(Token::Whitespace(Whitespace::MultiLineComment(s))));
+                }
+                Some(ch) => s.push(ch),
+                None => break self.tokenizer_error("Unexpected EOF while in a multi-line comment"),
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn consume_and_return(
+        &self,
+        chars: &mut Peekable<Chars<'_>>,
+        t: Token,
+    ) -> Result<Option<Token>, TokenizerError> {
+        chars.next();
+        Ok(Some(t))
+    }
+}
+
+fn is_identifier_start(ch: char) -> bool {
+    // See https://www.sqlite.org/draft/tokenreq.html - any non ASCII char can start an identifier
+    ch.is_ascii_alphabetic() || ch == '_' || !ch.is_ascii()
+}
+
+fn is_identifier_part(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '$' || !ch.is_ascii()
+}
+
+/// Read from `chars` until `predicate` returns `false` or EOF is hit.
+/// Return the characters read as String, and keep the first non-matching
+/// char available as `chars.next()`.
+fn peeking_take_while(
+    chars: &mut Peekable<Chars<'_>>,
+    mut predicate: impl FnMut(char) -> bool,
+) -> String {
+    let mut s = String::new();
+    while let Some(&ch) = chars.peek() {
+        if predicate(ch) {
+            chars.next(); // consume
+            s.push(ch);
+        } else {
+            break;
+        }
+    }
+
+    s
+}
+
+pub fn match_keyword_at_position(keyword: Keyword, tokens: &[Token], pos: usize) -> bool {
+    if let Some(token) = tokens.get(pos) {
+        return match token {
+            Token::Word(word) => word.keyword == keyword,
+            _ => false,
+        };
+    };
+
+    false
+}
+
+pub fn get_word_value_at_position(tokens: &[Token], pos: usize) -> Option<&str> {
+    if let Some(token) = tokens.get(pos) {
+        return match token {
+            Token::Word(word) => Some(word.value.as_str()),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+pub fn is_insert_into_statement(tokens: &[Token]) -> bool {
+    match_keyword_at_position(Insert, tokens, 0)
+        && match_keyword_at_position(KeywordInto, tokens, 2)
+}
+
+pub fn is_create_table_statement(tokens: &[Token]) -> bool {
+    match_keyword_at_position(Create, tokens, 0) && match_keyword_at_position(Table, tokens, 2)
+}
+
+/// position of the table name of a `CREATE TABLE [IF NOT EXISTS] <table> ...` or `INSERT INTO <table> ...` query -
+/// the schema name of `<schema>.<table>` is skipped
+fn get_table_name_position(tokens: &[Token]) -> Option<usize> {
+    let mut pos = 4;
+
+    if is_create_table_statement(tokens)
+        && match_keyword_at_position(If, tokens, 4)
+        && match_keyword_at_position(Not, tokens, 6)
+        && match_keyword_at_position(Exists, tokens, 8)
+    {
+        pos = 10;
+    } else if !is_insert_into_statement(tokens) && !is_create_table_statement(tokens) {
+        return None;
+    }
+
+    if tokens.get(pos + 1) == Some(&Token::Period) {
+        pos += 2;
+    }
+
+    match tokens.get(pos) {
+        Some(Token::Word(_)) => Some(pos),
+        _ => None,
+    }
+}
+
+/// Get the table name of a `CREATE TABLE ...` or `INSERT INTO ...` query
+pub fn get_table_name_from_query(tokens: &[Token]) -> Option<&str> {
+    get_table_name_position(tokens).and_then(|pos| get_word_value_at_position(tokens, pos))
+}
+
+/// Get the column names of an `INSERT INTO <table> (<columns>) VALUES (...);` query -
+/// empty when the query has no column names, as the ones of the `.dump` command of sqlite3
+pub fn get_column_names_from_insert_into_query(tokens: &[Token]) -> Vec<&str> {
+    if !is_insert_into_statement(tokens) {
+        // it means that the query is not an INSERT INTO.. one
+        return Vec::new();
+    }
+
+    let pos = match get_table_name_position(tokens) {
+        Some(pos) => pos,
+        None => return Vec::new(),
+    };
+
+    let mut tokens = tokens
+        .iter()
+        .skip(pos + 1)
+        .skip_while(|token| matches!(token, Token::Whitespace(_)));
+
+    if tokens.next() != Some(&Token::LParen) {
+        return Vec::new();
+    }
+
+    tokens
+        .take_while(|token| **token != Token::RParen)
+        .filter_map(|token| match token {
+            Token::Word(word) => Some(word.value.as_str()), // column name
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Get the values of the first row of an `INSERT INTO ... VALUES (...);` query -
+/// a value is made of several tokens when it is an expression, i.e: `replace('a\nb','\n',char(10))`
+pub fn get_column_values_from_insert_into_query(tokens: &[Token]) -> Vec<Vec<&Token>> {
+    if !is_insert_into_statement(tokens) {
+        // it means that the query is not an INSERT INTO.. one
+        return Vec::new();
+    }
+
+    let tokens = tokens
+        .iter()
+        .skip_while(|token| !matches!(token, Token::Word(word) if word.keyword == Values))
+        .skip_while(|token| **token != Token::LParen)
+        .skip(1);
+
+    let mut values = vec![];
+    let mut value = vec![];
+    let mut depth = 0;
+
+    for token in tokens {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 0 => break,
+            Token::RParen => depth -= 1,
+            Token::Comma if depth == 0 => {
+                values.push(trim_whitespaces(value));
+                value = vec![];
+                continue;
+            }
+            _ => {}
+        }
+
+        value.push(token);
+    }
+
+    values.push(trim_whitespaces(value));
+
+    values
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>()
+}
+
+fn trim_whitespaces(tokens: Vec<&Token>) -> Vec<&Token> {
+    let start = tokens
+        .iter()
+        .position(|token| !matches!(token, Token::Whitespace(_)));
+
+    let end = tokens
+        .iter()
+        .rposition(|token| !matches!(token, Token::Whitespace(_)));
+
+    match (start, end) {
+        (Some(start), Some(end)) => tokens[start..=end].to_vec(),
+        _ => vec![],
+    }
+}
+
+/// words ending the type of a column definition - https://www.sqlite.org/syntax/column-constraint.html
+const COLUMN_CONSTRAINT_WORDS: [&str; 11] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "NOT",
+    "NULL",
+    "UNIQUE",
+    "CHECK",
+    "DEFAULT",
+    "COLLATE",
+    "REFERENCES",
+    "GENERATED",
+    "AS",
+];
+
+/// words starting a table constraint instead of a column definition
+const TABLE_CONSTRAINT_WORDS: [&str; 5] = ["CONSTRAINT", "PRIMARY", "UNIQUE", "CHECK", "FOREIGN"];
+
+/// Get the column names and types of a `CREATE TABLE <table> (<column> <type> ...);` query.
+/// Types are in lowercase and without their modifiers: `VARCHAR(40)` -> `varchar`,
+/// `UNSIGNED BIG INT` -> `unsigned big int`. A column can be declared without a type.
+pub fn get_column_types_from_create_table_query(tokens: &[Token]) -> Vec<(String, String)> {
+    let pos = match get_table_name_position(tokens) {
+        Some(pos) if is_create_table_statement(tokens) => pos,
+        // it means that the query is not a CREATE TABLE.. one
+        _ => return Vec::new(),
+    };
+
+    // split the column definitions on the commas which are not in parentheses
+    let mut definitions: Vec<Vec<&Token>> = vec![];
+    let mut definition = vec![];
+    let mut depth = 0;
+
+    for token in tokens
+        .iter()
+        .skip(pos + 1)
+        .skip_while(|token| **token != Token::LParen)
+    {
+        match token {
+            Token::LParen => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            Token::RParen => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            Token::Comma if depth == 1 => {
+                definitions.push(definition);
+                definition = vec![];
+                continue;
+            }
+            Token::Whitespace(_) => continue,
+            _ => {}
+        }
+
+        definition.push(token);
+    }
+
+    definitions.push(definition);
+
+    definitions
+        .into_iter()
+        .filter_map(|definition| {
+            let mut tokens = definition.into_iter();
+
+            let column_name = match tokens.next() {
+                Some(Token::SingleQuotedString(name)) => unescape_string_value(name),
+                Some(Token::Word(word))
+                    if word.quote_style.is_some()
+                        || !TABLE_CONSTRAINT_WORDS
+                            .contains(&word.value.to_uppercase().as_str()) =>
+                {
+                    word.value.to_string()
+                }
+                _ => return None,
+            };
+
+            let mut type_words = vec![];
+
+            for token in tokens {
+                match token {
+                    Token::Word(word)
+                        if word.quote_style.is_none()
+                            && !COLUMN_CONSTRAINT_WORDS
+                                .contains(&word.value.to_uppercase().as_str()) =>
+                    {
+                        type_words.push(word.value.to_lowercase());
+                    }
+                    _ => break,
+                }
+            }
+
+            Some((column_name, type_words.join(" ")))
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Unescape the value of a single quoted string as it is tokenized, e.g. `It''s` -> `It's`.
+pub fn unescape_string_value(value: &str) -> String {
+    value.replace("''", "'")
+}
+
+/// Escape a value to write it into a single quoted string, e.g. `It's` -> `It''s`.
+pub fn escape_string_value(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+/// Quote an identifier with double quotes, e.g. `my "table"` -> `"my ""table"""`.
+pub fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+pub fn get_tokens_from_query_str(query: &str) -> Vec<Token> {
+    // query by query
+    let mut tokenizer = Tokenizer::new(query);
+
+    let tokens = match tokenizer.tokenize() {
+        Ok(tokens) => tokens,
+        Err(err) => {
+            println!("failing query: '{}'", query);
+            panic!("{:?}", err)
+        }
+    };
+
+    trim_pre_whitespaces(tokens)
+}
+
+pub fn trim_pre_whitespaces(tokens: Vec<Token>) -> Vec<Token> {
+    tokens
+        .into_iter()
+        .skip_while(|token| match token {
+            // remove whitespaces (and comments) at the beginning of a vec of tokens
+            Token::Whitespace(_) => true,
+            _ => false,
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Split the complete statements at the start of `query` - as `sqlite3_complete()` does:
+/// the `;` in strings, quoted identifiers, comments and `CREATE TRIGGER ... BEGIN ... END;` bodies do not end a statement.
+/// Return the statements - without the whitespaces before them - and the length of `query` they span.
+/// The rest of `query` is an incomplete statement.
+pub fn list_complete_statements(query: &str) -> (Vec<&str>, usize) {
+    let mut statements = vec![];
+    let mut start = 0;
+    // the first words of the statement - to find out whether it is a trigger
+    let mut words: Vec<String> = vec![];
+    let mut last_word_is_end = false;
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\'' | '"' | '`' | '[' => {
+                let closing_char = if ch == '[' { ']' } else { ch };
+
+                // an escaped quote closes the quoted text and opens another one
+                if !chars.any(|(_, ch)| ch == closing_char) {
+                    break;
+                }
+
+                last_word_is_end = false;
+            }
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                if !chars.any(|(_, ch)| ch == '\n') {
+                    break;
+                }
+            }
+            '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                let _ = chars.next();
+                let mut previous_char = ' ';
+
+                if !chars.any(|(_, ch)| {
+                    let is_end = previous_char == '*' && ch == '/';
+                    previous_char = ch;
+                    is_end
+                }) {
+                    break;
+                }
+            }
+            ';' => {
+                if !is_trigger(&words) || last_word_is_end {
+                    statements.push(query[start..=idx].trim_start());
+                    start = idx + 1;
+                    words.clear();
+                }
+
+                last_word_is_end = false;
+            }
+            ch if ch.is_whitespace() => {}
+            ch if is_identifier_start(ch) => {
+                let mut word = ch.to_string();
+
+                while let Some((_, ch)) = chars.peek() {
+                    if !is_identifier_part(*ch) {
+                        break;
+                    }
+
+                    word.push(*ch);
+                    let _ = chars.next();
+                }
+
+                last_word_is_end = word.eq_ignore_ascii_case("END");
+
+                if words.len() < 3 {
+                    words.push(word.to_uppercase());
+                }
+            }
+            _ => last_word_is_end = false,
+        }
+    }
+
+    (statements, start)
+}
+
+/// `CREATE [TEMP|TEMPORARY] TRIGGER ...`
+fn is_trigger(words: &[String]) -> bool {
+    match words {
+        [create, trigger, ..] if create == "CREATE" && trigger == "TRIGGER" => true,
+        [create, temp, trigger] => {
+            create == "CREATE" && (temp == "TEMP" || temp == "TEMPORARY") && trigger == "TRIGGER"
+        }
+        _ => false,
+    }
+}
+
+/// true when `query` holds only whitespaces and comments
+fn is_blank(query: &str) -> bool {
+    let mut query = query.trim_start();
+
+    loop {
+        if let Some(comment) = query.strip_prefix("--") {
+            query = match comment.find('\n') {
+                Some(end) => comment[end..].trim_start(),
+                None => "",
+            };
+        } else if let Some(comment) = query.strip_prefix("/*") {
+            query = match comment.find("*/") {
+                Some(end) => comment[end + 2..].trim_start(),
+                None => return false,
+            };
+        } else {
+            return query.is_empty();
+        }
+    }
+}
+
+/// read a SQLite dump and callback query function with each statement of the dump -
+/// the statements are split as SQLite does, with no backslash escapes in strings.
+pub fn list_statements_from_dump_reader<R, F>(
+    mut dump_reader: BufReader<R>,
+    mut query: F,
+) -> Result<(), DumpFileError>
+where
+    R: Read,
+    F: FnMut(&str) -> ListQueryResult,
+{
+    let mut buf = String::new();
+    let mut line_buf_bytes: Vec<u8> = Vec::new();
+
+    loop {
+        line_buf_bytes.clear();
+
+        let total_bytes = match dump_reader.read_until(b'\n', &mut line_buf_bytes) {
+            Ok(bytes) => bytes,
+            Err(err) => return Err(ReadError(err)),
+        };
+
+        if total_bytes == 0 {
+            // EOF
+            break;
+        }
+
+        match str::from_utf8(line_buf_bytes.as_slice()) {
+            Ok(line) => buf.push_str(line),
+            Err(_) => return Err(MalFormatted),
+        }
+
+        // a statement can only end on a line ending with a ';'
+        if !buf.trim_end().ends_with(';') {
+            continue;
+        }
+
+        let (statements, len) = list_complete_statements(buf.as_str());
+
+        for statement in statements {
+            if let ListQueryResult::Break = query(statement) {
+                return Ok(());
+            }
+        }
+
+        let _ = buf.drain(..len);
+    }
+
+    // the comments at the end of the dump are sent as they are
+    match buf.trim_start() {
+        "" => Ok(()),
+        rest if is_blank(rest) => {
+            let _ = query(rest);
+            Ok(())
+        }
+        _ => Err(MalFormatted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use crate::sqlite::{
+        escape_string_value, get_column_names_from_insert_into_query,
+        get_column_types_from_create_table_query, get_column_values_from_insert_into_query,
+        get_table_name_from_query, get_tokens_from_query_str, is_create_table_statement,
+        is_insert_into_statement, list_complete_statements, list_statements_from_dump_reader,
+        quote_identifier, unescape_string_value, Token, Tokenizer, Whitespace,
+    };
+    use crate::utils::ListQueryResult;
+
+    #[test]
+    fn tokenizer_for_insert_into_query() {
+        let q = "INSERT INTO \"my \"\"table\"\"\" (id, [first name], `note`, picture) VALUES(-1.5e3,'It''s',NULL,X'01FF');";
+
+        let mut tokenizer = Tokenizer::new(q);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        assert!(is_insert_into_statement(&tokens));
+        assert_eq!(tokens[4], Token::make_word("my \"table\"", Some('"')));
+        assert_eq!(get_table_name_from_query(&tokens), Some("my \"table\""));
+        assert_eq!(tokens.last(), Some(&Token::SemiColon));
+
+        // the tokens are written back as they are
+        let query = tokens
+            .iter()
+            .map(|token| token.to_string())
+            .collect::<String>();
+        assert_eq!(query, q);
+    }
+
+    #[test]
+    fn tokenizer_for_comments() {
+        let q = "-- comment\n/* multi\nline */SELECT 0x1F, .5;";
+
+        let mut tokenizer = Tokenizer::new(q);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        assert_eq!(
+            tokens[0],
+            Token::Whitespace(Whitespace::SingleLineComment {
+                comment: " comment\n".to_string(),
+                prefix: "--".to_string(),
+            })
+        );
+        assert_eq!(
+            tokens[1],
+            Token::Whitespace(Whitespace::MultiLineComment(" multi\nline ".to_string()))
+        );
+        assert_eq!(tokens[4], Token::Number("0x1F".to_string()));
+        assert_eq!(tokens[7], Token::Number(".5".to_string()));
+
+        assert!(Tokenizer::new("SELECT 'a").tokenize().is_err());
+        assert!(Tokenizer::new("SELECT \"a").tokenize().is_err());
+        assert!(Tokenizer::new("SELECT /* a").tokenize().is_err());
+    }
+
+    #[test]
+    fn test_get_column_names_from_insert_into_query() {
+        let tokens = get_tokens_from_query_str(
+            "INSERT INTO \"users\" (\"id\", \"first_name\") VALUES(1,'Romaric');",
+        );
+        assert_eq!(
+            get_column_names_from_insert_into_query(&tokens),
+            vec!["id", "first_name"]
+        );
+
+        // as written by the `.dump` command of sqlite3
+        let tokens = get_tokens_from_query_str("INSERT INTO users VALUES(1,'Romaric');");
+        assert!(get_column_names_from_insert_into_query(&tokens).is_empty());
+
+        let tokens = get_tokens_from_query_str("SELECT 1;");
+        assert!(get_column_names_from_insert_into_query(&tokens).is_empty());
+    }
+
+    #[test]
+    fn test_get_column_values_from_insert_into_query() {
+        let tokens = get_tokens_from_query_str(
+            "INSERT INTO users VALUES(1, 'It''s', NULL, -2.5, X'01', replace('a\\nb','\\n',char(10)));",
+        );
+
+        let values = get_column_values_from_insert_into_query(&tokens);
+        assert_eq!(values.len(), 6);
+        assert_eq!(values[0], vec![&Token::Number("1".to_string())]);
+        assert_eq!(
+            values[1],
+            vec![&Token::SingleQuotedString("It''s".to_string())]
+        );
+        assert_eq!(values[2], vec![&Token::make_keyword("NULL")]);
+        assert_eq!(values[3], vec![&Token::Number("-2.5".to_string())]);
+        assert_eq!(values[4], vec![&Token::HexStringLiteral("01".to_string())]);
+        assert_eq!(
+            values[5]
+                .iter()
+                .map(|token| token.to_string())
+                .collect::<String>(),
+            "replace('a\\nb','\\n',char(10))"
+        );
+    }
+
+    #[test]
+    fn test_get_column_types_from_create_table_query() {
+        let tokens = get_tokens_from_query_str(
+            "CREATE TABLE IF NOT EXISTS \"users\" (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  \"first name\" VARCHAR(40) NOT NULL DEFAULT '',
+  age UNSIGNED BIG INT,
+  note,
+  created_at DATETIME DEFAULT (datetime('now')),
+  CONSTRAINT users_age CHECK (age > 0),
+  UNIQUE (\"first name\", age)
+);",
+        );
+
+        assert!(is_create_table_statement(&tokens));
+        assert_eq!(get_table_name_from_query(&tokens), Some("users"));
+        assert_eq!(
+            get_column_types_from_create_table_query(&tokens),
+            vec![
+                ("id".to_string(), "integer".to_string()),
+                ("first name".to_string(), "varchar".to_string()),
+                ("age".to_string(), "unsigned big int".to_string()),
+                ("note".to_string(), "".to_string()),
+                ("created_at".to_string(), "datetime".to_string()),
+            ]
+        );
+
+        let tokens = get_tokens_from_query_str("CREATE TABLE main.orders(id INT);");
+        assert_eq!(get_table_name_from_query(&tokens), Some("orders"));
+        assert_eq!(
+            get_column_types_from_create_table_query(&tokens),
+            vec![("id".to_string(), "int".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_list_complete_statements() {
+        let query = "INSERT INTO t VALUES('a;b'); -- c;\n\
+        CREATE TRIGGER tr AFTER INSERT ON t BEGIN UPDATE t SET \"x;\" = 1; DELETE FROM u; END;\n\
+        /* d; */ SELECT 1;\nSELECT 'e";
+
+        let (statements, len) = list_complete_statements(query);
+
+        assert_eq!(
+            statements,
+            vec![
+                "INSERT INTO t VALUES('a;b');",
+                "-- c;\nCREATE TRIGGER tr AFTER INSERT ON t BEGIN UPDATE t SET \"x;\" = 1; DELETE FROM u; END;",
+                "/* d; */ SELECT 1;",
+            ]
+        );
+        assert_eq!(&query[len..], "\nSELECT 'e");
+
+        let (statements, len) = list_complete_statements("CREATE TEMP TRIGGER tr BEGIN SELECT 1;");
+        assert!(statements.is_empty());
+        assert_eq!(len, 0);
+    }
+
+    #[test]
+    fn test_list_statements_from_dump_reader() {
+        let dump = "PRAGMA foreign_keys=OFF;\nBEGIN TRANSACTION;\n\
+        INSERT INTO t VALUES('multi\nline;\n','C:\\');\nCOMMIT;\n-- end\n";
+
+        let mut statements = vec![];
+        list_statements_from_dump_reader(BufReader::new(dump.as_bytes()), |statement| {
+            statements.push(statement.to_string());
+            ListQueryResult::Continue
+        })
+        .unwrap();
+
+        assert_eq!(
+            statements,
+            vec![
+                "PRAGMA foreign_keys=OFF;",
+                "BEGIN TRANSACTION;",
+                "INSERT INTO t VALUES('multi\nline;\n','C:\\');",
+                "COMMIT;",
+                "-- end\n",
+            ]
+        );
+
+        let dump = "SELECT 1;\nSELECT 'a;\n";
+        assert!(
+            list_statements_from_dump_reader(BufReader::new(dump.as_bytes()), |_| {
+                ListQueryResult::Continue
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_escape_string_value() {
+        assert_eq!(escape_string_value("It's C:\\"), "It''s C:\\");
+        assert_eq!(unescape_string_value("It''s C:\\"), "It's C:\\");
+        assert_eq!(quote_identifier("my \"table\""), "\"my \"\"table\"\"\"");
+    }
+}
@@ -1,10 +1,11 @@
 use crate::DumpFileError;
-use crate::DumpFileError::ReadError;
+use crate::DumpFileError::{MalFormatted, ReadError};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::str;
 
 const COMMENT_CHARS: &str = "--";
+const END_OF_COPY_DATA: &str = "\\.";
 
 pub enum ListQueryResult {
     Continue,
@@ -29,7 +30,9 @@ where
     list_sql_queries_from_dump_reader(reader, query)
 }
 
-/// read dump and callback query function with each valid query inside the dump
+/// read dump and callback query function with each valid query inside the dump.
+/// The data rows following a `COPY ... FROM stdin;` query are sent one by one (without the trailing newline),
+/// until the end of data marker `\.` which is sent as well.
 pub fn list_sql_queries_from_dump_reader<R, F>(
     mut dump_reader: BufReader<R>,
     mut query: F,
@@ -41,6 +44,7 @@ where
     let mut count_empty_lines = 0;
     let mut buf_bytes: Vec<u8> = Vec::new();
     let mut line_buf_bytes: Vec<u8> = Vec::new();
+    let mut is_copy_data = false;
 
     loop {
         let bytes = dump_reader.read_until(b'\n', &mut line_buf_bytes);
@@ -49,6 +53,35 @@ where
             Err(err) => return Err(ReadError(err)),
         };
 
+        if is_copy_data {
+            if total_bytes == 0 {
+                // EOF
+                break;
+            }
+
+            // COPY data rows are not SQL - send them as they are
+            let row = match str::from_utf8(line_buf_bytes.as_slice()) {
+                Ok(row) => row,
+                Err(_) => return Err(MalFormatted),
+            };
+            let row = row.strip_suffix('\n').unwrap_or(row);
+            let row = row.strip_suffix('\r').unwrap_or(row);
+
+            if row == END_OF_COPY_DATA {
+                is_copy_data = false;
+            }
+
+            count_empty_lines = 0;
+
+            let query_res = query(row);
+            line_buf_bytes.clear();
+
+            match query_res {
+                ListQueryResult::Continue => continue,
+                ListQueryResult::Break => break,
+            }
+        }
+
         let last_real_char_idx = if buf_bytes.len() > 1 {
             buf_bytes.len() - 2
         } else if buf_bytes.len() == 1 {
@@ -63,11 +96,17 @@ where
             None => false,
         };
 
+        // a COPY query is directly followed by its data rows, so it must be processed right away
+        let is_copy_from_stdin_line = match str::from_utf8(line_buf_bytes.as_slice()) {
+            Ok(line) => is_copy_from_stdin_statement(line),
+            Err(_) => false,
+        };
+
         let mut query_res = ListQueryResult::Continue;
 
         buf_bytes.append(&mut line_buf_bytes);
 
-        if total_bytes <= 1 || is_last_line_buf_bytes_by_end_of_query {
+        if total_bytes <= 1 || is_last_line_buf_bytes_by_end_of_query || is_copy_from_stdin_line {
             let mut buf_bytes_to_keep: Vec<u8> = Vec::new();
 
             if buf_bytes.len() > 1 {
@@ -84,6 +123,11 @@ where
                         Statement::Query(sql_statement) => {
                             if sql_statement.valid {
                                 query(sql_statement.statement);
+
+                                if is_copy_from_stdin_statement(sql_statement.statement) {
+                                    // the next lines are data rows
+                                    is_copy_data = true;
+                                }
                             } else {
                                 // the query is not complete, so keep it for the next iteration
                                 buf_bytes_to_keep
@@ -117,6 +161,17 @@ where
     Ok(())
 }
 
+fn is_copy_from_stdin_statement(statement: &str) -> bool {
+    let statement = statement.trim_start();
+
+    statement.len() > 4
+        && statement[0..4].eq_ignore_ascii_case("COPY")
+        && statement
+            .to_ascii_uppercase()
+            .trim_end()
+            .ends_with("FROM STDIN;")
+}
+
 /// Decodes a hex string to a byte `Vec`.
 /// #### example:
 ///
@@ -301,10 +356,92 @@ fn list_statements(query: &str) -> Vec<Statement> {
 #[cfg(test)]
 mod tests {
     use crate::utils::{
-        list_sql_queries_from_dump_reader, list_statements, ListQueryResult, Statement,
+        is_copy_from_stdin_statement, list_sql_queries_from_dump_reader, list_statements,
+        ListQueryResult, Statement,
     };
+    use crate::DumpFileError::MalFormatted;
     use std::io::BufReader;
 
+    #[test]
+    fn check_list_sql_queries_with_copy_from_stdin_from_dump_reader() {
+        let r = r#"CREATE TABLE public.users (
+    id integer NOT NULL,
+    name text
+);
+
+COPY public.users (id, name) FROM stdin;
+1	John's (first; name
+2	\N
+3	multi\nline -- not a comment
+\.
+
+ALTER TABLE ONLY public.users ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+"#
+        .as_bytes();
+        let reader = BufReader::new(r);
+
+        let mut queries = vec![];
+
+        list_sql_queries_from_dump_reader(reader, |query| {
+            if query != "\n" {
+                queries.push(query.to_string());
+            }
+            ListQueryResult::Continue
+        })
+        .unwrap();
+
+        let copy_idx = queries
+            .iter()
+            .position(|query| query.starts_with("COPY public.users"))
+            .unwrap();
+
+        assert_eq!(queries[copy_idx + 1], "1\tJohn's (first; name");
+        assert_eq!(queries[copy_idx + 2], "2\t\\N");
+        assert_eq!(queries[copy_idx + 3], "3\tmulti\\nline -- not a comment");
+        assert_eq!(queries[copy_idx + 4], "\\.");
+        assert!(queries
+            .iter()
+            .skip(copy_idx + 5)
+            .any(|query| query.starts_with("ALTER TABLE ONLY public.users")));
+    }
+
+    #[test]
+    fn check_list_sql_queries_with_non_utf8_copy_data_from_dump_reader() {
+        let mut r = b"COPY public.users (id, name) FROM stdin;\n1\tJos".to_vec();
+        r.extend_from_slice(&[0xe9, b'\n']);
+        r.extend_from_slice(b"\\.\n");
+        let reader = BufReader::new(r.as_slice());
+
+        let mut queries = vec![];
+
+        let result = list_sql_queries_from_dump_reader(reader, |query| {
+            queries.push(query.to_string());
+            ListQueryResult::Continue
+        });
+
+        assert!(matches!(result, Err(MalFormatted)));
+        assert!(queries
+            .iter()
+            .any(|query| query.starts_with("COPY public.users")));
+        assert!(!queries.iter().any(|query| query.starts_with("1\t")));
+    }
+
+    #[test]
+    fn check_is_copy_from_stdin_statement() {
+        assert!(is_copy_from_stdin_statement(
+            "COPY public.users (id, name) FROM stdin;"
+        ));
+        assert!(is_copy_from_stdin_statement(
+            "\ncopy public.users (id, name) from stdin;"
+        ));
+        assert!(!is_copy_from_stdin_statement(
+            "COPY public.users (id, name) TO stdout;"
+        ));
+        assert!(!is_copy_from_stdin_statement(
+            "INSERT INTO public.users (id, name) VALUES (1, 'FROM stdin;');"
+        ));
+    }
+
     #[test]
     fn check_list_sql_queries_from_dump_reader() {
         let r = r#"INSERT INTO public.Users(uuid, "text", name) VALUES ('a84ac0c6-2348-45c0-b86c-8d34e251a859', 'Lorem ipsum dolor sit amet, consectetur adipiscing elit. Cras eu nisi tempor, viverra turpis sit amet, sodales augue. Vivamus sit amet erat urna. Morbi porta, quam nec consequat suscipit, ante diam tempus risus, et consequat erat odio sed magna. Maecenas dignissim quam nibh, nec congue magna convallis a.
@@ -26,6 +26,9 @@ pub enum SubCommand {
     /// all transformers command
     #[clap(subcommand)]
     Transformer(TransformerCommand),
+    /// all source commands
+    #[clap(subcommand)]
+    Source(SourceCommand),
 }
 
 /// all dump commands
@@ -40,6 +43,8 @@ pub enum DumpCommand {
     Restore(RestoreCommand),
     /// delete a dump from the defined datastore
     Delete(DumpDeleteArgs),
+    /// check the chunks of a dump against their checksums without restoring it
+    Verify(DumpVerifyArgs),
 }
 
 /// all transformer commands
@@ -47,6 +52,15 @@ pub enum DumpCommand {
 pub enum TransformerCommand {
     /// list available transformers
     List,
+    /// print the original and transformed rows of a table -- use `-h` to show all the options
+    Preview(TransformerPreviewArgs),
+}
+
+/// all source commands
+#[derive(Subcommand, Debug)]
+pub enum SourceCommand {
+    /// detect the columns with personal data and print the transformers configuration -- use `-h` to show all the options
+    Scan(SourceScanArgs),
 }
 
 /// all restore commands
@@ -107,6 +121,9 @@ pub struct DumpCreateArgs {
     /// dump name
     #[clap(short, long)]
     pub name: Option<String>,
+    /// resume the last interrupted dump - or the dump set with `--name` - from its last written chunk
+    #[clap(long)]
+    pub resume: bool,
 }
 
 #[derive(Args, Debug)]
@@ -122,3 +139,45 @@ pub struct DumpDeleteArgs {
     #[clap(long, group = "delete-mode")]
     pub keep_last: Option<usize>,
 }
+
+/// verify a dump
+#[derive(Args, Debug)]
+pub struct DumpVerifyArgs {
+    /// dump to verify -- set `latest` or `<dump name>` - use `dump list` command to list all dumps available
+    #[clap(value_name = "[latest | dump name]")]
+    pub value: String,
+}
+
+/// scan the source for personal data
+#[derive(Args, Debug)]
+pub struct SourceScanArgs {
+    #[clap(name = "source_type", short, long, value_name = "[postgresql | mysql]", possible_values = &["postgresql", "mysql"], requires = "input")]
+    /// database source type to import
+    pub source_type: Option<String>,
+    /// import dump from stdin
+    #[clap(name = "input", short, long, requires = "source_type")]
+    pub input: bool,
+    /// maximum number of values sampled by column
+    #[clap(long, default_value_t = 1000)]
+    pub sample_size: usize,
+    /// minimum confidence score (between 0 and 1) of the reported columns
+    #[clap(long, default_value_t = 0.5)]
+    pub min_confidence: f64,
+}
+
+/// preview the transformers of a table
+#[derive(Args, Debug)]
+pub struct TransformerPreviewArgs {
+    #[clap(name = "source_type", short, long, value_name = "[postgresql | mysql]", possible_values = &["postgresql", "mysql"], requires = "input")]
+    /// database source type to import
+    pub source_type: Option<String>,
+    /// import dump from stdin
+    #[clap(name = "input", short, long, requires = "source_type")]
+    pub input: bool,
+    /// table to preview
+    #[clap(short, long, value_name = "database.table")]
+    pub table: String,
+    /// maximum number of rows to preview
+    #[clap(short, long, default_value_t = 20)]
+    pub limit: usize,
+}
@@ -5,11 +5,14 @@ use std::time::Duration;
 
 use timeago::Formatter;
 
-use crate::cli::{DumpCreateArgs, DumpDeleteArgs};
+use crate::cli::{DumpCreateArgs, DumpDeleteArgs, DumpVerifyArgs};
 use crate::cli::{RestoreArgs, RestoreLocalArgs};
-use crate::config::{Config, ConnectionUri};
-use crate::datastore::Datastore;
+use crate::config::{
+    Config, ConnectionUri, DestinationConfig, DestinationWriterConfig, SourceConfig,
+    SourceReaderConfig,
+};
 use crate::datastore::ReadOptions;
+use crate::datastore::{Datastore, DumpStatus};
 use crate::destination::generic_stdout::GenericStdout;
 use crate::destination::mongodb_docker::{MongoDBDocker, DEFAULT_MONGO_CONTAINER_PORT};
 use crate::destination::mysql_docker::{
@@ -19,16 +22,21 @@ use crate::destination::postgres_docker::{
     PostgresDocker, DEFAULT_POSTGRES_CONTAINER_PORT, DEFAULT_POSTGRES_DB,
     DEFAULT_POSTGRES_IMAGE_TAG, DEFAULT_POSTGRES_PASSWORD, DEFAULT_POSTGRES_USER,
 };
+use crate::leak_detector::{LeakDetector, LeakReport};
+use crate::scanner::{Dialect, UNKNOWN_MYSQL_DATABASE};
 use crate::source::mongodb::MongoDB;
 use crate::source::mongodb_stdin::MongoDBStdin;
 use crate::source::mysql::Mysql;
 use crate::source::mysql_stdin::MysqlStdin;
 use crate::source::postgres::Postgres;
+use crate::source::postgres_direct::PostgresDirect;
 use crate::source::postgres_stdin::PostgresStdin;
+use crate::source::sqlite::Sqlite;
 use crate::source::SourceOptions;
-use crate::tasks::full_dump::FullDumpTask;
+use crate::tasks::full_dump::{FullDumpTask, ResumePoint};
 use crate::tasks::full_restore::FullRestoreTask;
 use crate::tasks::Task;
+use crate::transformer::{DeterministicKey, Transformer};
 use crate::utils::{epoch_millis, table, to_human_readable_unit};
 use crate::{destination, CLI};
 use clap::CommandFactory;
@@ -45,7 +53,14 @@ pub fn list(datastore: &mut Box<dyn Datastore>) -> Result<(), Error> {
     index_file.dumps.sort_by(|a, b| a.cmp(b).reverse());
 
     let mut table = table();
-    table.set_titles(row!["name", "size", "when", "compressed", "encrypted"]);
+    table.set_titles(row![
+        "name",
+        "size",
+        "when",
+        "compressed",
+        "encrypted",
+        "status"
+    ]);
     let formatter = Formatter::new();
     let now = epoch_millis();
 
@@ -56,6 +71,7 @@ pub fn list(datastore: &mut Box<dyn Datastore>) -> Result<(), Error> {
             formatter.convert(Duration::from_millis((now - dump.created_at) as u64)),
             dump.compressed,
             dump.encrypted,
+            dump.status,
         ]);
     }
 
@@ -64,6 +80,165 @@ pub fn list(datastore: &mut Box<dyn Datastore>) -> Result<(), Error> {
     Ok(())
 }
 
+/// transformers of the columns configured in <source.transformers>
+pub fn transformers(source: &SourceConfig) -> Result<Vec<Box<dyn Transformer>>, Error> {
+    let deterministic_key = source.deterministic_secret()?.map(DeterministicKey::new);
+
+    let mut transformers = vec![];
+    for transformer in source.transformers.iter().flatten() {
+        for column in &transformer.columns {
+            let is_deterministic = column
+                .is_deterministic(transformer.database.as_str(), transformer.table.as_str())?;
+
+            let column_deterministic_key = match &deterministic_key {
+                Some(key) if is_deterministic => Some(key.clone()),
+                None if is_deterministic => {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        format!(
+                            "<source.deterministic_secret> is missing while column \"{}.{}.{}\" is deterministic",
+                            transformer.database, transformer.table, column.name
+                        ),
+                    ));
+                }
+                _ => None,
+            };
+
+            transformers.push(column.transformer.transformer(
+                transformer.database.as_str(),
+                transformer.table.as_str(),
+                column.name.as_str(),
+                column_deterministic_key,
+            ));
+        }
+    }
+
+    Ok(transformers)
+}
+
+/// find the dump to resume - the dump set with `--name` or the last one which is not completed - and
+/// continue writing it: the compression and the encryption can't differ from its chunks
+fn resume_point(
+    datastore: &mut Box<dyn Datastore>,
+    args: &DumpCreateArgs,
+) -> Result<ResumePoint, Error> {
+    let mut index_file = datastore.index_file()?;
+    index_file
+        .dumps
+        .sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    let dump = match &args.name {
+        Some(name) => index_file
+            .dumps
+            .iter()
+            .find(|dump| &dump.directory_name == name),
+        None => index_file
+            .dumps
+            .iter()
+            .rev()
+            .find(|dump| dump.status != DumpStatus::Completed),
+    };
+
+    let dump = match dump {
+        Some(dump) if dump.status == DumpStatus::Completed => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("dump '{}' is already completed", dump.directory_name),
+            ));
+        }
+        Some(dump) => dump,
+        None => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "no interrupted dump to resume",
+            ));
+        }
+    };
+
+    let compression_codec = datastore.compression().map(|options| options.codec);
+    if dump.compressed != compression_codec.is_some()
+        || (dump.compressed && dump.compression_codec != compression_codec)
+    {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "dump '{}' can't be resumed - <source.compression> differs from its chunks",
+                dump.directory_name
+            ),
+        ));
+    }
+
+    if dump.encrypted != datastore.encryption_key().is_some() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "dump '{}' can't be resumed - <encryption_key> differs from its chunks",
+                dump.directory_name
+            ),
+        ));
+    }
+
+    let resume_point = ResumePoint::new(dump)?;
+    datastore.set_dump_name(dump.directory_name.to_string());
+
+    Ok(resume_point)
+}
+
+fn leak_detector(
+    source: &SourceConfig,
+    dialect: Dialect,
+    database_name: &str,
+) -> Option<LeakDetector> {
+    source
+        .leak_detection
+        .as_ref()
+        .map(|config| LeakDetector::new(dialect, database_name, config))
+}
+
+fn check_direct_reader_support(source: &SourceConfig) -> Result<(), Error> {
+    if source.reader == Some(SourceReaderConfig::Direct) {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "<source.reader: direct> supports PostgreSQL sources only",
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_direct_writer_support(destination: &DestinationConfig) -> Result<(), Error> {
+    if destination.writer == Some(DestinationWriterConfig::Direct) {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "<destination.writer: direct> supports PostgreSQL destinations only",
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_leak_detection_support(source: &SourceConfig) -> Result<(), Error> {
+    if source.leak_detection.is_some() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "<source.leak_detection> supports PostgreSQL and MySQL sources only",
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_workers_support(source: &SourceConfig) -> Result<(), Error> {
+    if source.workers()? > 1 {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "<source.workers> supports PostgreSQL sources only",
+        ));
+    }
+
+    Ok(())
+}
+
 // Create a new dump
 pub fn run<F>(
     args: &DumpCreateArgs,
@@ -81,25 +256,17 @@ where
     match config.source {
         Some(source) => {
             // Configure datastore options (compression is enabled by default)
-            datastore.set_compression(source.compression.unwrap_or(true));
+            datastore.set_compression(source.compression()?);
+            let chunk_size = source.chunk_size()?;
 
-            // Match the transformers from the config
-            let transformers = match &source.transformers {
-                Some(transformers) => transformers
-                    .iter()
-                    .flat_map(|transformer| {
-                        transformer.columns.iter().map(|column| {
-                            column.transformer.transformer(
-                                transformer.database.as_str(),
-                                transformer.table.as_str(),
-                                column.name.as_str(),
-                            )
-                        })
-                    })
-                    .collect::<Vec<_>>(),
-                None => vec![],
+            let resume_point = match args.resume {
+                true => Some(resume_point(&mut datastore, args)?),
+                false => None,
             };
 
+            // Match the transformers from the config
+            let transformers = transformers(&source)?;
+
             let empty_config = vec![];
             let skip_config = match &source.skip {
                 Some(config) => config,
@@ -112,6 +279,18 @@ where
                 None => &empty_config,
             };
 
+            let empty_config = vec![];
+            let filters_config = match &source.filters {
+                Some(config) => config,
+                None => &empty_config,
+            };
+
+            let empty_config = vec![];
+            let exclude_columns_config = match &source.exclude_columns {
+                Some(config) => config,
+                None => &empty_config,
+            };
+
             for only_table in only_tables_config {
                 for skip in skip_config {
                     if only_table.database == skip.database && only_table.table == skip.table {
@@ -132,10 +311,33 @@ where
                 skip_config: &skip_config,
                 database_subset: &source.database_subset,
                 only_tables: &only_tables_config,
+                filters: &filters_config,
+                exclude_columns: &exclude_columns_config,
+                strict: &source.strict,
+                workers: source.workers()?,
             };
 
-            match args.source_type.as_ref().map(|x| x.as_str()) {
+            let leak_detector = match args.source_type.as_ref().map(|x| x.as_str()) {
                 None => match source.connection_uri()? {
+                    ConnectionUri::Postgres(host, port, username, password, database)
+                        if source.reader == Some(SourceReaderConfig::Direct) =>
+                    {
+                        let postgres = PostgresDirect::new(
+                            host.as_str(),
+                            port,
+                            database.as_str(),
+                            username.as_str(),
+                            password.as_str(),
+                        );
+
+                        let mut leak_detector = leak_detector(&source, Dialect::Postgres, "");
+                        let task = FullDumpTask::new(postgres, datastore, options)
+                            .with_chunk_size(chunk_size)
+                            .with_leak_detector(leak_detector.as_mut())
+                            .with_resume_point(resume_point);
+                        task.run(progress_callback)?;
+                        leak_detector
+                    }
                     ConnectionUri::Postgres(host, port, username, password, database) => {
                         let postgres = Postgres::new(
                             host.as_str(),
@@ -145,10 +347,17 @@ where
                             password.as_str(),
                         );
 
-                        let task = FullDumpTask::new(postgres, datastore, options);
-                        task.run(progress_callback)?
+                        let mut leak_detector = leak_detector(&source, Dialect::Postgres, "");
+                        let task = FullDumpTask::new(postgres, datastore, options)
+                            .with_chunk_size(chunk_size)
+                            .with_leak_detector(leak_detector.as_mut())
+                            .with_resume_point(resume_point);
+                        task.run(progress_callback)?;
+                        leak_detector
                     }
                     ConnectionUri::Mysql(host, port, username, password, database) => {
+                        let _ = check_direct_reader_support(&source)?;
+                        let _ = check_workers_support(&source)?;
                         let mysql = Mysql::new(
                             host.as_str(),
                             port,
@@ -157,14 +366,38 @@ where
                             password.as_str(),
                         );
 
-                        let task = FullDumpTask::new(mysql, datastore, options);
-                        task.run(progress_callback)?
+                        let mut leak_detector =
+                            leak_detector(&source, Dialect::Mysql, database.as_str());
+                        let task = FullDumpTask::new(mysql, datastore, options)
+                            .with_chunk_size(chunk_size)
+                            .with_leak_detector(leak_detector.as_mut())
+                            .with_resume_point(resume_point);
+                        task.run(progress_callback)?;
+                        leak_detector
                     }
                     ConnectionUri::MongoDB(uri, database) => {
                         let mongodb = MongoDB::new(uri.as_str(), database.as_str());
 
-                        let task = FullDumpTask::new(mongodb, datastore, options);
-                        task.run(progress_callback)?
+                        let _ = check_direct_reader_support(&source)?;
+                        let _ = check_leak_detection_support(&source)?;
+                        let _ = check_workers_support(&source)?;
+                        let task = FullDumpTask::new(mongodb, datastore, options)
+                            .with_chunk_size(chunk_size)
+                            .with_resume_point(resume_point);
+                        task.run(progress_callback)?;
+                        None
+                    }
+                    ConnectionUri::Sqlite(path) => {
+                        let sqlite = Sqlite::new(path.as_str());
+
+                        let _ = check_direct_reader_support(&source)?;
+                        let _ = check_leak_detection_support(&source)?;
+                        let _ = check_workers_support(&source)?;
+                        let task = FullDumpTask::new(sqlite, datastore, options)
+                            .with_chunk_size(chunk_size)
+                            .with_resume_point(resume_point);
+                        task.run(progress_callback)?;
+                        None
                     }
                 },
                 // some user use "postgres" and "postgresql" both are valid
@@ -177,8 +410,13 @@ where
                     }
 
                     let postgres = PostgresStdin::default();
-                    let task = FullDumpTask::new(postgres, datastore, options);
-                    task.run(progress_callback)?
+                    let mut leak_detector = leak_detector(&source, Dialect::Postgres, "");
+                    let task = FullDumpTask::new(postgres, datastore, options)
+                        .with_chunk_size(chunk_size)
+                        .with_leak_detector(leak_detector.as_mut())
+                        .with_resume_point(resume_point);
+                    task.run(progress_callback)?;
+                    leak_detector
                 }
                 Some(v) if v == "mysql" => {
                     if args.file.is_some() {
@@ -189,8 +427,15 @@ where
                     }
 
                     let mysql = MysqlStdin::default();
-                    let task = FullDumpTask::new(mysql, datastore, options);
-                    task.run(progress_callback)?
+                    let _ = check_workers_support(&source)?;
+                    let mut leak_detector =
+                        leak_detector(&source, Dialect::Mysql, UNKNOWN_MYSQL_DATABASE);
+                    let task = FullDumpTask::new(mysql, datastore, options)
+                        .with_chunk_size(chunk_size)
+                        .with_leak_detector(leak_detector.as_mut())
+                        .with_resume_point(resume_point);
+                    task.run(progress_callback)?;
+                    leak_detector
                 }
                 Some(v) if v == "mongodb" => {
                     if args.file.is_some() {
@@ -201,8 +446,13 @@ where
                     }
 
                     let mongodb = MongoDBStdin::default();
-                    let task = FullDumpTask::new(mongodb, datastore, options);
-                    task.run(progress_callback)?
+                    let _ = check_leak_detection_support(&source)?;
+                    let _ = check_workers_support(&source)?;
+                    let task = FullDumpTask::new(mongodb, datastore, options)
+                        .with_chunk_size(chunk_size)
+                        .with_resume_point(resume_point);
+                    task.run(progress_callback)?;
+                    None
                 }
                 Some(v) => {
                     return Err(anyhow::Error::from(Error::new(
@@ -210,9 +460,24 @@ where
                         format!("source type '{}' not recognized", v),
                     )));
                 }
+            };
+
+            match args.resume {
+                true => println!("Dump resumed successfully!"),
+                false => println!("Dump created successfully!"),
+            }
+
+            if let Some(leak_detector) = leak_detector {
+                let leaks = leak_detector.leaks();
+
+                if !leaks.is_empty() {
+                    println!(
+                        "Leak detection: original values of transformed columns found in the dump\n{}",
+                        LeakReport(&leaks)
+                    );
+                }
             }
 
-            println!("Dump created successfully!");
             Ok(())
         }
         None => {
@@ -230,6 +495,57 @@ pub fn delete(datastore: Box<dyn Datastore>, args: &DumpDeleteArgs) -> anyhow::R
     Ok(())
 }
 
+/// Check the chunks of a dump against their checksums without restoring it
+pub fn verify(
+    mut datastore: Box<dyn Datastore>,
+    args: &DumpVerifyArgs,
+    config: Config,
+) -> anyhow::Result<()> {
+    if let Some(encryption_key) = config.encryption_key()? {
+        datastore.set_encryption_key(encryption_key);
+    }
+
+    let options = match args.value.as_str() {
+        "latest" => ReadOptions::Latest,
+        v => ReadOptions::Dump {
+            name: v.to_string(),
+        },
+    };
+
+    let mut index_file = datastore.index_file()?;
+    let dump = index_file.find_dump(&options)?;
+    let mismatches = datastore.verify(&options)?;
+
+    if !mismatches.is_empty() {
+        let mut message = format!(
+            "Dump '{}' is corrupted: {} chunk mismatch(es)",
+            dump.directory_name,
+            mismatches.len()
+        );
+
+        for mismatch in mismatches {
+            message.push_str(format!("\n - {}", mismatch).as_str());
+        }
+
+        return Err(anyhow::Error::from(Error::new(ErrorKind::Other, message)));
+    }
+
+    if dump.chunks.is_empty() {
+        println!(
+            "Dump '{}' has no checksums - it was created before they were recorded. All its chunks can be decrypted and decompressed.",
+            dump.directory_name
+        );
+    } else {
+        println!(
+            "Dump '{}' verified: {} chunk(s) match their checksums",
+            dump.directory_name,
+            dump.chunks.len()
+        );
+    }
+
+    Ok(())
+}
+
 /// Restore a dump in a local container
 pub fn restore_local<F>(
     args: &RestoreLocalArgs,
@@ -441,7 +757,25 @@ where
 
     match config.destination {
         Some(destination) => {
+            let single_transaction = destination.single_transaction()?;
+
             match destination.connection_uri()? {
+                ConnectionUri::Postgres(host, port, username, password, database)
+                    if destination.writer == Some(DestinationWriterConfig::Direct) =>
+                {
+                    let mut postgres = destination::postgres_direct::PostgresDirect::new(
+                        host.as_str(),
+                        port,
+                        database.as_str(),
+                        username.as_str(),
+                        password.as_str(),
+                        destination.wipe_database.unwrap_or(true),
+                        single_transaction,
+                    );
+
+                    let task = FullRestoreTask::new(&mut postgres, datastore, options);
+                    task.run(progress_callback)?
+                }
                 ConnectionUri::Postgres(host, port, username, password, database) => {
                     let mut postgres = destination::postgres::Postgres::new(
                         host.as_str(),
@@ -456,6 +790,8 @@ where
                     task.run(progress_callback)?
                 }
                 ConnectionUri::Mysql(host, port, username, password, database) => {
+                    let _ = check_direct_writer_support(&destination)?;
+
                     let mut mysql = destination::mysql::Mysql::new(
                         host.as_str(),
                         port,
@@ -467,12 +803,25 @@ where
                     task.run(progress_callback)?;
                 }
                 ConnectionUri::MongoDB(uri, database) => {
+                    let _ = check_direct_writer_support(&destination)?;
+
                     let mut mongodb =
                         destination::mongodb::MongoDB::new(uri.as_str(), database.as_str());
 
                     let task = FullRestoreTask::new(&mut mongodb, datastore, options);
                     task.run(progress_callback)?
                 }
+                ConnectionUri::Sqlite(path) => {
+                    let _ = check_direct_writer_support(&destination)?;
+
+                    let mut sqlite = destination::sqlite::Sqlite::new(
+                        path.as_str(),
+                        destination.wipe_database.unwrap_or(true),
+                    );
+
+                    let task = FullRestoreTask::new(&mut sqlite, datastore, options);
+                    task.run(progress_callback)?
+                }
             }
 
             println!("Restore successful!");
@@ -0,0 +1,149 @@
+use std::io::{Error, ErrorKind};
+
+use crate::cli::SourceScanArgs;
+use crate::config::{Config, ConnectionUri, SourceReaderConfig};
+use crate::connector::Connector;
+use crate::scanner::{to_transformers_yaml, Dialect, Scanner, UNKNOWN_MYSQL_DATABASE};
+use crate::source::mysql::Mysql;
+use crate::source::mysql_stdin::MysqlStdin;
+use crate::source::postgres::Postgres;
+use crate::source::postgres_direct::PostgresDirect;
+use crate::source::postgres_stdin::PostgresStdin;
+use crate::source::{Source, SourceOptions};
+
+/// Scan the source and print the transformers configuration of the columns with personal data
+pub fn scan(args: &SourceScanArgs, config: Config) -> anyhow::Result<()> {
+    let source = match config.source {
+        Some(source) => source,
+        None => {
+            return Err(anyhow::Error::from(Error::new(
+                ErrorKind::Other,
+                "missing <source> object in the configuration file",
+            )));
+        }
+    };
+
+    // values are sampled before being transformed
+    let transformers = vec![];
+
+    let empty_config = vec![];
+    let skip_config = match &source.skip {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let empty_config = vec![];
+    let only_tables_config = match &source.only_tables {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let empty_config = vec![];
+    let filters_config = match &source.filters {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let empty_config = vec![];
+    let exclude_columns_config = match &source.exclude_columns {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let options = SourceOptions {
+        transformers: &transformers,
+        skip_config: &skip_config,
+        database_subset: &None,
+        only_tables: &only_tables_config,
+        filters: &filters_config,
+        exclude_columns: &exclude_columns_config,
+        strict: &None,
+        workers: 1,
+    };
+
+    let scanner = match args.source_type.as_ref().map(|x| x.as_str()) {
+        None => match source.connection_uri()? {
+            ConnectionUri::Postgres(host, port, username, password, database)
+                if source.reader == Some(SourceReaderConfig::Direct) =>
+            {
+                let postgres = PostgresDirect::new(
+                    host.as_str(),
+                    port,
+                    database.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let scanner = Scanner::new(Dialect::Postgres, "", args.sample_size);
+                scan_source(postgres, options, scanner)?
+            }
+            ConnectionUri::Postgres(host, port, username, password, database) => {
+                let postgres = Postgres::new(
+                    host.as_str(),
+                    port,
+                    database.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let scanner = Scanner::new(Dialect::Postgres, "", args.sample_size);
+                scan_source(postgres, options, scanner)?
+            }
+            ConnectionUri::Mysql(host, port, username, password, database) => {
+                let mysql = Mysql::new(
+                    host.as_str(),
+                    port,
+                    database.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let scanner = Scanner::new(Dialect::Mysql, database.as_str(), args.sample_size);
+                scan_source(mysql, options, scanner)?
+            }
+            ConnectionUri::MongoDB(_, _) | ConnectionUri::Sqlite(_) => {
+                return Err(anyhow::Error::from(Error::new(
+                    ErrorKind::Other,
+                    "source scan supports PostgreSQL and MySQL sources only",
+                )));
+            }
+        },
+        // some user use "postgres" and "postgresql" both are valid
+        Some(v) if v == "postgres" || v == "postgresql" => {
+            let scanner = Scanner::new(Dialect::Postgres, "", args.sample_size);
+            scan_source(PostgresStdin::default(), options, scanner)?
+        }
+        Some(v) if v == "mysql" => {
+            let scanner = Scanner::new(Dialect::Mysql, UNKNOWN_MYSQL_DATABASE, args.sample_size);
+            scan_source(MysqlStdin::default(), options, scanner)?
+        }
+        Some(v) => {
+            return Err(anyhow::Error::from(Error::new(
+                ErrorKind::Other,
+                format!("source type '{}' not recognized", v),
+            )));
+        }
+    };
+
+    let detections = scanner.detections(args.min_confidence);
+
+    if detections.is_empty() {
+        println!("<empty> no columns with personal data detected\n");
+        return Ok(());
+    }
+
+    print!("{}", to_transformers_yaml(&detections));
+
+    Ok(())
+}
+
+fn scan_source<S: Source>(
+    mut source: S,
+    options: SourceOptions,
+    mut scanner: Scanner,
+) -> Result<Scanner, Error> {
+    let _ = source.init()?;
+    let _ = source.read(options, |original_query, _| scanner.scan(&original_query))?;
+
+    Ok(scanner)
+}
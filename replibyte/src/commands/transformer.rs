@@ -1,4 +1,21 @@
+use std::io::{Error, ErrorKind};
+
+use dump_parser::utils::ListQueryResult;
+use prettytable::{Cell, Row};
+
+use crate::cli::TransformerPreviewArgs;
+use crate::commands::dump::transformers as source_transformers;
+use crate::config::{Config, ConnectionUri, OnlyTablesConfig, SourceReaderConfig};
+use crate::connector::Connector;
+use crate::scanner::{for_each_row, Dialect, UNKNOWN_MYSQL_DATABASE};
+use crate::source::mysql::Mysql;
+use crate::source::mysql_stdin::MysqlStdin;
+use crate::source::postgres::Postgres;
+use crate::source::postgres_direct::PostgresDirect;
+use crate::source::postgres_stdin::PostgresStdin;
+use crate::source::{Source, SourceOptions};
 use crate::transformer::transformers;
+use crate::types::{OriginalQuery, Query};
 use crate::utils::table;
 
 /// display all transformers available
@@ -12,3 +29,362 @@ pub fn list() {
 
     let _ = table.printstd();
 }
+
+/// Read the source and print the first rows of a table before and after being transformed
+pub fn preview(args: &TransformerPreviewArgs, config: Config) -> anyhow::Result<()> {
+    let source = match config.source {
+        Some(source) => source,
+        None => {
+            return Err(anyhow::Error::from(Error::new(
+                ErrorKind::Other,
+                "missing <source> object in the configuration file",
+            )));
+        }
+    };
+
+    let (database, table_name) = match args.table.split_once('.') {
+        Some((database, table)) => (database.to_string(), table.to_string()),
+        None => {
+            return Err(anyhow::Error::from(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "table '{}' must be written as <database>.<table>",
+                    args.table
+                ),
+            )));
+        }
+    };
+
+    let transformers = source_transformers(&source)?;
+
+    let empty_config = vec![];
+    let skip_config = match &source.skip {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    // only the previewed table is dumped by the database
+    let only_tables_config = vec![OnlyTablesConfig {
+        database: database.clone(),
+        table: table_name.clone(),
+    }];
+
+    let empty_config = vec![];
+    let filters_config = match &source.filters {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let empty_config = vec![];
+    let exclude_columns_config = match &source.exclude_columns {
+        Some(config) => config,
+        None => &empty_config,
+    };
+
+    let options = SourceOptions {
+        transformers: &transformers,
+        skip_config: &skip_config,
+        database_subset: &None,
+        only_tables: &only_tables_config,
+        filters: &filters_config,
+        exclude_columns: &exclude_columns_config,
+        strict: &None,
+        workers: 1,
+    };
+
+    let preview = match args.source_type.as_ref().map(|x| x.as_str()) {
+        None => match source.connection_uri()? {
+            ConnectionUri::Postgres(host, port, username, password, db)
+                if source.reader == Some(SourceReaderConfig::Direct) =>
+            {
+                let postgres = PostgresDirect::new(
+                    host.as_str(),
+                    port,
+                    db.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let preview = Preview::new(Dialect::Postgres, "", &args.table, args.limit);
+                preview_source(postgres, options, preview)?
+            }
+            ConnectionUri::Postgres(host, port, username, password, db) => {
+                let postgres = Postgres::new(
+                    host.as_str(),
+                    port,
+                    db.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let preview = Preview::new(Dialect::Postgres, "", &args.table, args.limit);
+                preview_source(postgres, options, preview)?
+            }
+            ConnectionUri::Mysql(host, port, username, password, db) => {
+                let mysql = Mysql::new(
+                    host.as_str(),
+                    port,
+                    db.as_str(),
+                    username.as_str(),
+                    password.as_str(),
+                );
+
+                let preview = Preview::new(Dialect::Mysql, db.as_str(), &args.table, args.limit);
+                preview_source(mysql, options, preview)?
+            }
+            ConnectionUri::MongoDB(_, _) | ConnectionUri::Sqlite(_) => {
+                return Err(anyhow::Error::from(Error::new(
+                    ErrorKind::Other,
+                    "transformer preview supports PostgreSQL and MySQL sources only",
+                )));
+            }
+        },
+        // some user use "postgres" and "postgresql" both are valid
+        Some(v) if v == "postgres" || v == "postgresql" => {
+            let preview = Preview::new(Dialect::Postgres, "", &args.table, args.limit);
+            preview_source(PostgresStdin::default(), options, preview)?
+        }
+        Some(v) if v == "mysql" => {
+            let preview = Preview::new(
+                Dialect::Mysql,
+                UNKNOWN_MYSQL_DATABASE,
+                &args.table,
+                args.limit,
+            );
+            preview_source(MysqlStdin::default(), options, preview)?
+        }
+        Some(v) => {
+            return Err(anyhow::Error::from(Error::new(
+                ErrorKind::Other,
+                format!("source type '{}' not recognized", v),
+            )));
+        }
+    };
+
+    if preview.rows.is_empty() {
+        println!("<empty> no rows found for table '{}'\n", args.table);
+        return Ok(());
+    }
+
+    preview.print();
+
+    Ok(())
+}
+
+fn preview_source<S: Source>(
+    mut source: S,
+    options: SourceOptions,
+    mut preview: Preview,
+) -> Result<Preview, Error> {
+    let _ = source.init()?;
+    // the source is stopped once the limit is reached
+    source.read_until(options, |original_query, query| {
+        preview.add(&original_query, &query)
+    })?;
+
+    Ok(preview)
+}
+
+type PreviewRow = Vec<(String, Option<String>)>;
+
+/// first rows of a table, before and after being transformed
+struct Preview {
+    dialect: Dialect,
+    /// MySQL queries do not hold the database name
+    database_name: String,
+    table: String,
+    limit: usize,
+    rows: Vec<(PreviewRow, PreviewRow)>,
+}
+
+impl Preview {
+    fn new(dialect: Dialect, database_name: &str, table: &str, limit: usize) -> Self {
+        Preview {
+            dialect,
+            database_name: database_name.to_string(),
+            table: table.to_string(),
+            limit,
+            rows: vec![],
+        }
+    }
+
+    /// keep the rows of the previewed table - the next queries are not needed once the limit is reached
+    fn add(&mut self, original_query: &OriginalQuery, query: &Query) -> ListQueryResult {
+        let original_rows = self.table_rows(original_query);
+        if original_rows.is_empty() {
+            return ListQueryResult::Continue;
+        }
+
+        let rows = self.table_rows(query);
+
+        for (original_row, row) in original_rows.into_iter().zip(rows) {
+            if self.rows.len() >= self.limit {
+                break;
+            }
+
+            self.rows.push((original_row, row));
+        }
+
+        if self.rows.len() >= self.limit {
+            ListQueryResult::Break
+        } else {
+            ListQueryResult::Continue
+        }
+    }
+
+    fn table_rows(&self, query: &Query) -> Vec<PreviewRow> {
+        let mut rows = vec![];
+
+        for_each_row(
+            self.dialect,
+            self.database_name.as_str(),
+            query.data(),
+            |database_name, table_name, row| {
+                if self.is_previewed_table(database_name, table_name) {
+                    rows.push(row);
+                }
+            },
+        );
+
+        rows
+    }
+
+    fn is_previewed_table(&self, database_name: &str, table_name: &str) -> bool {
+        match self.dialect {
+            Dialect::Postgres => format!("{}.{}", database_name, table_name) == self.table,
+            // MySQL transformers are matched on the table name only
+            Dialect::Mysql => {
+                self.table.split_once('.').map(|(_, table)| table) == Some(table_name)
+            }
+        }
+    }
+
+    /// print each row twice - original then transformed - with the values of the columns aligned
+    fn print(&self) {
+        let column_names = match self.rows.first() {
+            Some((original_row, _)) => original_row
+                .iter()
+                .map(|(column_name, _)| column_name.clone())
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+
+        let mut table = table();
+
+        let mut titles = vec![Cell::new("#"), Cell::new("")];
+        titles.extend(column_names.iter().map(|name| Cell::new(name.as_str())));
+        table.set_titles(Row::new(titles));
+
+        for (idx, (original_row, row)) in self.rows.iter().enumerate() {
+            let mut original_cells = vec![Cell::new(&(idx + 1).to_string()), Cell::new("original")];
+            let mut cells = vec![Cell::new(""), Cell::new("transformed")];
+
+            for column_name in &column_names {
+                original_cells.push(Cell::new(&preview_value(original_row, column_name)));
+                cells.push(Cell::new(&preview_value(row, column_name)));
+            }
+
+            table.add_row(Row::new(original_cells));
+            table.add_row(Row::new(cells));
+        }
+
+        let _ = table.printstd();
+    }
+}
+
+/// text of a column value - columns removed by <source.exclude_columns> are not in the transformed row
+fn preview_value(row: &[(String, Option<String>)], column_name: &str) -> String {
+    match row.iter().find(|(name, _)| name == column_name) {
+        Some((_, Some(value))) => value.clone(),
+        Some((_, None)) => "NULL".to_string(),
+        None => "<removed>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dump_parser::utils::ListQueryResult;
+
+    use crate::commands::transformer::{preview_value, Preview};
+    use crate::scanner::Dialect;
+    use crate::types::Query;
+
+    fn add(preview: &mut Preview, original_query: &str, query: &str) -> ListQueryResult {
+        preview.add(
+            &Query(original_query.as_bytes().to_vec()),
+            &Query(query.as_bytes().to_vec()),
+        )
+    }
+
+    #[test]
+    fn preview_rows() {
+        let mut preview = Preview::new(Dialect::Postgres, "", "public.users", 3);
+
+        let _ = add(
+            &mut preview,
+            "INSERT INTO public.orders (id, amount) VALUES (1, 10);",
+            "INSERT INTO public.orders (id, amount) VALUES (1, 10);",
+        );
+        assert!(matches!(
+            add(
+                &mut preview,
+                "INSERT INTO public.users (id, email) VALUES (1, 'jane@doe.com');",
+                "INSERT INTO public.users (id, email) VALUES (1, 'abcd@doe.com');",
+            ),
+            ListQueryResult::Continue
+        ));
+        // the source is stopped once the limit is reached
+        assert!(matches!(
+            add(
+                &mut preview,
+                "\nCOPY public.users (id, email) FROM stdin;\n2\tjohn@doe.com\n3\t\\N\n4\tmary@roe.com\n\\.\n",
+                "\nCOPY public.users (id) FROM stdin;\n2\n3\n4\n\\.\n",
+            ),
+            ListQueryResult::Break
+        ));
+
+        assert_eq!(preview.rows.len(), 3);
+
+        let (original_row, row) = &preview.rows[0];
+        assert_eq!(
+            original_row,
+            &vec![
+                ("id".to_string(), Some("1".to_string())),
+                ("email".to_string(), Some("jane@doe.com".to_string())),
+            ]
+        );
+        assert_eq!(
+            row,
+            &vec![
+                ("id".to_string(), Some("1".to_string())),
+                ("email".to_string(), Some("abcd@doe.com".to_string())),
+            ]
+        );
+
+        let (original_row, row) = &preview.rows[2];
+        assert_eq!(preview_value(original_row, "email"), "NULL");
+        assert_eq!(preview_value(row, "email"), "<removed>");
+    }
+
+    #[test]
+    fn preview_mysql_rows() {
+        let mut preview = Preview::new(Dialect::Mysql, "world", "world.city", 20);
+
+        let _ = add(
+            &mut preview,
+            "INSERT INTO `city` (`id`, `name`) VALUES (1,'Kabul');",
+            "INSERT INTO `city` (`id`, `name`) VALUES (1,'Kxxxx');",
+        );
+        let _ = add(
+            &mut preview,
+            "INSERT INTO `country` (`code`, `name`) VALUES ('AFG','Afghanistan');",
+            "INSERT INTO `country` (`code`, `name`) VALUES ('AFG','Afghanistan');",
+        );
+
+        assert_eq!(preview.rows.len(), 1);
+        assert_eq!(
+            preview_value(&preview.rows[0].1, "name"),
+            "Kxxxx".to_string()
+        );
+    }
+}
@@ -0,0 +1,963 @@
+use std::io::{Error, ErrorKind};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use log::{error, info};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use reqwest::blocking::{Client, Response};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::config::{AzureCredentials, Endpoint};
+use crate::connector::Connector;
+use crate::datastore::{
+    chunk_part, decompress, decrypt, ChunkQueries, CompressionOptions, Datastore, Dump, DumpChunk,
+    DumpStatus, EncryptionKey, IndexFile, ReadOptions, ENCRYPTION_VERSION,
+};
+use crate::types::Bytes;
+use crate::utils::epoch_millis;
+
+use super::INDEX_FILE_NAME;
+
+const AZURE_STORAGE_API_VERSION: &str = "2020-10-02";
+
+/// characters to encode in a blob name - `/` is kept as the virtual directory separator
+const BLOB_NAME_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+pub struct AzureBlobStorage {
+    container: String,
+    root_key: String,
+    client: AzureBlobClient,
+    compression: Option<CompressionOptions>,
+    encryption_key: Option<EncryptionKey>,
+}
+
+impl AzureBlobStorage {
+    pub fn new<S: Into<String>>(
+        account: S,
+        container: S,
+        credentials: AzureCredentials,
+        endpoint: Endpoint,
+    ) -> anyhow::Result<Self> {
+        let account = account.into();
+
+        let endpoint = match endpoint {
+            Endpoint::Default => format!("https://{}.blob.core.windows.net", account),
+            Endpoint::Custom(url) => url,
+        };
+
+        Ok(AzureBlobStorage {
+            container: container.into(),
+            root_key: format!("dump-{}", epoch_millis()),
+            client: AzureBlobClient::new(account, endpoint, credentials)?,
+            compression: Some(CompressionOptions::default()),
+            encryption_key: None,
+        })
+    }
+
+    fn create_index_file(&self) -> Result<IndexFile, Error> {
+        match self.index_file() {
+            Ok(index_file) => Ok(index_file),
+            Err(_) => {
+                let index_file = IndexFile::new();
+                let _ = self.write_index_file(&index_file)?;
+                Ok(index_file)
+            }
+        }
+    }
+}
+
+impl Connector for AzureBlobStorage {
+    fn init(&mut self) -> Result<(), Error> {
+        let _ = create_container(&self.client, self.container.as_str())?;
+        self.create_index_file().map(|_| ())
+    }
+}
+
+impl Datastore for AzureBlobStorage {
+    fn index_file(&self) -> Result<IndexFile, Error> {
+        let blob = get_blob(&self.client, self.container.as_str(), INDEX_FILE_NAME)?;
+        let index_file: IndexFile = serde_json::from_slice(blob.as_slice())?;
+        Ok(index_file)
+    }
+
+    fn raw_index_file(&self) -> Result<Value, Error> {
+        let blob = get_blob(&self.client, self.container.as_str(), INDEX_FILE_NAME)?;
+        let index_file = serde_json::from_slice(blob.as_slice())?;
+
+        Ok(index_file)
+    }
+
+    fn write_index_file(&self, index_file: &IndexFile) -> Result<(), Error> {
+        let index_file_json = serde_json::to_vec(index_file)?;
+
+        create_blob(
+            &self.client,
+            self.container.as_str(),
+            INDEX_FILE_NAME,
+            index_file_json,
+        )
+        .map_err(|err| Error::from(err))
+    }
+
+    fn write_raw_index_file(&self, raw_index_file: &Value) -> Result<(), Error> {
+        let index_file_json = serde_json::to_vec(raw_index_file)?;
+
+        create_blob(
+            &self.client,
+            self.container.as_str(),
+            INDEX_FILE_NAME,
+            index_file_json,
+        )
+        .map_err(|err| Error::from(err))
+    }
+
+    fn write_chunk(
+        &self,
+        file_part: u16,
+        queries: &ChunkQueries,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let data_size = data.len();
+        let chunk = DumpChunk::new(file_part, queries, &data);
+        let key = format!("{}/{}.dump", self.root_key, file_part);
+
+        info!("upload blob '{}' part {}", key.as_str(), file_part);
+
+        let _ = create_blob(&self.client, self.container.as_str(), key.as_str(), data)?;
+
+        // update index file
+        let mut index_file = self.index_file()?;
+
+        let mut new_dump = Dump {
+            directory_name: self.root_key.to_string(),
+            size: 0,
+            created_at: epoch_millis(),
+            compressed: self.compression().is_some(),
+            encrypted: self.encryption_key().is_some(),
+            encryption_version: self.encryption_key().as_ref().map(|_| ENCRYPTION_VERSION),
+            compression_codec: self.compression().map(|options| options.codec),
+            chunks: vec![],
+            status: DumpStatus::InProgress,
+        };
+
+        // find or create dump
+        let mut dump = index_file
+            .dumps
+            .iter_mut()
+            .find(|b| b.directory_name.as_str() == self.root_key)
+            .unwrap_or(&mut new_dump);
+
+        dump.chunks.push(chunk);
+
+        if dump.size == 0 {
+            // it means it's a new dump.
+            // We need to add it into the index_file.dumps
+            new_dump.size = data_size;
+            index_file.dumps.push(new_dump);
+        } else {
+            // update total dump size
+            dump.size = dump.size + data_size;
+        }
+
+        // save index file
+        self.write_index_file(&index_file)
+    }
+
+    fn read(
+        &self,
+        options: &ReadOptions,
+        data_callback: &mut dyn FnMut(Bytes),
+    ) -> Result<(), Error> {
+        let mut index_file = self.index_file()?;
+        let dump = index_file.find_dump(options)?;
+        let prefix = format!("{}/", dump.directory_name);
+
+        for blob_name in list_blobs(&self.client, self.container.as_str(), prefix.as_str())? {
+            let data = get_blob(&self.client, self.container.as_str(), blob_name.as_str())?;
+
+            // decrypt data?
+            let data = if dump.encrypted {
+                // It should be safe to unwrap here because the dump is marked as encrypted in the dump manifest
+                // so if there is no encryption key set at the datastore level we want to panic.
+                let encryption_key = self.encryption_key.as_ref().unwrap();
+                decrypt(data, encryption_key, dump.encryption_version)?
+            } else {
+                data
+            };
+
+            // decompress data?
+            let data = if dump.compressed {
+                decompress(data, dump.compression_codec)?
+            } else {
+                data
+            };
+
+            data_callback(data);
+        }
+
+        Ok(())
+    }
+
+    fn read_chunks(
+        &self,
+        dump: &Dump,
+        chunk_callback: &mut dyn FnMut(u16, Bytes),
+    ) -> Result<(), Error> {
+        let prefix = format!("{}/", dump.directory_name);
+
+        for blob_name in list_blobs(&self.client, self.container.as_str(), prefix.as_str())? {
+            let part = match chunk_part(blob_name.as_str()) {
+                Some(part) => part,
+                None => continue,
+            };
+
+            let data = get_blob(&self.client, self.container.as_str(), blob_name.as_str())?;
+            chunk_callback(part, data);
+        }
+
+        Ok(())
+    }
+
+    fn compression(&self) -> Option<CompressionOptions> {
+        self.compression
+    }
+
+    fn set_compression(&mut self, compression: Option<CompressionOptions>) {
+        self.compression = compression;
+    }
+
+    fn encryption_key(&self) -> &Option<EncryptionKey> {
+        &self.encryption_key
+    }
+
+    fn set_encryption_key(&mut self, key: String) {
+        self.encryption_key = Some(EncryptionKey::new(key));
+    }
+
+    fn dump_name(&self) -> String {
+        self.root_key.to_string()
+    }
+
+    fn set_dump_name(&mut self, name: String) {
+        self.root_key = name;
+    }
+
+    fn delete_by_name(&self, name: String) -> Result<(), Error> {
+        let mut index_file = self.index_file()?;
+
+        let _ = delete_directory(&self.client, self.container.as_str(), name.as_str())
+            .map_err(|err| Error::from(err))?;
+
+        index_file.dumps.retain(|b| b.directory_name != name);
+
+        self.write_index_file(&index_file)
+    }
+}
+
+/// Minimal Azure Blob Storage REST client
+/// https://docs.microsoft.com/en-us/rest/api/storageservices/blob-service-rest-api
+struct AzureBlobClient {
+    account: String,
+    endpoint: String,
+    credentials: AzureCredentials,
+    // decoded access key
+    access_key: Vec<u8>,
+    http_client: Client,
+}
+
+impl AzureBlobClient {
+    fn new(
+        account: String,
+        endpoint: String,
+        credentials: AzureCredentials,
+    ) -> anyhow::Result<Self> {
+        let access_key = match &credentials {
+            AzureCredentials::AccessKey(access_key) => base64::decode(access_key.as_str())
+                .map_err(|err| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("invalid azure access key (base64 expected): {}", err),
+                    )
+                })?,
+            AzureCredentials::SasToken(_) => vec![],
+        };
+
+        Ok(AzureBlobClient {
+            account,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            credentials,
+            access_key,
+            http_client: Client::new(),
+        })
+    }
+
+    fn url(&self, container: &str, blob: Option<&str>, query: &[(&str, &str)]) -> Url {
+        let path = match blob {
+            Some(blob) => format!(
+                "{}/{}/{}",
+                self.endpoint,
+                container,
+                utf8_percent_encode(blob, BLOB_NAME_ENCODE_SET)
+            ),
+            None => format!("{}/{}", self.endpoint, container),
+        };
+
+        let mut url = Url::parse(path.as_str()).unwrap();
+
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+
+        if let AzureCredentials::SasToken(sas_token) = &self.credentials {
+            let sas_token = sas_token.trim_start_matches('?');
+            let query = match url.query() {
+                Some(query) => format!("{}&{}", query, sas_token),
+                None => sas_token.to_string(),
+            };
+
+            url.set_query(Some(query.as_str()));
+        }
+
+        url
+    }
+
+    fn send(
+        &self,
+        method: Method,
+        url: Url,
+        mut headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    ) -> reqwest::Result<Response> {
+        headers.push((
+            "x-ms-date".to_string(),
+            Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+        ));
+        headers.push((
+            "x-ms-version".to_string(),
+            AZURE_STORAGE_API_VERSION.to_string(),
+        ));
+
+        if let AzureCredentials::AccessKey(_) = &self.credentials {
+            let string_to_sign = string_to_sign(
+                self.account.as_str(),
+                &method,
+                &url,
+                &headers,
+                body.len(),
+            );
+
+            headers.push((
+                "Authorization".to_string(),
+                format!(
+                    "SharedKey {}:{}",
+                    self.account,
+                    sign(self.access_key.as_slice(), string_to_sign.as_str())
+                ),
+            ));
+        }
+
+        let mut request = self.http_client.request(method, url);
+
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        request.body(body).send()
+    }
+}
+
+/// https://docs.microsoft.com/en-us/rest/api/storageservices/authorize-with-shared-key#blob-queue-and-file-services-shared-key-authorization
+fn string_to_sign(
+    account: &str,
+    method: &Method,
+    url: &Url,
+    headers: &Vec<(String, String)>,
+    content_length: usize,
+) -> String {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+            .unwrap_or("")
+    };
+
+    let content_length = if content_length == 0 {
+        String::new()
+    } else {
+        content_length.to_string()
+    };
+
+    let mut canonicalized_headers = headers
+        .iter()
+        .filter(|(name, _)| name.to_lowercase().starts_with("x-ms-"))
+        .map(|(name, value)| format!("{}:{}\n", name.to_lowercase(), value.trim()))
+        .collect::<Vec<_>>();
+
+    canonicalized_headers.sort();
+
+    let mut canonicalized_resource = format!("/{}{}", account, url.path());
+
+    let mut query_pairs = url
+        .query_pairs()
+        .map(|(name, value)| (name.to_lowercase(), value.to_string()))
+        .collect::<Vec<_>>();
+
+    query_pairs.sort();
+
+    for (name, value) in query_pairs {
+        canonicalized_resource.push_str(format!("\n{}:{}", name, value).as_str());
+    }
+
+    format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}{}",
+        method.as_str(),
+        header("Content-Encoding"),
+        header("Content-Language"),
+        content_length,
+        header("Content-MD5"),
+        header("Content-Type"),
+        "", // Date - x-ms-date is used instead
+        header("If-Modified-Since"),
+        header("If-Match"),
+        header("If-None-Match"),
+        header("If-Unmodified-Since"),
+        header("Range"),
+        canonicalized_headers.join(""),
+        canonicalized_resource,
+    )
+}
+
+fn sign(access_key: &[u8], string_to_sign: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(access_key).unwrap();
+    mac.update(string_to_sign.as_bytes());
+    base64::encode(mac.finalize().into_bytes())
+}
+
+/// return the values of the `<tag>value</tag>` elements from a XML document
+fn xml_tag_values(xml: &str, tag: &str) -> Vec<String> {
+    let open_tag = format!("<{}>", tag);
+    let close_tag = format!("</{}>", tag);
+
+    let mut values = vec![];
+    let mut xml = xml;
+
+    while let Some(start) = xml.find(open_tag.as_str()) {
+        xml = &xml[start + open_tag.len()..];
+
+        let end = match xml.find(close_tag.as_str()) {
+            Some(end) => end,
+            None => break,
+        };
+
+        values.push(
+            xml[..end]
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&amp;", "&"),
+        );
+
+        xml = &xml[end + close_tag.len()..];
+    }
+
+    values
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum AzureError<'a> {
+    FailedToCreateContainer { container: &'a str },
+    FailedToDeleteContainer { container: &'a str },
+    FailedToListBlobs { container: &'a str },
+    BlobDoesNotExist { container: &'a str, key: &'a str },
+    FailedBlobDownload { container: &'a str, key: &'a str },
+    FailedBlobUpload { container: &'a str, key: &'a str },
+    FailedToDeleteBlob { container: &'a str, key: &'a str },
+}
+
+impl<'a> From<AzureError<'a>> for Error {
+    fn from(err: AzureError<'a>) -> Self {
+        match err {
+            AzureError::FailedToCreateContainer { container } => Error::new(
+                ErrorKind::Other,
+                format!("failed to create container '{}'", container),
+            ),
+            AzureError::FailedToDeleteContainer { container } => Error::new(
+                ErrorKind::Other,
+                format!("failed to delete container '{}'", container),
+            ),
+            AzureError::FailedToListBlobs { container } => Error::new(
+                ErrorKind::Other,
+                format!("failed to list blobs from container '{}'", container),
+            ),
+            AzureError::BlobDoesNotExist { container, key } => Error::new(
+                ErrorKind::Other,
+                format!("blob '{}/{}' does not exist", container, key),
+            ),
+            AzureError::FailedBlobDownload { container, key } => Error::new(
+                ErrorKind::Other,
+                format!("failed to download blob '{}/{}'", container, key),
+            ),
+            AzureError::FailedBlobUpload { container, key } => Error::new(
+                ErrorKind::Other,
+                format!("failed to upload blob '{}/{}'", container, key),
+            ),
+            AzureError::FailedToDeleteBlob { container, key } => Error::new(
+                ErrorKind::Other,
+                format!("failed to delete blob '{}/{}'", container, key),
+            ),
+        }
+    }
+}
+
+fn log_error_response(result: reqwest::Result<Response>) -> Option<StatusCode> {
+    match result {
+        Ok(response) => {
+            let status = response.status();
+
+            if !status.is_success() {
+                error!(
+                    "azure blob storage error {}: {}",
+                    status,
+                    response.text().unwrap_or_default()
+                );
+            }
+
+            Some(status)
+        }
+        Err(err) => {
+            error!("{}", err);
+            None
+        }
+    }
+}
+
+fn create_container<'a>(client: &AzureBlobClient, container: &'a str) -> Result<(), AzureError<'a>> {
+    let url = client.url(container, None, &[("restype", "container")]);
+
+    if let Ok(response) = client.send(Method::GET, url.clone(), vec![], vec![]) {
+        if response.status().is_success() {
+            info!("container {} exists", container);
+            return Ok(());
+        }
+    }
+
+    match log_error_response(client.send(Method::PUT, url, vec![], vec![])) {
+        // the container has been created in the meantime
+        Some(status) if status.is_success() || status == StatusCode::CONFLICT => {}
+        _ => return Err(AzureError::FailedToCreateContainer { container }),
+    }
+
+    info!("container {} created", container);
+
+    Ok(())
+}
+
+fn delete_container<'a>(client: &AzureBlobClient, container: &'a str) -> Result<(), AzureError<'a>> {
+    let url = client.url(container, None, &[("restype", "container")]);
+
+    match log_error_response(client.send(Method::DELETE, url, vec![], vec![])) {
+        Some(status) if status.is_success() => Ok(()),
+        _ => Err(AzureError::FailedToDeleteContainer { container }),
+    }
+}
+
+fn create_blob<'a>(
+    client: &AzureBlobClient,
+    container: &'a str,
+    key: &'a str,
+    blob: Vec<u8>,
+) -> Result<(), AzureError<'a>> {
+    let url = client.url(container, Some(key), &[]);
+    let headers = vec![("x-ms-blob-type".to_string(), "BlockBlob".to_string())];
+
+    match log_error_response(client.send(Method::PUT, url, headers, blob)) {
+        Some(status) if status.is_success() => Ok(()),
+        _ => Err(AzureError::FailedBlobUpload { container, key }),
+    }
+}
+
+fn get_blob<'a>(
+    client: &AzureBlobClient,
+    container: &'a str,
+    key: &'a str,
+) -> Result<Vec<u8>, AzureError<'a>> {
+    let url = client.url(container, Some(key), &[]);
+
+    match client.send(Method::GET, url, vec![], vec![]) {
+        Ok(response) if response.status().is_success() => match response.bytes() {
+            Ok(data) => Ok(data.to_vec()),
+            Err(_) => Err(AzureError::FailedBlobDownload { container, key }),
+        },
+        _ => Err(AzureError::BlobDoesNotExist { container, key }),
+    }
+}
+
+/// list the blob names starting with `prefix` - sorted by dump part number
+fn list_blobs<'a>(
+    client: &AzureBlobClient,
+    container: &'a str,
+    prefix: &str,
+) -> Result<Vec<String>, AzureError<'a>> {
+    let mut blob_names = vec![];
+    let mut marker = String::new();
+
+    loop {
+        let mut query = vec![("restype", "container"), ("comp", "list")];
+
+        if !prefix.is_empty() {
+            query.push(("prefix", prefix));
+        }
+
+        if !marker.is_empty() {
+            query.push(("marker", marker.as_str()));
+        }
+
+        let url = client.url(container, None, query.as_slice());
+
+        let body = match client.send(Method::GET, url, vec![], vec![]) {
+            Ok(response) if response.status().is_success() => match response.text() {
+                Ok(body) => body,
+                Err(err) => {
+                    error!("{}", err);
+                    return Err(AzureError::FailedToListBlobs { container });
+                }
+            },
+            result => {
+                let _ = log_error_response(result);
+                return Err(AzureError::FailedToListBlobs { container });
+            }
+        };
+
+        blob_names.append(&mut xml_tag_values(body.as_str(), "Name"));
+
+        // results are paginated (5000 blobs max per page)
+        marker = xml_tag_values(body.as_str(), "NextMarker")
+            .pop()
+            .unwrap_or_default();
+
+        if marker.is_empty() {
+            break;
+        }
+    }
+
+    // dump parts are named `<dump>/<part>.dump` - sort them by part number
+    blob_names.sort_by(|a, b| chunk_part(a).cmp(&chunk_part(b)).then(a.cmp(b)));
+
+    Ok(blob_names)
+}
+
+fn delete_blob<'a>(
+    client: &AzureBlobClient,
+    container: &'a str,
+    key: &'a str,
+) -> Result<(), AzureError<'a>> {
+    let url = client.url(container, Some(key), &[]);
+
+    match log_error_response(client.send(Method::DELETE, url, vec![], vec![])) {
+        Some(status) if status.is_success() => Ok(()),
+        Some(StatusCode::NOT_FOUND) => Err(AzureError::BlobDoesNotExist { container, key }),
+        _ => Err(AzureError::FailedToDeleteBlob { container, key }),
+    }
+}
+
+fn delete_directory<'a>(
+    client: &AzureBlobClient,
+    container: &'a str,
+    directory: &'a str,
+) -> Result<(), AzureError<'a>> {
+    let prefix = format!("{}/", directory);
+
+    for blob_name in list_blobs(client, container, prefix.as_str())? {
+        if let Err(err) = delete_blob(client, container, blob_name.as_str()) {
+            error!("{:?}", err);
+            return Err(AzureError::FailedToDeleteBlob {
+                container,
+                key: directory,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+    use reqwest::{Method, Url};
+
+    use crate::cli::DumpDeleteArgs;
+    use crate::config::{AzureCredentials, Endpoint};
+    use crate::connector::Connector;
+    use crate::datastore::azure::{
+        create_blob, delete_blob, delete_container, get_blob, list_blobs, sign, string_to_sign,
+        xml_tag_values, AzureBlobStorage, AzureError,
+    };
+    use crate::datastore::{ChunkQueries, Datastore, Dump, DumpStatus, ReadOptions};
+    use crate::utils::epoch_millis;
+
+    // well-known Azurite emulator account
+    const AZURITE_ACCOUNT: &str = "devstoreaccount1";
+    const AZURITE_ACCESS_KEY: &str =
+        "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+    const AZURITE_ENDPOINT: &str = "http://127.0.0.1:10000/devstoreaccount1";
+
+    fn container() -> String {
+        format!("replibyte-test-{}", Faker.fake::<String>().to_lowercase())
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .take(63)
+            .collect()
+    }
+
+    fn azure(container: &str) -> AzureBlobStorage {
+        AzureBlobStorage::new(
+            AZURITE_ACCOUNT.to_string(),
+            container.to_string(),
+            AzureCredentials::AccessKey(AZURITE_ACCESS_KEY.to_string()),
+            Endpoint::Custom(AZURITE_ENDPOINT.to_string()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn shared_key_signature() {
+        let url = Url::parse(
+            "http://127.0.0.1:10000/devstoreaccount1/replibyte?restype=container&comp=list&prefix=dump-1%2F",
+        )
+        .unwrap();
+
+        let headers = vec![
+            (
+                "x-ms-date".to_string(),
+                "Fri, 10 Jun 2022 09:00:00 GMT".to_string(),
+            ),
+            ("x-ms-version".to_string(), "2020-10-02".to_string()),
+        ];
+
+        let list_string_to_sign = string_to_sign(AZURITE_ACCOUNT, &Method::GET, &url, &headers, 0);
+
+        assert_eq!(
+            list_string_to_sign,
+            "GET\n\n\n\n\n\n\n\n\n\n\n\nx-ms-date:Fri, 10 Jun 2022 09:00:00 GMT\nx-ms-version:2020-10-02\n/devstoreaccount1/devstoreaccount1/replibyte\ncomp:list\nprefix:dump-1/\nrestype:container"
+        );
+
+        assert_eq!(
+            sign(
+                base64::decode(AZURITE_ACCESS_KEY).unwrap().as_slice(),
+                list_string_to_sign.as_str()
+            ),
+            "jJktBZps6Lx6gwfpcH/KYMIPtA6MzlktNcsK+QKMpSI="
+        );
+
+        let headers = vec![
+            ("x-ms-blob-type".to_string(), "BlockBlob".to_string()),
+            (
+                "x-ms-date".to_string(),
+                "Fri, 10 Jun 2022 09:00:00 GMT".to_string(),
+            ),
+            ("x-ms-version".to_string(), "2020-10-02".to_string()),
+        ];
+
+        let url = Url::parse("http://127.0.0.1:10000/devstoreaccount1/replibyte/dump-1/0.dump")
+            .unwrap();
+
+        assert_eq!(
+            string_to_sign(AZURITE_ACCOUNT, &Method::PUT, &url, &headers, 11),
+            "PUT\n\n\n11\n\n\n\n\n\n\n\n\nx-ms-blob-type:BlockBlob\nx-ms-date:Fri, 10 Jun 2022 09:00:00 GMT\nx-ms-version:2020-10-02\n/devstoreaccount1/devstoreaccount1/replibyte/dump-1/0.dump"
+        );
+    }
+
+    #[test]
+    fn parse_list_blobs_response() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?><EnumerationResults ServiceEndpoint="http://127.0.0.1:10000/devstoreaccount1" ContainerName="replibyte"><Prefix>dump-1/</Prefix><Blobs><Blob><Name>dump-1/10.dump</Name></Blob><Blob><Name>dump-1/2.dump</Name></Blob><Blob><Name>dump-1/a&amp;b.dump</Name></Blob></Blobs><NextMarker/></EnumerationResults>"#;
+
+        assert_eq!(
+            xml_tag_values(xml, "Name"),
+            vec!["dump-1/10.dump", "dump-1/2.dump", "dump-1/a&b.dump"]
+        );
+        assert!(xml_tag_values(xml, "NextMarker").is_empty());
+    }
+
+    #[test]
+    fn init_azure() {
+        let container = container();
+        let mut azure = azure(container.as_str());
+        // executed twice to check that there is no error at the second call
+        assert!(azure.init().is_ok());
+        assert!(azure.init().is_ok());
+
+        assert!(delete_container(&azure.client, container.as_str()).is_ok());
+    }
+
+    #[test]
+    fn create_and_get_and_delete_blob() {
+        let container = container();
+        let mut azure = azure(container.as_str());
+        let _ = azure.init().expect("azure init failed");
+
+        let key = format!("testing-blob-{}", epoch_millis());
+
+        assert_eq!(
+            get_blob(&azure.client, container.as_str(), key.as_str())
+                .err()
+                .unwrap(),
+            AzureError::BlobDoesNotExist {
+                container: container.as_str(),
+                key: key.as_str(),
+            }
+        );
+
+        assert!(create_blob(
+            &azure.client,
+            container.as_str(),
+            key.as_str(),
+            b"hello w0rld".to_vec(),
+        )
+        .is_ok());
+
+        assert_eq!(
+            get_blob(&azure.client, container.as_str(), key.as_str()).unwrap(),
+            b"hello w0rld"
+        );
+
+        // check that the blob is updated
+        assert!(create_blob(
+            &azure.client,
+            container.as_str(),
+            key.as_str(),
+            b"hello w0rld updated".to_vec(),
+        )
+        .is_ok());
+
+        assert_eq!(
+            get_blob(&azure.client, container.as_str(), key.as_str()).unwrap(),
+            b"hello w0rld updated"
+        );
+
+        assert!(delete_blob(&azure.client, container.as_str(), key.as_str()).is_ok());
+
+        assert_eq!(
+            delete_blob(&azure.client, container.as_str(), key.as_str())
+                .err()
+                .unwrap(),
+            AzureError::BlobDoesNotExist {
+                container: container.as_str(),
+                key: key.as_str(),
+            }
+        );
+
+        assert!(delete_container(&azure.client, container.as_str()).is_ok());
+    }
+
+    #[test]
+    fn write_and_read_dump() {
+        let container = container();
+        let mut azure = azure(container.as_str());
+        azure.set_encryption_key("encryption key".to_string());
+        let _ = azure.init().expect("azure init failed");
+
+        for file_part in 0..12u16 {
+            assert!(azure
+                .write(
+                    file_part,
+                    &ChunkQueries::default(),
+                    format!("part {}", file_part).into_bytes()
+                )
+                .is_ok());
+        }
+        assert!(azure.set_dump_status(DumpStatus::Completed).is_ok());
+
+        let index_file = azure.index_file().unwrap();
+        assert_eq!(index_file.dumps.len(), 1);
+        assert!(index_file.dumps[0].compressed);
+        assert!(index_file.dumps[0].encrypted);
+
+        let mut parts = vec![];
+        assert!(azure
+            .read(&ReadOptions::Latest, &mut |data| {
+                parts.push(String::from_utf8(data).unwrap());
+            })
+            .is_ok());
+
+        // parts are read in order - 10 and 11 after 9
+        assert_eq!(
+            parts,
+            (0..12)
+                .map(|file_part| format!("part {}", file_part))
+                .collect::<Vec<_>>()
+        );
+
+        assert!(delete_container(&azure.client, container.as_str()).is_ok());
+    }
+
+    #[test]
+    fn test_azure_dump_delete_by_name() {
+        let container = container();
+        let mut azure = azure(container.as_str());
+
+        let _ = azure.init().expect("azure init failed");
+
+        let mut index_file = azure.index_file().unwrap();
+
+        assert!(index_file.dumps.is_empty());
+
+        // Add 2 dumps in the manifest
+        for directory_name in ["dump-1", "dump-2"] {
+            index_file.dumps.push(Dump {
+                directory_name: directory_name.to_string(),
+                size: 0,
+                created_at: epoch_millis(),
+                compressed: true,
+                encrypted: false,
+                encryption_version: None,
+                compression_codec: None,
+                chunks: vec![],
+                status: DumpStatus::Completed,
+            });
+
+            assert!(create_blob(
+                &azure.client,
+                container.as_str(),
+                format!("{}/0.dump", directory_name).as_str(),
+                b"hello w0rld".to_vec(),
+            )
+            .is_ok());
+        }
+
+        assert!(azure.write_index_file(&index_file).is_ok());
+        assert_eq!(azure.index_file().unwrap().dumps.len(), 2);
+
+        assert!(azure
+            .delete(&DumpDeleteArgs {
+                dump: Some("dump-1".to_string()),
+                older_than: None,
+                keep_last: None,
+            })
+            .is_ok());
+
+        assert_eq!(azure.index_file().unwrap().dumps.len(), 1);
+        assert!(list_blobs(&azure.client, container.as_str(), "dump-1/")
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            list_blobs(&azure.client, container.as_str(), "dump-2/").unwrap(),
+            vec!["dump-2/0.dump".to_string()]
+        );
+
+        assert!(delete_container(&azure.client, container.as_str()).is_ok());
+    }
+}
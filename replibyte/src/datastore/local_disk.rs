@@ -9,20 +9,23 @@ use crate::connector::Connector;
 use crate::types;
 use crate::utils::epoch_millis;
 
-use super::{compress, decompress, decrypt, encrypt, Datastore, Dump, IndexFile, INDEX_FILE_NAME};
+use super::{
+    chunk_part, decompress, decrypt, ChunkQueries, CompressionOptions, Datastore, Dump, DumpChunk,
+    DumpStatus, EncryptionKey, IndexFile, ENCRYPTION_VERSION, INDEX_FILE_NAME,
+};
 
 pub struct LocalDisk {
     dir: String,
     dump_name: String,
-    enable_compression: bool,
-    encryption_key: Option<String>,
+    compression: Option<CompressionOptions>,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl LocalDisk {
     pub fn new<S: Into<String>>(dir: S) -> Self {
         Self {
             dir: dir.into(),
-            enable_compression: true,
+            compression: Some(CompressionOptions::default()),
             encryption_key: None,
             dump_name: format!("dump-{}", epoch_millis()),
         }
@@ -108,21 +111,14 @@ impl Datastore for LocalDisk {
         serde_json::to_writer(file, raw_index_file).map_err(|err| Error::from(err))
     }
 
-    fn write(&self, file_part: u16, data: types::Bytes) -> Result<(), Error> {
-        // compress data?
-        let data = if self.compression_enabled() {
-            compress(data)?
-        } else {
-            data
-        };
-
-        // encrypt data?
-        let data = match self.encryption_key() {
-            Some(key) => encrypt(data, key.as_str())?,
-            None => data,
-        };
-
+    fn write_chunk(
+        &self,
+        file_part: u16,
+        queries: &ChunkQueries,
+        data: types::Bytes,
+    ) -> Result<(), Error> {
         let data_size = data.len();
+        let chunk = DumpChunk::new(file_part, queries, &data);
         let dump_dir_path = format!("{}/{}", self.dir, self.dump_name);
         let dump_file_path = format!("{}/{}.dump", dump_dir_path, file_part);
 
@@ -148,8 +144,12 @@ impl Datastore for LocalDisk {
             directory_name: self.dump_name.to_string(),
             size: 0,
             created_at: epoch_millis(),
-            compressed: self.compression_enabled(),
+            compressed: self.compression().is_some(),
             encrypted: self.encryption_key().is_some(),
+            encryption_version: self.encryption_key().as_ref().map(|_| ENCRYPTION_VERSION),
+            compression_codec: self.compression().map(|options| options.codec),
+            chunks: vec![],
+            status: DumpStatus::InProgress,
         };
 
         // find or create Dump
@@ -159,6 +159,8 @@ impl Datastore for LocalDisk {
             .find(|b| b.directory_name.as_str() == self.dump_name)
             .unwrap_or(&mut new_dump);
 
+        dump.chunks.push(chunk);
+
         if dump.size == 0 {
             // it means it's a new dump.
             // We need to add it into the index_file.dumps
@@ -191,14 +193,14 @@ impl Datastore for LocalDisk {
                 // It should be safe to unwrap here because the dump is marked as encrypted in the dump manifest
                 // so if there is no encryption key set at the datastore level we want to panic.
                 let encryption_key = self.encryption_key.as_ref().unwrap();
-                decrypt(data, encryption_key.as_str())?
+                decrypt(data, encryption_key, dump.encryption_version)?
             } else {
                 data
             };
 
             // decompress data?
             let data = if dump.compressed {
-                decompress(data)?
+                decompress(data, dump.compression_codec)?
             } else {
                 data
             };
@@ -209,25 +211,50 @@ impl Datastore for LocalDisk {
         Ok(())
     }
 
-    fn compression_enabled(&self) -> bool {
-        self.enable_compression
+    fn read_chunks(
+        &self,
+        dump: &Dump,
+        chunk_callback: &mut dyn FnMut(u16, types::Bytes),
+    ) -> Result<(), Error> {
+        let entries = read_dir(format!("{}/{}", self.dir, dump.directory_name))?;
+
+        for entry in entries {
+            let entry = entry?;
+
+            let part = match entry.file_name().to_str().and_then(chunk_part) {
+                Some(part) => part,
+                None => continue,
+            };
+
+            chunk_callback(part, read(entry.path())?);
+        }
+
+        Ok(())
+    }
+
+    fn compression(&self) -> Option<CompressionOptions> {
+        self.compression
     }
 
-    fn set_compression(&mut self, enable: bool) {
-        if !enable {
+    fn set_compression(&mut self, compression: Option<CompressionOptions>) {
+        if compression.is_none() {
             info!("disable datastore compression");
         }
 
-        self.enable_compression = enable;
+        self.compression = compression;
     }
 
-    fn encryption_key(&self) -> &Option<String> {
+    fn encryption_key(&self) -> &Option<EncryptionKey> {
         &self.encryption_key
     }
 
     fn set_encryption_key(&mut self, key: String) {
         info!("set datastore encryption_key");
-        self.encryption_key = Some(key)
+        self.encryption_key = Some(EncryptionKey::new(key))
+    }
+
+    fn dump_name(&self) -> String {
+        self.dump_name.to_string()
     }
 
     fn set_dump_name(&mut self, name: String) {
@@ -262,16 +289,29 @@ mod tests {
     use crate::{
         cli::DumpDeleteArgs,
         connector::Connector,
-        datastore::{Datastore, Dump, ReadOptions, INDEX_FILE_NAME},
+        datastore::{
+            ChunkMismatch, ChunkQueries, Datastore, Dump, DumpStatus, ReadOptions, INDEX_FILE_NAME,
+        },
         migration::{
             rename_backups_to_dumps::RenameBackupsToDump,
             update_version_number::UpdateVersionNumber, Migrator,
         },
+        types::Query,
         utils::epoch_millis,
     };
 
     use super::LocalDisk;
 
+    fn chunk_queries(count: usize) -> ChunkQueries {
+        let mut queries = ChunkQueries::default();
+        for idx in 0..count {
+            let query = format!("INSERT INTO t VALUES ({});", idx);
+            queries.push(&Query(query.into_bytes()));
+        }
+
+        queries
+    }
+
     // update_dump_date is a helper function that updates the date of a dump inside the index file.
     fn update_dump_date(local_disk: &LocalDisk, dump_name: String, days_before_now: i64) {
         let mut index_file = local_disk.index_file().unwrap();
@@ -304,7 +344,8 @@ mod tests {
 
         let bytes: Vec<u8> = b"hello world".to_vec();
 
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
+        assert!(local_disk.set_dump_status(DumpStatus::Completed).is_ok());
 
         // index_file should contain 1 dump
         let mut index_file = local_disk.index_file().unwrap();
@@ -330,6 +371,100 @@ mod tests {
         assert_eq!(dump_content, b"hello world".to_vec())
     }
 
+    #[test]
+    fn test_verify() {
+        let dir = tempdir().expect("cannot create tempdir");
+        let mut local_disk = LocalDisk::new(dir.path().to_str().unwrap().to_string());
+        let _ = local_disk.init().expect("local_disk init failed");
+        local_disk.set_encryption_key("this is my secret".to_string());
+
+        assert!(local_disk
+            .write(1, &chunk_queries(1), b"hello world".to_vec())
+            .is_ok());
+        assert!(local_disk
+            .write(2, &chunk_queries(1), b"hello w0rld".to_vec())
+            .is_ok());
+        assert!(local_disk
+            .write(3, &chunk_queries(1), b"hello wor1d".to_vec())
+            .is_ok());
+        assert!(local_disk.set_dump_status(DumpStatus::Completed).is_ok());
+
+        let mut index_file = local_disk.index_file().unwrap();
+        let dump = index_file.find_dump(&ReadOptions::Latest).unwrap();
+        assert_eq!(dump.chunks.len(), 3);
+        assert!(local_disk.verify(&ReadOptions::Latest).unwrap().is_empty());
+
+        let chunk_path = |part: u16| {
+            format!(
+                "{}/{}/{}.dump",
+                dir.path().to_str().unwrap(),
+                dump.directory_name,
+                part
+            )
+        };
+
+        // truncated chunk
+        let data = std::fs::read(chunk_path(1)).unwrap();
+        std::fs::write(chunk_path(1), &data[..data.len() - 1]).unwrap();
+        // corrupted chunk of the same size
+        let mut data = std::fs::read(chunk_path(2)).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        std::fs::write(chunk_path(2), data).unwrap();
+        // missing chunk
+        std::fs::remove_file(chunk_path(3)).unwrap();
+
+        let mismatches = local_disk.verify(&ReadOptions::Latest).unwrap();
+        assert_eq!(mismatches.len(), 5);
+        assert_eq!(mismatches[0], ChunkMismatch::Missing { part: 3 });
+        assert!(matches!(mismatches[1], ChunkMismatch::Size { part: 1, .. }));
+        assert_eq!(mismatches[2], ChunkMismatch::Digest { part: 2 });
+        assert!(matches!(
+            mismatches[3],
+            ChunkMismatch::Unreadable { part: 1, .. }
+        ));
+        assert!(matches!(
+            mismatches[4],
+            ChunkMismatch::Unreadable { part: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_dump_status() {
+        let dir = tempdir().expect("cannot create tempdir");
+        let mut local_disk = LocalDisk::new(dir.path().to_str().unwrap().to_string());
+        let _ = local_disk.init().expect("local_disk init failed");
+
+        // nothing to update before the first chunk is written
+        assert!(local_disk.set_dump_status(DumpStatus::Failed).is_ok());
+
+        local_disk.set_dump_name("dump-1".to_string());
+        assert!(local_disk
+            .write(1, &chunk_queries(1), b"hello world".to_vec())
+            .is_ok());
+        assert!(local_disk.set_dump_status(DumpStatus::Completed).is_ok());
+
+        // dump-2 is interrupted
+        local_disk.set_dump_name("dump-2".to_string());
+        assert!(local_disk
+            .write(1, &chunk_queries(2), b"hello w0rld".to_vec())
+            .is_ok());
+
+        let mut index_file = local_disk.index_file().unwrap();
+        assert_eq!(index_file.dumps[1].status, DumpStatus::InProgress);
+        assert_eq!(index_file.dumps[1].chunks[0].queries, 2);
+
+        // latest ignores the dumps which are not completed
+        let dump = index_file.find_dump(&ReadOptions::Latest).unwrap();
+        assert_eq!(dump.directory_name, "dump-1".to_string());
+
+        assert!(local_disk.set_dump_status(DumpStatus::Failed).is_ok());
+        let mut index_file = local_disk.index_file().unwrap();
+        assert_eq!(index_file.dumps[1].status, DumpStatus::Failed);
+        let dump = index_file.find_dump(&ReadOptions::Latest).unwrap();
+        assert_eq!(dump.directory_name, "dump-1".to_string());
+    }
+
     #[test]
     fn test_index_file() {
         let dir = tempdir().expect("cannot create tempdir");
@@ -348,6 +483,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         assert!(local_disk.write_index_file(&index_file).is_ok());
@@ -379,14 +518,14 @@ mod tests {
         // create dump 1
         local_disk.set_dump_name("dump-1".to_string());
         let bytes: Vec<u8> = b"hello world from dump-1".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 1);
         assert!(Path::new(&format!("{}/dump-1", dir.path().to_str().unwrap())).exists());
 
         // create dump 2
         local_disk.set_dump_name("dump-2".to_string());
         let bytes: Vec<u8> = b"hello world from dump-2".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 2);
         assert!(Path::new(&format!("{}/dump-2", dir.path().to_str().unwrap())).exists());
 
@@ -427,7 +566,7 @@ mod tests {
         // create dump 1
         local_disk.set_dump_name("dump-1".to_string());
         let bytes: Vec<u8> = b"hello world from dump-1".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 1);
         assert!(Path::new(&format!("{}/dump-1", dir.path().to_str().unwrap())).exists());
         update_dump_date(&local_disk, "dump-1".to_string(), 3);
@@ -435,7 +574,7 @@ mod tests {
         // create dump 2
         local_disk.set_dump_name("dump-2".to_string());
         let bytes: Vec<u8> = b"hello world from dump-2".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 2);
         assert!(Path::new(&format!("{}/dump-2", dir.path().to_str().unwrap())).exists());
         update_dump_date(&local_disk, "dump-2".to_string(), 2);
@@ -443,7 +582,7 @@ mod tests {
         // create dump 3
         local_disk.set_dump_name("dump-3".to_string());
         let bytes: Vec<u8> = b"hello world from dump-3".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 3);
         assert!(Path::new(&format!("{}/dump-3", dir.path().to_str().unwrap())).exists());
         update_dump_date(&local_disk, "dump-3".to_string(), 1);
@@ -489,7 +628,7 @@ mod tests {
         // create dump 1
         local_disk.set_dump_name("dump-1".to_string());
         let bytes: Vec<u8> = b"hello world from dump-1".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 1);
         assert!(Path::new(&format!("{}/dump-1", dir.path().to_str().unwrap())).exists());
         update_dump_date(&local_disk, "dump-1".to_string(), 5);
@@ -497,7 +636,7 @@ mod tests {
         // create dump 2
         local_disk.set_dump_name("dump-2".to_string());
         let bytes: Vec<u8> = b"hello world from dump-2".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 2);
         assert!(Path::new(&format!("{}/dump-2", dir.path().to_str().unwrap())).exists());
         update_dump_date(&local_disk, "dump-2".to_string(), 3);
@@ -505,7 +644,7 @@ mod tests {
         // create dump 3
         local_disk.set_dump_name("dump-3".to_string());
         let bytes: Vec<u8> = b"hello world from dump-3".to_vec();
-        assert!(local_disk.write(1, bytes).is_ok());
+        assert!(local_disk.write(1, &chunk_queries(1), bytes).is_ok());
         assert_eq!(local_disk.index_file().unwrap().dumps.len(), 3);
         assert!(Path::new(&format!("{}/dump-3", dir.path().to_str().unwrap())).exists());
 
@@ -626,7 +765,11 @@ mod tests {
                 size: 62279,
                 created_at: 1234,
                 compressed: true,
-                encrypted: false
+                encrypted: false,
+                encryption_version: None,
+                compression_codec: None,
+                chunks: vec![],
+                status: DumpStatus::Completed,
             })
         );
         assert_eq!(
@@ -636,7 +779,11 @@ mod tests {
                 size: 62283,
                 created_at: 5678,
                 compressed: true,
-                encrypted: false
+                encrypted: false,
+                encryption_version: None,
+                compression_codec: None,
+                chunks: vec![],
+                status: DumpStatus::Completed,
             })
         );
     }
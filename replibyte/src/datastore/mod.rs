@@ -1,43 +1,100 @@
 use aes_gcm::aead::{Aead, NewAead};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
 use chrono::{Duration, Utc};
+use lazy_static::lazy_static;
+use rand::RngCore;
 use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
 use std::io::{Error, ErrorKind, Read, Write};
+use std::sync::Mutex;
 
-use flate2::read::ZlibDecoder;
-use flate2::write::ZlibEncoder;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
 use flate2::Compression;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::cli::DumpDeleteArgs;
 use crate::connector::Connector;
-use crate::types::Bytes;
+use crate::types::{Bytes, OriginalQuery};
 use crate::utils::get_replibyte_version;
 
+pub mod azure;
 pub mod local_disk;
 pub mod s3;
 
 const INDEX_FILE_NAME: &str = "metadata.json";
 
+/// chunks encrypted with a constant nonce and a padded key - dumps created before the encryption was versioned
+pub const LEGACY_ENCRYPTION_VERSION: u8 = 1;
+/// chunks starting with a header: magic number, version, salt and nonce
+pub const ENCRYPTION_VERSION: u8 = 2;
+const ENCRYPTION_MAGIC_NUMBER: &[u8; 4] = b"RBYT";
+const ENCRYPTION_SALT_LENGTH: usize = 16;
+const ENCRYPTION_NONCE_LENGTH: usize = 12;
+const ENCRYPTION_HEADER_LENGTH: usize =
+    ENCRYPTION_MAGIC_NUMBER.len() + 1 + ENCRYPTION_SALT_LENGTH + ENCRYPTION_NONCE_LENGTH;
+
+lazy_static! {
+    /// salt of the chunks encrypted by this process - the key is derived once for all the chunks of a dump
+    static ref ENCRYPTION_SALT: [u8; ENCRYPTION_SALT_LENGTH] = {
+        let mut salt = [0u8; ENCRYPTION_SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        salt
+    };
+}
+
 pub trait Datastore: Connector + Send + Sync {
     /// Getting Index file with all the dumps information
     fn index_file(&self) -> Result<IndexFile, Error>;
     fn raw_index_file(&self) -> Result<Value, Error>;
     fn write_index_file(&self, index_file: &IndexFile) -> Result<(), Error>;
     fn write_raw_index_file(&self, raw_index_file: &Value) -> Result<(), Error>;
-    fn write(&self, file_part: u16, data: Bytes) -> Result<(), Error>;
+    /// write a chunk of the current dump - already compressed and encrypted - made of the original `queries`
+    fn write_chunk(&self, file_part: u16, queries: &ChunkQueries, data: Bytes)
+        -> Result<(), Error>;
     fn read(
         &self,
         options: &ReadOptions,
         data_callback: &mut dyn FnMut(Bytes),
     ) -> Result<(), Error>;
-    fn compression_enabled(&self) -> bool;
-    fn set_compression(&mut self, enable: bool);
-    fn encryption_key(&self) -> &Option<String>;
+    /// read the chunks of a dump as they are stored - compressed and encrypted - with their part number
+    fn read_chunks(
+        &self,
+        dump: &Dump,
+        chunk_callback: &mut dyn FnMut(u16, Bytes),
+    ) -> Result<(), Error>;
+    fn compression(&self) -> Option<CompressionOptions>;
+    fn set_compression(&mut self, compression: Option<CompressionOptions>);
+    fn encryption_key(&self) -> &Option<EncryptionKey>;
     fn set_encryption_key(&mut self, key: String);
+    fn dump_name(&self) -> String;
     fn set_dump_name(&mut self, name: String);
     fn delete_by_name(&self, name: String) -> Result<(), Error>;
 
+    /// compress and encrypt a chunk before it is written with `write_chunk`
+    fn encode_chunk(&self, data: Bytes) -> Result<Bytes, Error> {
+        // compress data?
+        let data = match self.compression() {
+            Some(options) => compress(data, &options)?,
+            None => data,
+        };
+
+        // encrypt data?
+        match self.encryption_key() {
+            Some(key) => encrypt(data, key),
+            None => Ok(data),
+        }
+    }
+
+    /// write a chunk of the current dump made of the original `queries`
+    fn write(&self, file_part: u16, queries: &ChunkQueries, data: Bytes) -> Result<(), Error> {
+        let data = self.encode_chunk(data)?;
+        self.write_chunk(file_part, queries, data)
+    }
+
     fn delete(&self, args: &DumpDeleteArgs) -> Result<(), Error> {
         if let Some(dump_name) = &args.dump {
             return self.delete_by_name(dump_name.to_string());
@@ -79,6 +136,23 @@ pub trait Datastore: Connector + Send + Sync {
         ))
     }
 
+    /// update the status of the current dump in the index - nothing to do if no chunk was written yet
+    fn set_dump_status(&self, status: DumpStatus) -> Result<(), Error> {
+        let mut index_file = self.index_file()?;
+        let dump_name = self.dump_name();
+
+        match index_file
+            .dumps
+            .iter_mut()
+            .find(|dump| dump.directory_name == dump_name)
+        {
+            Some(dump) => dump.status = status,
+            None => return Ok(()),
+        }
+
+        self.write_index_file(&index_file)
+    }
+
     fn delete_older_than(&self, days: i64) -> Result<(), Error> {
         let index_file = self.index_file()?;
 
@@ -115,6 +189,72 @@ pub trait Datastore: Connector + Send + Sync {
 
         Ok(())
     }
+
+    /// download each chunk of a dump and check its size and digest against the index,
+    /// and that it can be decrypted and decompressed - nothing is restored
+    fn verify(&self, options: &ReadOptions) -> Result<Vec<ChunkMismatch>, Error> {
+        let mut index_file = self.index_file()?;
+        let dump = index_file.find_dump(options)?;
+
+        if dump.encrypted && self.encryption_key().is_none() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "the dump is encrypted - <encryption_key> is missing in the configuration file",
+            ));
+        }
+
+        let mut mismatches = vec![];
+        let mut read_parts = BTreeSet::new();
+
+        self.read_chunks(dump, &mut |part, data| {
+            let _ = read_parts.insert(part);
+
+            // dumps written before the checksums only have their chunks decrypted and decompressed
+            if !dump.chunks.is_empty() {
+                match dump.chunks.iter().find(|chunk| chunk.part == part) {
+                    Some(chunk) if chunk.size != data.len() => {
+                        mismatches.push(ChunkMismatch::Size {
+                            part,
+                            expected: chunk.size,
+                            actual: data.len(),
+                        })
+                    }
+                    Some(chunk) if chunk.sha256 != sha256_digest(&data) => {
+                        mismatches.push(ChunkMismatch::Digest { part })
+                    }
+                    Some(_) => {}
+                    None => mismatches.push(ChunkMismatch::Unexpected { part }),
+                }
+            }
+
+            let data = match (dump.encrypted, self.encryption_key()) {
+                (true, Some(key)) => decrypt(data, key, dump.encryption_version),
+                _ => Ok(data),
+            };
+
+            let data = match data {
+                Ok(data) if dump.compressed => decompress(data, dump.compression_codec),
+                data => data,
+            };
+
+            if let Err(err) = data {
+                mismatches.push(ChunkMismatch::Unreadable {
+                    part,
+                    error: err.to_string(),
+                });
+            }
+        })?;
+
+        for chunk in &dump.chunks {
+            if !read_parts.contains(&chunk.part) {
+                mismatches.push(ChunkMismatch::Missing { part: chunk.part });
+            }
+        }
+
+        mismatches.sort();
+
+        Ok(mismatches)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -136,7 +276,13 @@ impl IndexFile {
             ReadOptions::Latest => {
                 self.dumps.sort_by(|a, b| a.created_at.cmp(&b.created_at));
 
-                match self.dumps.last() {
+                // dumps which are in progress or failed can't be restored
+                match self
+                    .dumps
+                    .iter()
+                    .rev()
+                    .find(|dump| dump.status == DumpStatus::Completed)
+                {
                     Some(dump) => Ok(dump),
                     None => return Err(Error::new(ErrorKind::Other, "No dumps available.")),
                 }
@@ -167,6 +313,191 @@ pub struct Dump {
     pub created_at: u128,
     pub compressed: bool,
     pub encrypted: bool,
+    // None for dumps which are not encrypted or written before the encryption was versioned
+    #[serde(default)]
+    pub encryption_version: Option<u8>,
+    // None for dumps which are not compressed or written before the codec was configurable (zlib)
+    #[serde(default)]
+    pub compression_codec: Option<CompressionCodec>,
+    // empty for dumps written before the chunks were recorded
+    #[serde(default)]
+    pub chunks: Vec<DumpChunk>,
+    // Completed for dumps written before the status was recorded
+    #[serde(default)]
+    pub status: DumpStatus,
+}
+
+/// a dump is completed once all its chunks are written - `latest` ignores the other dumps
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DumpStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+impl Default for DumpStatus {
+    fn default() -> Self {
+        DumpStatus::Completed
+    }
+}
+
+impl fmt::Display for DumpStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpStatus::InProgress => write!(f, "in_progress"),
+            DumpStatus::Completed => write!(f, "completed"),
+            DumpStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// a chunk as written to the datastore - compressed and encrypted
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct DumpChunk {
+    pub part: u16,
+    pub size: usize,
+    // hex encoded SHA-256 digest
+    pub sha256: String,
+    // number of queries in the chunk - 0 for chunks written before it was recorded
+    #[serde(default)]
+    pub queries: usize,
+    // hex encoded SHA-256 digest of the original queries - empty for chunks written before it was recorded
+    #[serde(default)]
+    pub fingerprint: String,
+}
+
+impl DumpChunk {
+    pub fn new(part: u16, queries: &ChunkQueries, data: &[u8]) -> Self {
+        DumpChunk {
+            part,
+            size: data.len(),
+            sha256: sha256_digest(data),
+            queries: queries.count(),
+            fingerprint: queries.fingerprint(),
+        }
+    }
+}
+
+/// original queries of a chunk, before they are transformed - a resumed dump reads the same
+/// queries again and compares them to the chunks already written before skipping them
+#[derive(Clone, Default)]
+pub struct ChunkQueries {
+    count: usize,
+    hasher: Sha256,
+}
+
+impl ChunkQueries {
+    pub fn push(&mut self, original_query: &OriginalQuery) {
+        let data = original_query.data();
+        // the length delimits the queries - the digest differs when a query is split in two
+        self.hasher.update((data.len() as u64).to_le_bytes());
+        self.hasher.update(data);
+        self.count += 1;
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// hex encoded SHA-256 digest of the queries
+    pub fn fingerprint(&self) -> String {
+        self.hasher
+            .clone()
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// a chunk of a dump which does not match the index
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChunkMismatch {
+    Missing {
+        part: u16,
+    },
+    Unexpected {
+        part: u16,
+    },
+    Size {
+        part: u16,
+        expected: usize,
+        actual: usize,
+    },
+    Digest {
+        part: u16,
+    },
+    Unreadable {
+        part: u16,
+        error: String,
+    },
+}
+
+impl fmt::Display for ChunkMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkMismatch::Missing { part } => {
+                write!(f, "chunk {} is in the index but not in the datastore", part)
+            }
+            ChunkMismatch::Unexpected { part } => {
+                write!(f, "chunk {} is in the datastore but not in the index", part)
+            }
+            ChunkMismatch::Size {
+                part,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "chunk {} has a size of {} bytes instead of {} bytes",
+                part, actual, expected
+            ),
+            ChunkMismatch::Digest { part } => {
+                write!(f, "chunk {} does not match its SHA-256 digest", part)
+            }
+            ChunkMismatch::Unreadable { part, error } => {
+                write!(
+                    f,
+                    "chunk {} can't be decrypted or decompressed: {}",
+                    part, error
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionCodec {
+    Zlib,
+    Gzip,
+    Zstd,
+}
+
+impl fmt::Display for CompressionCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressionCodec::Zlib => write!(f, "zlib"),
+            CompressionCodec::Gzip => write!(f, "gzip"),
+            CompressionCodec::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CompressionOptions {
+    pub codec: CompressionCodec,
+    // default level of the codec when None
+    pub level: Option<i32>,
+}
+
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        CompressionOptions {
+            codec: CompressionCodec::Zlib,
+            level: None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
@@ -175,19 +506,62 @@ pub enum ReadOptions {
     Dump { name: String },
 }
 
-fn compress(data: Bytes) -> Result<Bytes, Error> {
-    let mut enc = ZlibEncoder::new(Vec::new(), Compression::default());
-    let _ = enc.write_all(data.as_slice());
-    enc.flush_finish()
+fn compress(data: Bytes, options: &CompressionOptions) -> Result<Bytes, Error> {
+    let flate2_level = match options.level {
+        Some(level) => Compression::new(level as u32),
+        None => Compression::default(),
+    };
+
+    match options.codec {
+        CompressionCodec::Zlib => {
+            let mut enc = ZlibEncoder::new(Vec::new(), flate2_level);
+            let _ = enc.write_all(data.as_slice())?;
+            enc.finish()
+        }
+        CompressionCodec::Gzip => {
+            let mut enc = GzEncoder::new(Vec::new(), flate2_level);
+            let _ = enc.write_all(data.as_slice())?;
+            enc.finish()
+        }
+        // level 0 is the zstd default level
+        CompressionCodec::Zstd => zstd::encode_all(data.as_slice(), options.level.unwrap_or(0)),
+    }
 }
 
-fn decompress(data: Bytes) -> Result<Bytes, Error> {
-    let mut dec = ZlibDecoder::new(data.as_slice());
+/// decompress a chunk written with the compression `codec` of the dump - zlib for the dumps written before the codec was configurable
+fn decompress(data: Bytes, codec: Option<CompressionCodec>) -> Result<Bytes, Error> {
     let mut decoded_data = Vec::new();
-    let _ = dec.read_to_end(&mut decoded_data);
+
+    match codec.unwrap_or(CompressionCodec::Zlib) {
+        CompressionCodec::Zlib => {
+            let _ = ZlibDecoder::new(data.as_slice()).read_to_end(&mut decoded_data)?;
+        }
+        CompressionCodec::Gzip => {
+            let _ = GzDecoder::new(data.as_slice()).read_to_end(&mut decoded_data)?;
+        }
+        CompressionCodec::Zstd => decoded_data = zstd::decode_all(data.as_slice())?,
+    }
+
     Ok(decoded_data)
 }
 
+/// hex encoded SHA-256 digest of a chunk
+fn sha256_digest(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// part number of a chunk from its file or object name - `<dump name>/<part>.dump`
+fn chunk_part(name: &str) -> Option<u16> {
+    name.rsplit('/')
+        .next()
+        .and_then(|file_name| file_name.strip_suffix(".dump"))
+        .and_then(|part| part.parse::<u16>().ok())
+}
+
+/// legacy key derivation - only used to decrypt the dumps encrypted with `LEGACY_ENCRYPTION_VERSION`
 fn get_encryption_key_with_correct_length(key: &str) -> String {
     if key.len() >= 32 {
         return key[0..32].to_string();
@@ -201,21 +575,119 @@ fn get_encryption_key_with_correct_length(key: &str) -> String {
     key_string
 }
 
-fn encrypt(data: Bytes, encryption_key: &str) -> Result<Bytes, Error> {
-    let key = get_encryption_key_with_correct_length(encryption_key);
-    let key = Key::from_slice(key.as_bytes());
-    let cipher = Aes256Gcm::new(key);
-    let nonce = Nonce::from_slice(b"unique nonce");
+/// encryption key of a datastore, with the keys derived from it by salt - deriving a key is deliberately slow
+pub struct EncryptionKey {
+    key: String,
+    derived_keys: Mutex<HashMap<Vec<u8>, [u8; 32]>>,
+}
 
-    let encrypted_data = match cipher.encrypt(nonce, data.as_slice()) {
+impl EncryptionKey {
+    pub fn new(key: String) -> Self {
+        EncryptionKey {
+            key,
+            derived_keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.key.as_str()
+    }
+
+    /// derive a 256 bits key with Argon2id - once by salt. The lock is not held while deriving
+    /// so the chunks with a known salt are not blocked - a salt can be derived twice at worst
+    fn derive(&self, salt: &[u8]) -> Result<[u8; 32], Error> {
+        let derived_key = self.derived_keys.lock().unwrap().get(salt).copied();
+        if let Some(key) = derived_key {
+            return Ok(key);
+        }
+
+        let mut key = [0u8; 32];
+
+        match Argon2::default().hash_password_into(self.key.as_bytes(), salt, &mut key) {
+            Ok(_) => {
+                let _ = self.derived_keys.lock().unwrap().insert(salt.to_vec(), key);
+                Ok(key)
+            }
+            Err(err) => Err(Error::new(ErrorKind::Other, format!("{}", err))),
+        }
+    }
+}
+
+/// encrypt a chunk with `ENCRYPTION_VERSION`.
+/// The chunk is prefixed with a header: magic number (4 bytes), version (1 byte), salt (16 bytes) and nonce (12 bytes).
+/// The chunks share the salt of the process and have a random nonce each.
+fn encrypt(data: Bytes, encryption_key: &EncryptionKey) -> Result<Bytes, Error> {
+    let salt = *ENCRYPTION_SALT;
+    let mut nonce = [0u8; ENCRYPTION_NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let key = encryption_key.derive(&salt)?;
+    let cipher = Aes256Gcm::new(Key::from_slice(&key));
+
+    let encrypted_data = match cipher.encrypt(Nonce::from_slice(&nonce), data.as_slice()) {
         Ok(data) => data,
         Err(err) => return Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
     };
 
-    Ok(encrypted_data)
+    let mut chunk = Vec::with_capacity(ENCRYPTION_HEADER_LENGTH + encrypted_data.len());
+    chunk.extend_from_slice(ENCRYPTION_MAGIC_NUMBER);
+    chunk.push(ENCRYPTION_VERSION);
+    chunk.extend_from_slice(&salt);
+    chunk.extend_from_slice(&nonce);
+    chunk.extend_from_slice(encrypted_data.as_slice());
+
+    Ok(chunk)
 }
 
-fn decrypt(encrypted_data: Bytes, encryption_key: &str) -> Result<Bytes, Error> {
+/// decrypt a chunk written with the encryption `version` of the dump
+fn decrypt(
+    encrypted_data: Bytes,
+    encryption_key: &EncryptionKey,
+    version: Option<u8>,
+) -> Result<Bytes, Error> {
+    match version.unwrap_or(LEGACY_ENCRYPTION_VERSION) {
+        LEGACY_ENCRYPTION_VERSION => decrypt_legacy(encrypted_data, encryption_key.as_str()),
+        ENCRYPTION_VERSION => decrypt_chunk(encrypted_data, encryption_key),
+        version => Err(Error::new(
+            ErrorKind::Other,
+            format!("unsupported encryption version '{}'", version),
+        )),
+    }
+}
+
+fn decrypt_chunk(encrypted_data: Bytes, encryption_key: &EncryptionKey) -> Result<Bytes, Error> {
+    if encrypted_data.len() < ENCRYPTION_HEADER_LENGTH
+        || &encrypted_data[0..ENCRYPTION_MAGIC_NUMBER.len()] != ENCRYPTION_MAGIC_NUMBER
+    {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "invalid encrypted chunk: missing header",
+        ));
+    }
+
+    let (header, encrypted_data) = encrypted_data.split_at(ENCRYPTION_HEADER_LENGTH);
+    let (version, header) = header[ENCRYPTION_MAGIC_NUMBER.len()..].split_at(1);
+    let (salt, nonce) = header.split_at(ENCRYPTION_SALT_LENGTH);
+
+    if version[0] != ENCRYPTION_VERSION {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("unsupported encryption version '{}'", version[0]),
+        ));
+    }
+
+    let key = encryption_key.derive(salt)?;
+    let cipher = Aes256Gcm::new(Key::from_slice(&key));
+
+    let data = match cipher.decrypt(Nonce::from_slice(nonce), encrypted_data) {
+        Ok(data) => data,
+        Err(err) => return Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    };
+
+    Ok(data)
+}
+
+fn decrypt_legacy(encrypted_data: Bytes, encryption_key: &str) -> Result<Bytes, Error> {
     let key = get_encryption_key_with_correct_length(encryption_key);
     let key = Key::from_slice(key.as_bytes());
     let cipher = Aes256Gcm::new(key);
@@ -231,31 +703,156 @@ fn decrypt(encrypted_data: Bytes, encryption_key: &str) -> Result<Bytes, Error>
 
 #[cfg(test)]
 mod tests {
-    use crate::datastore::{compress, decompress, decrypt, encrypt};
+    use aes_gcm::aead::{Aead, NewAead};
+    use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+    use crate::datastore::{
+        chunk_part, compress, decompress, decrypt, encrypt, get_encryption_key_with_correct_length,
+        ChunkQueries, CompressionCodec, CompressionOptions, DumpChunk, EncryptionKey,
+        ENCRYPTION_VERSION, LEGACY_ENCRYPTION_VERSION,
+    };
+    use crate::types::Query;
 
     #[test]
     fn test_compression() {
         let data = b"hello w0rld - this is a long sentence right?".to_vec();
-        let compressed_data = compress(data.clone()).unwrap();
+        let compressed_data = compress(data.clone(), &CompressionOptions::default()).unwrap();
         assert_ne!(data, compressed_data);
-        assert_eq!(decompress(compressed_data).unwrap(), data);
+        assert_eq!(decompress(compressed_data.clone(), None).unwrap(), data);
+        assert_eq!(
+            decompress(compressed_data, Some(CompressionCodec::Zlib)).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_compression_codecs() {
+        let data = b"hello w0rld hello w0rld hello w0rld hello w0rld hello w0rld".to_vec();
+
+        for codec in [
+            CompressionCodec::Zlib,
+            CompressionCodec::Gzip,
+            CompressionCodec::Zstd,
+        ] {
+            for level in [None, Some(1), Some(9)] {
+                let options = CompressionOptions { codec, level };
+                let compressed_data = compress(data.clone(), &options).unwrap();
+                assert_ne!(data, compressed_data);
+                assert_eq!(decompress(compressed_data, Some(codec)).unwrap(), data);
+            }
+        }
+
+        // a chunk is not read with another codec
+        let compressed_data = compress(
+            data.clone(),
+            &CompressionOptions {
+                codec: CompressionCodec::Zstd,
+                level: None,
+            },
+        )
+        .unwrap();
+        assert!(decompress(compressed_data, Some(CompressionCodec::Gzip)).is_err());
+    }
+
+    #[test]
+    fn test_dump_chunk() {
+        let mut queries = ChunkQueries::default();
+        queries.push(&Query(b"INSERT INTO t VALUES (1);".to_vec()));
+        queries.push(&Query(b"INSERT INTO t VALUES (2);".to_vec()));
+
+        let chunk = DumpChunk::new(1, &queries, b"hello world");
+        assert_eq!(chunk.size, 11);
+        assert_eq!(chunk.queries, 2);
+        assert_eq!(chunk.fingerprint.len(), 64);
+        assert_eq!(
+            chunk.sha256,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+
+        assert_eq!(chunk_part("dump-1654091316452/12.dump"), Some(12));
+        assert_eq!(chunk_part("12.dump"), Some(12));
+        assert_eq!(chunk_part("dump-1654091316452/metadata.json"), None);
+    }
+
+    #[test]
+    fn test_chunk_queries_fingerprint() {
+        let fingerprint = |queries: &[&[u8]]| {
+            let mut chunk_queries = ChunkQueries::default();
+            for query in queries {
+                chunk_queries.push(&Query(query.to_vec()));
+            }
+
+            chunk_queries.fingerprint()
+        };
+
+        assert_eq!(fingerprint(&[b"a;", b"b;"]), fingerprint(&[b"a;", b"b;"]));
+        assert_ne!(fingerprint(&[b"a;", b"b;"]), fingerprint(&[b"a;", b"c;"]));
+        assert_ne!(fingerprint(&[b"a;", b"b;"]), fingerprint(&[b"a;b;"]));
     }
 
     #[test]
     fn test_encryption_1() {
-        let key = "this is my secret";
+        let key = &EncryptionKey::new("this is my secret".to_string());
         let data = b"hello w0rld hello w0rld hello w0rld hello w0rld hello w0rld".to_vec();
         let encrypted_data = encrypt(data.clone(), key).unwrap();
         assert_ne!(encrypted_data, data);
-        assert_eq!(decrypt(encrypted_data, key).unwrap(), data);
+        assert_eq!(
+            decrypt(encrypted_data, key, Some(ENCRYPTION_VERSION)).unwrap(),
+            data
+        );
     }
 
     #[test]
     fn test_encryption_2() {
-        let key = "this is my secret very very very long and greater than 32 chars";
+        let key = &EncryptionKey::new(
+            "this is my secret very very very long and greater than 32 chars".to_string(),
+        );
         let data = b"hello w0rld hello w0rld hello w0rld hello w0rld hello w0rld".to_vec();
         let encrypted_data = encrypt(data.clone(), key).unwrap();
         assert_ne!(encrypted_data, data);
-        assert_eq!(decrypt(encrypted_data, key).unwrap(), data);
+        assert_eq!(
+            decrypt(encrypted_data, key, Some(ENCRYPTION_VERSION)).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_encryption_with_random_nonce() {
+        let key = &EncryptionKey::new("this is my secret".to_string());
+        let data = b"hello w0rld hello w0rld hello w0rld hello w0rld hello w0rld".to_vec();
+        let encrypted_data_1 = encrypt(data.clone(), key).unwrap();
+        let encrypted_data_2 = encrypt(data.clone(), key).unwrap();
+        // same data and same key but different nonce - the key is derived once from the salt of the dump
+        assert_ne!(encrypted_data_1, encrypted_data_2);
+        assert_eq!(&encrypted_data_1[0..4], b"RBYT");
+        assert_eq!(encrypted_data_1[4], ENCRYPTION_VERSION);
+        assert_eq!(&encrypted_data_1[5..21], &encrypted_data_2[5..21]);
+        assert_ne!(&encrypted_data_1[21..33], &encrypted_data_2[21..33]);
+
+        assert!(decrypt(
+            encrypted_data_1,
+            &EncryptionKey::new("this is not my secret".to_string()),
+            Some(ENCRYPTION_VERSION)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_legacy_decryption() {
+        let key = &EncryptionKey::new("this is my secret".to_string());
+        let data = b"hello w0rld hello w0rld hello w0rld hello w0rld hello w0rld".to_vec();
+
+        // chunk encrypted by the previous versions
+        let legacy_key = get_encryption_key_with_correct_length(key.as_str());
+        let cipher = Aes256Gcm::new(Key::from_slice(legacy_key.as_bytes()));
+        let encrypted_data = cipher
+            .encrypt(Nonce::from_slice(b"unique nonce"), data.as_slice())
+            .unwrap();
+
+        assert_eq!(
+            decrypt(encrypted_data.clone(), key, Some(LEGACY_ENCRYPTION_VERSION)).unwrap(),
+            data
+        );
+        assert_eq!(decrypt(encrypted_data, key, None).unwrap(), data);
     }
 }
@@ -18,7 +18,8 @@ use crate::config::{AwsCredentials, Endpoint};
 use crate::connector::Connector;
 use crate::datastore::s3::S3Error::FailedObjectUpload;
 use crate::datastore::{
-    compress, decompress, decrypt, encrypt, Datastore, Dump, IndexFile, ReadOptions,
+    chunk_part, decompress, decrypt, ChunkQueries, CompressionOptions, Datastore, Dump, DumpChunk,
+    DumpStatus, EncryptionKey, IndexFile, ReadOptions, ENCRYPTION_VERSION,
 };
 use crate::runtime::block_on;
 use crate::types::Bytes;
@@ -34,8 +35,8 @@ pub struct S3 {
     region: Option<String>,
     endpoint: Endpoint,
     client: Client,
-    enable_compression: bool,
-    encryption_key: Option<String>,
+    compression: Option<CompressionOptions>,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl S3 {
@@ -110,7 +111,7 @@ impl S3 {
             region: region.map(|region| region.as_ref().into()),
             endpoint,
             client: Client::from_conf(s3_config),
-            enable_compression: true,
+            compression: Some(CompressionOptions::default()),
             encryption_key: None,
         })
     }
@@ -210,10 +211,16 @@ impl Datastore for S3 {
         .map_err(|err| Error::from(err))
     }
 
-    fn write(&self, file_part: u16, data: Bytes) -> Result<(), Error> {
+    fn write_chunk(
+        &self,
+        file_part: u16,
+        queries: &ChunkQueries,
+        data: Bytes,
+    ) -> Result<(), Error> {
         write_objects(
             self,
             file_part,
+            queries,
             data,
             self.bucket.as_str(),
             self.root_key.as_str(),
@@ -241,14 +248,14 @@ impl Datastore for S3 {
                 // It should be safe to unwrap here because the dump is marked as encrypted in the dump manifest
                 // so if there is no encryption key set at the datastore level we want to panic.
                 let encryption_key = self.encryption_key.as_ref().unwrap();
-                decrypt(data, encryption_key.as_str())?
+                decrypt(data, encryption_key, dump.encryption_version)?
             } else {
                 data
             };
 
             // decompress data?
             let data = if dump.compressed {
-                decompress(data)?
+                decompress(data, dump.compression_codec)?
             } else {
                 data
             };
@@ -259,23 +266,51 @@ impl Datastore for S3 {
         Ok(())
     }
 
+    fn read_chunks(
+        &self,
+        dump: &Dump,
+        chunk_callback: &mut dyn FnMut(u16, Bytes),
+    ) -> Result<(), Error> {
+        let prefix = format!("{}/", dump.directory_name);
+
+        for object in list_objects(&self.client, self.bucket.as_str(), Some(prefix.as_str()))? {
+            let key = match object.key() {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let part = match chunk_part(key) {
+                Some(part) => part,
+                None => continue,
+            };
+
+            chunk_callback(part, get_object(&self.client, self.bucket.as_str(), key)?);
+        }
+
+        Ok(())
+    }
+
     fn set_encryption_key(&mut self, key: String) {
-        self.encryption_key = Some(key);
+        self.encryption_key = Some(EncryptionKey::new(key));
     }
 
-    fn set_compression(&mut self, enable: bool) {
-        self.enable_compression = enable;
+    fn set_compression(&mut self, compression: Option<CompressionOptions>) {
+        self.compression = compression;
+    }
+
+    fn dump_name(&self) -> String {
+        self.root_key.to_string()
     }
 
     fn set_dump_name(&mut self, name: String) {
         self.root_key = name;
     }
 
-    fn compression_enabled(&self) -> bool {
-        self.enable_compression
+    fn compression(&self) -> Option<CompressionOptions> {
+        self.compression
     }
 
-    fn encryption_key(&self) -> &Option<String> {
+    fn encryption_key(&self) -> &Option<EncryptionKey> {
         &self.encryption_key
     }
 
@@ -295,25 +330,14 @@ impl Datastore for S3 {
 fn write_objects<B: Datastore>(
     datastore: &B,
     file_part: u16,
+    queries: &ChunkQueries,
     data: Bytes,
     bucket: &str,
     root_key: &str,
     client: &Client,
 ) -> Result<(), Error> {
-    // compress data?
-    let data = if datastore.compression_enabled() {
-        compress(data)?
-    } else {
-        data
-    };
-
-    // encrypt data?
-    let data = match datastore.encryption_key() {
-        Some(key) => encrypt(data, key.as_str())?,
-        None => data,
-    };
-
     let data_size = data.len();
+    let chunk = DumpChunk::new(file_part, queries, &data);
     let key = format!("{}/{}.dump", root_key, file_part);
 
     info!("upload object '{}' part {} on", key.as_str(), file_part);
@@ -327,8 +351,15 @@ fn write_objects<B: Datastore>(
         directory_name: root_key.to_string(),
         size: 0,
         created_at: epoch_millis(),
-        compressed: datastore.compression_enabled(),
+        compressed: datastore.compression().is_some(),
         encrypted: datastore.encryption_key().is_some(),
+        encryption_version: datastore
+            .encryption_key()
+            .as_ref()
+            .map(|_| ENCRYPTION_VERSION),
+        compression_codec: datastore.compression().map(|options| options.codec),
+        chunks: vec![],
+        status: DumpStatus::InProgress,
     };
 
     // find or create dump
@@ -338,6 +369,8 @@ fn write_objects<B: Datastore>(
         .find(|b| b.directory_name.as_str() == root_key)
         .unwrap_or(&mut new_dump);
 
+    dump.chunks.push(chunk);
+
     if dump.size == 0 {
         // it means it's a new dump.
         // We need to add it into the index_file.dumps
@@ -613,7 +646,7 @@ mod tests {
     use crate::datastore::s3::{
         create_bucket, create_object, delete_bucket, delete_object, get_object, S3Error,
     };
-    use crate::datastore::{Datastore, Dump, INDEX_FILE_NAME};
+    use crate::datastore::{Datastore, Dump, DumpStatus, INDEX_FILE_NAME};
     use crate::migration::rename_backups_to_dumps::RenameBackupsToDump;
     use crate::migration::update_version_number::UpdateVersionNumber;
     use crate::migration::Migrator;
@@ -852,6 +885,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         assert!(s3.write_index_file(&index_file).is_ok());
@@ -891,6 +928,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         index_file.dumps.push(Dump {
@@ -899,6 +940,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         assert!(s3.write_index_file(&index_file).is_ok());
@@ -963,6 +1008,10 @@ mod tests {
             created_at: (Utc::now() - Duration::days(5)).timestamp_millis() as u128,
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         // Add a dump from now
@@ -972,6 +1021,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         assert!(s3.write_index_file(&index_file).is_ok());
@@ -1037,6 +1090,10 @@ mod tests {
             created_at: (Utc::now() - Duration::days(3)).timestamp_millis() as u128,
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         index_file.dumps.push(Dump {
@@ -1045,6 +1102,10 @@ mod tests {
             created_at: (Utc::now() - Duration::days(5)).timestamp_millis() as u128,
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         index_file.dumps.push(Dump {
@@ -1053,6 +1114,10 @@ mod tests {
             created_at: epoch_millis(),
             compressed: true,
             encrypted: false,
+            encryption_version: None,
+            compression_codec: None,
+            chunks: vec![],
+            status: DumpStatus::Completed,
         });
 
         assert!(s3.write_index_file(&index_file).is_ok());
@@ -1170,7 +1235,11 @@ mod tests {
                 size: 62279,
                 created_at: 1234,
                 compressed: true,
-                encrypted: false
+                encrypted: false,
+                encryption_version: None,
+                compression_codec: None,
+                chunks: vec![],
+                status: DumpStatus::Completed,
             })
         );
         assert_eq!(
@@ -1180,7 +1249,11 @@ mod tests {
                 size: 62283,
                 created_at: 5678,
                 compressed: true,
-                encrypted: false
+                encrypted: false,
+                encryption_version: None,
+                compression_codec: None,
+                chunks: vec![],
+                status: DumpStatus::Completed,
             })
         );
     }
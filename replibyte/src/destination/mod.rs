@@ -10,8 +10,15 @@ pub mod mongodb_docker;
 pub mod mysql;
 pub mod mysql_docker;
 pub mod postgres;
+pub mod postgres_direct;
 pub mod postgres_docker;
+pub mod sqlite;
 
 pub trait Destination: Connector {
     fn write(&self, data: Bytes) -> Result<(), Error>;
+
+    /// called once all the data is written - e.g. to commit it
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
-use std::io::{Error, Write};
+use std::io::{BufReader, Error, Write};
 use std::process::{Command, Stdio};
 
+use dump_parser::mongodb::merge_archives;
+
 use crate::connector::Connector;
 use crate::destination::Destination;
 use crate::types::Bytes;
@@ -29,6 +31,12 @@ impl<'a> Connector for MongoDB<'a> {
 
 impl<'a> Destination for MongoDB<'a> {
     fn write(&self, data: Bytes) -> Result<(), Error> {
+        // the data can contain several archives - mongorestore expects a single one
+        let archive = merge_archives(BufReader::new(data.as_slice()))?;
+        if archive.is_empty() {
+            return Ok(());
+        }
+
         let mut process = Command::new("mongorestore")
             .args([
                 "--uri",
@@ -45,7 +53,7 @@ impl<'a> Destination for MongoDB<'a> {
             .stdin
             .take()
             .unwrap()
-            .write_all(&data[..data.len() - 1]); // remove trailing null terminator, or else mongorestore will fail
+            .write_all(&archive);
 
         wait_for_command(&mut process)
     }
@@ -5,7 +5,8 @@ use crate::destination::docker::{
 use crate::destination::Destination;
 use crate::types::Bytes;
 use crate::utils::binary_exists;
-use std::io::{Error, ErrorKind, Write};
+use dump_parser::mongodb::merge_archives;
+use std::io::{BufReader, Error, ErrorKind, Write};
 
 const DEFAULT_MONGO_IMAGE: &str = "mongo";
 pub const DEFAULT_MONGO_IMAGE_TAG: &str = "5";
@@ -56,6 +57,12 @@ impl Connector for MongoDBDocker {
 
 impl Destination for MongoDBDocker {
     fn write(&self, data: Bytes) -> Result<(), Error> {
+        // the data can contain several archives - mongorestore expects a single one
+        let archive = merge_archives(BufReader::new(data.as_slice()))?;
+        if archive.is_empty() {
+            return Ok(());
+        }
+
         let cmd = format!(
             "mongorestore --authenticationDatabase admin -u {} -p {} --archive",
             DEFAULT_MONGO_USER, DEFAULT_MONGO_PASSWORD,
@@ -68,7 +75,7 @@ impl Destination for MongoDBDocker {
                     .stdin
                     .take()
                     .unwrap()
-                    .write_all(&data[..data.len() - 1]); // remove trailing null terminator, or else mongorestore will fail
+                    .write_all(&archive);
 
                 let exit_status = container_exec.wait()?;
                 if !exit_status.success() {
@@ -99,7 +99,7 @@ impl<'a> Destination for Postgres<'a> {
     }
 }
 
-fn wipe_database_query(username: &str) -> String {
+pub(crate) fn wipe_database_query(username: &str) -> String {
     format!(
         "\
     DROP SCHEMA public CASCADE; \
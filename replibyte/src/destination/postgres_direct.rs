@@ -0,0 +1,668 @@
+use std::cell::RefCell;
+use std::io;
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Write};
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, SyncSender};
+use std::thread;
+use std::thread::JoinHandle;
+
+use postgres::{Client, Config, NoTls};
+
+use crate::connector::Connector;
+use crate::destination::postgres::wipe_database_query;
+use crate::destination::Destination;
+use crate::tasks::Message;
+use crate::types::Bytes;
+
+/// end of data marker of a `COPY ... FROM stdin;` block
+const END_OF_COPY_DATA: &[u8] = b"\\.";
+
+/// Destination PostgreSQL database restored over a single connection, without psql.
+/// The chunks are split into statements on another thread - a statement can be split across two chunks.
+pub struct PostgresDirect<'a> {
+    host: &'a str,
+    port: u16,
+    database: &'a str,
+    username: &'a str,
+    password: &'a str,
+    wipe_database: bool,
+    single_transaction: bool,
+    tx: Option<SyncSender<Message<Bytes>>>,
+    join_handle: RefCell<Option<JoinHandle<Result<(), Error>>>>,
+}
+
+impl<'a> PostgresDirect<'a> {
+    pub fn new(
+        host: &'a str,
+        port: u16,
+        database: &'a str,
+        username: &'a str,
+        password: &'a str,
+        wipe_database: bool,
+        single_transaction: bool,
+    ) -> Self {
+        PostgresDirect {
+            host,
+            port,
+            database,
+            username,
+            password,
+            wipe_database,
+            single_transaction,
+            tx: None,
+            join_handle: RefCell::new(None),
+        }
+    }
+
+    /// wait for the statements to be executed - the error of the first failing statement is returned
+    fn wait(&self) -> Result<(), Error> {
+        match self.join_handle.borrow_mut().take() {
+            Some(join_handle) => join_handle.join().unwrap(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Connector for PostgresDirect<'a> {
+    fn init(&mut self) -> Result<(), Error> {
+        let mut client = Config::new()
+            .host(self.host)
+            .port(self.port)
+            .user(self.username)
+            .password(self.password)
+            .dbname(self.database)
+            .connect(NoTls)
+            .map_err(to_error)?;
+
+        if self.single_transaction {
+            // the database is wiped in the transaction of the restore - it is left as it was if the restore fails
+            let _ = client.batch_execute("BEGIN;").map_err(to_error)?;
+        }
+
+        if self.wipe_database {
+            let _ = client
+                .batch_execute(wipe_database_query(self.username).as_str())
+                .map_err(to_error)?;
+        }
+
+        // bound to 1 to avoid eating too much memory if the dump is downloaded faster than it is restored
+        let (tx, rx) = mpsc::sync_channel::<Message<Bytes>>(1);
+        let single_transaction = self.single_transaction;
+
+        let join_handle = thread::spawn(move || {
+            let statements = StatementReader::new(BufReader::new(ChunkReader::new(rx)));
+            restore(&mut client, statements, single_transaction)
+        });
+
+        self.tx = Some(tx);
+        self.join_handle = RefCell::new(Some(join_handle));
+
+        Ok(())
+    }
+}
+
+impl<'a> Destination for PostgresDirect<'a> {
+    fn write(&self, data: Bytes) -> Result<(), Error> {
+        let tx = match &self.tx {
+            Some(tx) => tx,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    "the destination is not initialized",
+                ))
+            }
+        };
+
+        match tx.send(Message::Data(data)) {
+            Ok(_) => Ok(()),
+            // the restore stopped on an error
+            Err(_) => self
+                .wait()
+                .and(Err(Error::new(ErrorKind::Other, "the restore is stopped"))),
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        if let Some(tx) = self.tx.take() {
+            let _ = tx.send(Message::EOF);
+        }
+
+        self.wait()
+    }
+}
+
+/// execute the statements of the dump - with a single transaction, the transaction is started by `init`
+/// and nothing is committed on error
+fn restore<R: BufRead>(
+    client: &mut Client,
+    mut statements: StatementReader<R>,
+    single_transaction: bool,
+) -> Result<(), Error> {
+    while let Some(statement) = statements.next_statement()? {
+        if is_copy_from_stdin_statement(statement.query.as_str()) {
+            let mut writer = client
+                .copy_in(statement.query.as_str())
+                .map_err(|err| statement.error(err))?;
+
+            let _ = statements
+                .copy_rows(&mut writer)
+                .map_err(|err| statement.error(err))?;
+
+            let _ = writer.finish().map_err(|err| statement.error(err))?;
+        } else {
+            let _ = client
+                .batch_execute(statement.query.as_str())
+                .map_err(|err| statement.error(err))?;
+        }
+    }
+
+    if single_transaction {
+        let _ = client.batch_execute("COMMIT;").map_err(to_error)?;
+    }
+
+    Ok(())
+}
+
+fn is_copy_from_stdin_statement(statement: &str) -> bool {
+    statement.len() > 4
+        && statement[0..4].eq_ignore_ascii_case("COPY")
+        && statement
+            .to_ascii_uppercase()
+            .trim_end()
+            .ends_with("FROM STDIN;")
+}
+
+fn to_error(err: postgres::Error) -> Error {
+    Error::new(ErrorKind::Other, format!("{}", err))
+}
+
+/// statement of the dump and the line where it starts
+struct Statement {
+    line: usize,
+    query: String,
+}
+
+impl Statement {
+    fn error<E: ToString>(&self, err: E) -> Error {
+        Error::new(
+            ErrorKind::Other,
+            format!(
+                "statement at line {} failed: {}\n{}",
+                self.line,
+                err.to_string(),
+                self.query
+            ),
+        )
+    }
+}
+
+/// where the bytes read belong in the dump
+#[derive(Debug, PartialEq, Clone)]
+enum ScanState {
+    Code,
+    /// `'...'` - with backslash escapes for `E'...'`
+    String {
+        escapes: bool,
+    },
+    /// `"..."`
+    QuotedIdentifier,
+    /// `$tag$...$tag$`
+    DollarQuoted(Vec<u8>),
+    /// `-- ...` until the end of the line
+    LineComment,
+    /// `/* ... */` - they can be nested
+    BlockComment(usize),
+}
+
+/// reads the statements of a dump and the data rows of its `COPY ... FROM stdin;` statements
+struct StatementReader<R: BufRead> {
+    reader: R,
+    /// number of lines read
+    lines: usize,
+    /// line being read
+    line: Vec<u8>,
+    /// position of the next byte to read in the line
+    position: usize,
+}
+
+impl<R: BufRead> StatementReader<R> {
+    fn new(reader: R) -> Self {
+        StatementReader {
+            reader,
+            lines: 0,
+            line: vec![],
+            position: 0,
+        }
+    }
+
+    /// read the next line - false at the end of the dump
+    fn read_line(&mut self) -> Result<bool, Error> {
+        self.line.clear();
+        self.position = 0;
+
+        if self.reader.read_until(b'\n', &mut self.line)? == 0 {
+            return Ok(false);
+        }
+
+        self.lines += 1;
+        Ok(true)
+    }
+
+    /// next statement - without the comments and the blank lines before it.
+    /// None at the end of the dump.
+    fn next_statement(&mut self) -> Result<Option<Statement>, Error> {
+        let mut query = vec![];
+        let mut first_line = None;
+        let mut state = ScanState::Code;
+
+        loop {
+            if self.position == self.line.len() && !self.read_line()? {
+                return match first_line {
+                    None => Ok(None),
+                    Some(line) => Err(Error::new(
+                        ErrorKind::Other,
+                        format!("the statement at line {} is not complete", line),
+                    )),
+                };
+            }
+
+            // the psql meta-commands (e.g. `\restrict` of pg_dump) are left out
+            if first_line.is_none()
+                && state == ScanState::Code
+                && self.line[self.position..].starts_with(b"\\")
+            {
+                self.position = self.line.len();
+                continue;
+            }
+
+            while self.position < self.line.len() {
+                let idx = self.position;
+
+                if state == ScanState::Code {
+                    let bytes = &self.line[idx..];
+
+                    if first_line.is_none()
+                        && !bytes[0].is_ascii_whitespace()
+                        && !bytes.starts_with(b"--")
+                        && !bytes.starts_with(b"/*")
+                    {
+                        first_line = Some(self.lines);
+                    }
+
+                    if bytes[0] == b';' {
+                        query.push(b';');
+                        self.position += 1;
+
+                        let query = String::from_utf8(query)
+                            .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+
+                        return Ok(Some(Statement {
+                            line: first_line.unwrap_or(self.lines),
+                            query,
+                        }));
+                    }
+                }
+
+                self.position = scan(&self.line, idx, &mut state);
+
+                if first_line.is_some() {
+                    query.extend_from_slice(&self.line[idx..self.position]);
+                }
+            }
+        }
+    }
+
+    /// write the data rows following a `COPY ... FROM stdin;` statement, until its end of data marker
+    fn copy_rows<W: Write>(&mut self, writer: &mut W) -> Result<(), Error> {
+        loop {
+            if !self.read_line()? {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    "the dump ends before the end of the COPY data",
+                ));
+            }
+
+            self.position = self.line.len();
+
+            let row = self.line.strip_suffix(b"\n").unwrap_or(&self.line);
+            let row = row.strip_suffix(b"\r").unwrap_or(row);
+
+            if row == END_OF_COPY_DATA {
+                return Ok(());
+            }
+
+            writer.write_all(&self.line)?;
+        }
+    }
+}
+
+/// scan the byte of `line` at `idx` - and return the position of the next byte to scan
+fn scan(line: &[u8], idx: usize, state: &mut ScanState) -> usize {
+    let byte = line[idx];
+    let next_byte = line.get(idx + 1).copied();
+
+    let next_idx = match state {
+        ScanState::Code => match byte {
+            b'\'' => {
+                let escapes = idx > 0
+                    && line[idx - 1].eq_ignore_ascii_case(&b'e')
+                    && (idx < 2 || !is_identifier_byte(line[idx - 2]));
+                *state = ScanState::String { escapes };
+                idx + 1
+            }
+            b'"' => {
+                *state = ScanState::QuotedIdentifier;
+                idx + 1
+            }
+            b'-' if next_byte == Some(b'-') => {
+                *state = ScanState::LineComment;
+                idx + 2
+            }
+            b'/' if next_byte == Some(b'*') => {
+                *state = ScanState::BlockComment(1);
+                idx + 2
+            }
+            b'$' if idx == 0 || !is_identifier_byte(line[idx - 1]) => {
+                match dollar_quote_tag(&line[idx..]) {
+                    Some(tag) => {
+                        let next_idx = idx + tag.len();
+                        *state = ScanState::DollarQuoted(tag);
+                        next_idx
+                    }
+                    None => idx + 1,
+                }
+            }
+            _ => idx + 1,
+        },
+        ScanState::String { escapes } => match byte {
+            b'\\' if *escapes => idx + 2,
+            b'\'' if next_byte == Some(b'\'') => idx + 2,
+            b'\'' => {
+                *state = ScanState::Code;
+                idx + 1
+            }
+            _ => idx + 1,
+        },
+        ScanState::QuotedIdentifier => match byte {
+            b'"' if next_byte == Some(b'"') => idx + 2,
+            b'"' => {
+                *state = ScanState::Code;
+                idx + 1
+            }
+            _ => idx + 1,
+        },
+        ScanState::DollarQuoted(tag) => {
+            if line[idx..].starts_with(tag) {
+                let next_idx = idx + tag.len();
+                *state = ScanState::Code;
+                next_idx
+            } else {
+                idx + 1
+            }
+        }
+        ScanState::LineComment => {
+            if byte == b'\n' {
+                *state = ScanState::Code;
+            }
+            idx + 1
+        }
+        ScanState::BlockComment(depth) => {
+            if byte == b'/' && next_byte == Some(b'*') {
+                *depth += 1;
+                idx + 2
+            } else if byte == b'*' && next_byte == Some(b'/') {
+                *depth -= 1;
+                if *depth == 0 {
+                    *state = ScanState::Code;
+                }
+                idx + 2
+            } else {
+                idx + 1
+            }
+        }
+    };
+
+    next_idx.min(line.len())
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || byte >= 0x80
+}
+
+/// `$$` or `$tag$` at the start of `bytes`
+fn dollar_quote_tag(bytes: &[u8]) -> Option<Vec<u8>> {
+    let end = bytes[1..].iter().position(|byte| *byte == b'$')? + 1;
+    let tag = &bytes[1..end];
+
+    let is_valid = match tag.first() {
+        None => true,
+        Some(first) if first.is_ascii_digit() => false,
+        Some(_) => tag
+            .iter()
+            .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_' || *byte >= 0x80),
+    };
+
+    match is_valid {
+        true => Some(bytes[..end + 1].to_vec()),
+        false => None,
+    }
+}
+
+/// reads the chunks sent to the destination - an error is returned when the restore is interrupted
+struct ChunkReader {
+    rx: Receiver<Message<Bytes>>,
+    chunk: Bytes,
+    position: usize,
+}
+
+impl ChunkReader {
+    fn new(rx: Receiver<Message<Bytes>>) -> Self {
+        ChunkReader {
+            rx,
+            chunk: vec![],
+            position: 0,
+        }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.chunk.len() {
+            match self.rx.recv() {
+                Ok(Message::Data(chunk)) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                }
+                Ok(Message::EOF) => return Ok(0),
+                Err(_) => return Err(Error::new(ErrorKind::Other, "the restore was interrupted")),
+            }
+        }
+
+        let size = buf.len().min(self.chunk.len() - self.position);
+        buf[..size].copy_from_slice(&self.chunk[self.position..self.position + size]);
+        self.position += size;
+
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+    use std::sync::mpsc;
+
+    use crate::connector::Connector;
+    use crate::destination::postgres_direct::{
+        is_copy_from_stdin_statement, ChunkReader, PostgresDirect, StatementReader,
+    };
+    use crate::destination::Destination;
+    use crate::tasks::Message;
+
+    fn get_postgres() -> PostgresDirect<'static> {
+        PostgresDirect::new("localhost", 5453, "root", "root", "password", true, true)
+    }
+
+    fn get_invalid_postgres() -> PostgresDirect<'static> {
+        PostgresDirect::new(
+            "localhost",
+            5453,
+            "root",
+            "root",
+            "wrongpassword",
+            true,
+            true,
+        )
+    }
+
+    fn statements(dump: &str) -> Vec<(usize, String)> {
+        let mut reader = StatementReader::new(dump.as_bytes());
+        let mut statements = vec![];
+
+        while let Some(statement) = reader.next_statement().unwrap() {
+            statements.push((statement.line, statement.query));
+        }
+
+        statements
+    }
+
+    #[test]
+    fn connect() {
+        let mut p = get_postgres();
+        let _ = p.init().expect("can't init postgres");
+        assert!(p.write(b"SELECT 1;".to_vec()).is_ok());
+        assert!(p.finish().is_ok());
+
+        let mut p = get_invalid_postgres();
+        assert!(p.init().is_err());
+        assert!(p.write(b"SELECT 1;".to_vec()).is_err());
+    }
+
+    #[test]
+    fn restore_statements() {
+        let mut p = get_postgres();
+        let _ = p.init().expect("can't init postgres");
+
+        // statements split across two chunks
+        assert!(p
+            .write(
+                b"CREATE TABLE direct_restore (id integer, name text);\nINSERT INTO direct_re"
+                    .to_vec()
+            )
+            .is_ok());
+        assert!(p
+            .write(b"store VALUES (1, 'a;b');\nCOPY direct_restore (id, name) FROM stdin;\n2\tc\n\\.\n".to_vec())
+            .is_ok());
+        assert!(p.finish().is_ok());
+
+        let mut p = get_postgres();
+        let _ = p.init().expect("can't init postgres");
+        let _ = p.write(b"SELECT 1;\nSELECT * FROM unknown_table;\n".to_vec());
+
+        let err = p.finish().unwrap_err().to_string();
+        assert!(err.starts_with("statement at line 2 failed"));
+        assert!(err.ends_with("SELECT * FROM unknown_table;"));
+    }
+
+    #[test]
+    fn split_statements() {
+        let dump = "--\n-- PostgreSQL database dump\n--\n\nSET statement_timeout = 0;\nSET lock_timeout = 0; SELECT 1;\n\nCREATE TABLE public.orders (\n    id integer,\n    note text DEFAULT 'a;b'''\n);\n";
+
+        assert_eq!(
+            statements(dump),
+            vec![
+                (5, "SET statement_timeout = 0;".to_string()),
+                (6, "SET lock_timeout = 0;".to_string()),
+                (6, "SELECT 1;".to_string()),
+                (
+                    8,
+                    "CREATE TABLE public.orders (\n    id integer,\n    note text DEFAULT 'a;b'''\n);"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_quoted_statements() {
+        let dump = "INSERT INTO \"a;b\" VALUES (E'\\';', 'x');\nCREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql;\nSELECT $$;$$, a$b, '$x$';\n";
+
+        assert_eq!(
+            statements(dump),
+            vec![
+                (1, "INSERT INTO \"a;b\" VALUES (E'\\';', 'x');".to_string()),
+                (
+                    2,
+                    "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql;"
+                        .to_string()
+                ),
+                (3, "SELECT $$;$$, a$b, '$x$';".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_commented_statements() {
+        let dump = "/* a; /* nested; */ comment; */\nSELECT 1 -- one;\n;\n-- only a comment;\n\\restrict abc\nSELECT 2;\n\\unrestrict abc\n";
+
+        assert_eq!(
+            statements(dump),
+            vec![
+                (2, "SELECT 1 -- one;\n;".to_string()),
+                (6, "SELECT 2;".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn incomplete_statement() {
+        let mut reader = StatementReader::new("SELECT 1;\nSELECT 'a;\n".as_bytes());
+
+        assert!(reader.next_statement().unwrap().is_some());
+        assert!(reader.next_statement().is_err());
+    }
+
+    #[test]
+    fn copy_rows() {
+        let dump = "COPY public.orders (id, note) FROM stdin;\n1\ta;b\n2\t\\N\n\\.\nSELECT 1;\n";
+        let mut reader = StatementReader::new(dump.as_bytes());
+
+        let statement = reader.next_statement().unwrap().unwrap();
+        assert!(is_copy_from_stdin_statement(statement.query.as_str()));
+
+        let mut rows = vec![];
+        let _ = reader.copy_rows(&mut rows).unwrap();
+        assert_eq!(rows, b"1\ta;b\n2\t\\N\n".to_vec());
+
+        let statement = reader.next_statement().unwrap().unwrap();
+        assert_eq!(statement.line, 5);
+        assert!(!is_copy_from_stdin_statement(statement.query.as_str()));
+
+        let mut reader = StatementReader::new(dump.lines().next().unwrap().as_bytes());
+        let _ = reader.next_statement().unwrap().unwrap();
+        assert!(reader.copy_rows(&mut vec![]).is_err());
+    }
+
+    #[test]
+    fn read_chunks() {
+        let (tx, rx) = mpsc::sync_channel(2);
+        let _ = tx.send(Message::Data(b"SELECT".to_vec()));
+        let _ = tx.send(Message::Data(b" 1;".to_vec()));
+
+        let reader = std::thread::spawn(move || {
+            let mut query = String::new();
+            let _ = BufReader::new(ChunkReader::new(rx)).read_to_string(&mut query);
+            query
+        });
+
+        let _ = tx.send(Message::EOF);
+        assert_eq!(reader.join().unwrap(), "SELECT 1;");
+
+        // the restore is interrupted when the chunks stop before the end of the dump
+        let (tx, rx) = mpsc::sync_channel(1);
+        let _ = tx.send(Message::Data(b"SELECT".to_vec()));
+        drop(tx);
+
+        let mut query = String::new();
+        assert!(ChunkReader::new(rx).read_to_string(&mut query).is_err());
+    }
+}
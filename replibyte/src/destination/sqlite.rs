@@ -0,0 +1,234 @@
+use std::cell::RefCell;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::str;
+
+use rusqlite::Connection;
+
+use dump_parser::sqlite::list_complete_statements;
+
+use crate::connector::Connector;
+use crate::destination::Destination;
+use crate::types::Bytes;
+
+/// Destination SQLite database file - the statements are executed as soon as they are complete,
+/// the ones split across two chunks are executed once the second chunk is written.
+pub struct Sqlite<'a> {
+    path: &'a str,
+    wipe_database: bool,
+    connection: Option<Connection>,
+    /// start of the statement not complete yet
+    pending: RefCell<Vec<u8>>,
+}
+
+impl<'a> Sqlite<'a> {
+    pub fn new(path: &'a str, wipe_database: bool) -> Self {
+        Sqlite {
+            path,
+            wipe_database,
+            connection: None,
+            pending: RefCell::new(vec![]),
+        }
+    }
+
+    fn connection(&self) -> Result<&Connection, Error> {
+        match &self.connection {
+            Some(connection) => Ok(connection),
+            None => Err(Error::new(
+                ErrorKind::Other,
+                "the destination is not initialized",
+            )),
+        }
+    }
+}
+
+impl<'a> Connector for Sqlite<'a> {
+    fn init(&mut self) -> Result<(), Error> {
+        if self.wipe_database {
+            // the dump creates the tables - the database is restored into a new file,
+            // without the journal of the previous one
+            for suffix in ["", "-journal", "-wal", "-shm"] {
+                let path = format!("{}{}", self.path, suffix);
+
+                if Path::new(path.as_str()).exists() {
+                    let _ = fs::remove_file(path)?;
+                }
+            }
+        }
+
+        self.connection = Some(Connection::open(self.path).map_err(to_error)?);
+
+        Ok(())
+    }
+}
+
+impl<'a> Destination for Sqlite<'a> {
+    fn write(&self, data: Bytes) -> Result<(), Error> {
+        let connection = self.connection()?;
+        let mut pending = self.pending.borrow_mut();
+        pending.extend_from_slice(data.as_slice());
+
+        // a chunk can end in the middle of a character
+        let text = match str::from_utf8(pending.as_slice()) {
+            Ok(text) => text,
+            Err(err) if err.error_len().is_none() => {
+                str::from_utf8(&pending[..err.valid_up_to()]).unwrap()
+            }
+            Err(err) => return Err(Error::new(ErrorKind::InvalidData, err)),
+        };
+
+        let (statements, len) = list_complete_statements(text);
+
+        for statement in statements {
+            let _ = execute(connection, statement)?;
+        }
+
+        let _ = pending.drain(..len);
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        let connection = self.connection()?;
+        let pending = self.pending.borrow();
+
+        let rest = match str::from_utf8(pending.as_slice()) {
+            Ok(rest) => rest.trim(),
+            Err(err) => return Err(Error::new(ErrorKind::InvalidData, err)),
+        };
+
+        if rest.is_empty() {
+            return Ok(());
+        }
+
+        // comments are executed as empty statements - an incomplete statement fails
+        execute(connection, rest)
+    }
+}
+
+fn execute(connection: &Connection, statement: &str) -> Result<(), Error> {
+    connection.execute_batch(statement).map_err(|err| {
+        Error::new(
+            ErrorKind::Other,
+            format!("statement failed: {}\n{}", err, statement),
+        )
+    })
+}
+
+fn to_error(err: rusqlite::Error) -> Error {
+    Error::new(ErrorKind::Other, format!("{}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+    use tempfile::NamedTempFile;
+
+    use crate::connector::Connector;
+    use crate::destination::sqlite::Sqlite;
+    use crate::destination::Destination;
+
+    const DUMP: &str = "PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE customers (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, picture BLOB);
+INSERT INTO \"customers\" (\"id\",\"name\",\"picture\") VALUES(1,'Lucas; ''Jr''',X'01ff');
+INSERT INTO \"customers\" (\"id\",\"name\",\"picture\") VALUES(2,'Paul
+Smith',NULL);
+DELETE FROM sqlite_sequence;
+INSERT INTO \"sqlite_sequence\" (\"name\",\"seq\") VALUES('customers',2);
+CREATE TRIGGER customers_name AFTER UPDATE ON customers BEGIN
+  UPDATE customers SET name = trim(name) WHERE id = new.id;
+END;
+COMMIT;
+-- end of the dump
+";
+
+    fn get_names(path: &str) -> Vec<String> {
+        let connection = Connection::open(path).unwrap();
+        let mut statement = connection
+            .prepare("SELECT name FROM customers ORDER BY id")
+            .unwrap();
+
+        let names = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        names
+    }
+
+    #[test]
+    fn connect() {
+        let file = NamedTempFile::new().unwrap();
+        let mut sqlite = Sqlite::new(file.path().to_str().unwrap(), true);
+        assert!(sqlite.init().is_ok());
+
+        let mut sqlite = Sqlite::new("/tmp/replibyte-not-a-directory/restore.db", false);
+        assert!(sqlite.init().is_err());
+    }
+
+    #[test]
+    fn restore_statements_split_across_chunks() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        // an existing database is replaced
+        let connection = Connection::open(path).unwrap();
+        connection
+            .execute_batch("CREATE TABLE customers (id INTEGER);")
+            .unwrap();
+        drop(connection);
+
+        let mut sqlite = Sqlite::new(path, true);
+        let _ = sqlite.init().unwrap();
+
+        // chunks of 7 bytes end in the middle of the statements
+        for chunk in DUMP.as_bytes().chunks(7) {
+            let _ = sqlite.write(chunk.to_vec()).unwrap();
+        }
+
+        let _ = sqlite.finish().unwrap();
+
+        assert_eq!(
+            get_names(path),
+            vec!["Lucas; 'Jr'".to_string(), "Paul\nSmith".to_string()]
+        );
+    }
+
+    #[test]
+    fn restore_failing_statement() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let mut sqlite = Sqlite::new(path, true);
+        let _ = sqlite.init().unwrap();
+
+        let err = sqlite
+            .write(b"INSERT INTO missing_table VALUES(1);\n".to_vec())
+            .unwrap_err();
+
+        assert!(err.to_string().starts_with("statement failed: "));
+        assert!(err
+            .to_string()
+            .ends_with("INSERT INTO missing_table VALUES(1);"));
+    }
+
+    #[test]
+    fn restore_incomplete_statement() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let mut sqlite = Sqlite::new(path, true);
+        let _ = sqlite.init().unwrap();
+
+        let _ = sqlite
+            .write(
+                b"CREATE TABLE customers (id INTEGER);\nINSERT INTO customers VALUES('1".to_vec(),
+            )
+            .unwrap();
+
+        assert!(sqlite.finish().is_err());
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{Error, ErrorKind};
+
+use crate::config::LeakDetectionConfig;
+use crate::scanner::{for_each_sampled_row, Dialect};
+use crate::types::{OriginalQuery, Query};
+
+const DEFAULT_MIN_LENGTH: usize = 4;
+/// 16MB bloom filter - less than 1% of false positives up to ~13 millions of dumped values
+const BLOOM_FILTER_BITS: usize = 1 << 27;
+const BLOOM_FILTER_HASHES: u64 = 4;
+
+/// bloom filter of 64 bits hashes - a hash is derived into `BLOOM_FILTER_HASHES` bit indexes by double hashing
+struct BloomFilter {
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+    fn new() -> Self {
+        BloomFilter {
+            bits: vec![0; BLOOM_FILTER_BITS / 64],
+        }
+    }
+
+    fn bit_indexes(hash: u64) -> impl Iterator<Item = usize> {
+        let h1 = hash & 0xffff_ffff;
+        let h2 = (hash >> 32) | 1;
+
+        (0..BLOOM_FILTER_HASHES)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) as usize) % BLOOM_FILTER_BITS)
+    }
+
+    fn insert(&mut self, hash: u64) {
+        for index in BloomFilter::bit_indexes(hash) {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    fn contains(&self, hash: u64) -> bool {
+        BloomFilter::bit_indexes(hash).all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leak {
+    /// "<database>.<table>.<column>" the original values come from
+    pub transformed_column: String,
+    /// "<database>.<table>.<column>" holding the original values - unknown when they were dumped before their original was read
+    pub leaking_column: Option<String>,
+    pub values: usize,
+}
+
+/// LeakDetector compares the original and the transformed queries of a dump,
+/// keeps the hashes of the original values changed by a transformer,
+/// and looks for those original values surviving anywhere in the transformed queries,
+/// e.g. copied into another column or a denormalized table.
+pub struct LeakDetector {
+    dialect: Dialect,
+    /// MySQL queries do not hold the database name
+    database_name: String,
+    fail: bool,
+    min_length: usize,
+    /// interned "<database>.<table>.<column>" - the ids are the indexes in `columns`
+    columns: Vec<String>,
+    column_ids: HashMap<String, u32>,
+    /// hashes of the original values changed by a transformer, with the id of the column they come from
+    original_column_ids_by_value_hash: HashMap<u64, u32>,
+    /// hashes of the dumped values which did not match any original value when dumped
+    dumped_values: BloomFilter,
+    /// number of leaked values by (transformed column id, leaking column id)
+    leaks: HashMap<(u32, u32), usize>,
+}
+
+impl LeakDetector {
+    pub fn new(dialect: Dialect, database_name: &str, config: &LeakDetectionConfig) -> Self {
+        LeakDetector {
+            dialect,
+            database_name: database_name.to_string(),
+            fail: config.fail.unwrap_or(false),
+            min_length: config.min_length.unwrap_or(DEFAULT_MIN_LENGTH),
+            columns: vec![],
+            column_ids: HashMap::new(),
+            original_column_ids_by_value_hash: HashMap::new(),
+            dumped_values: BloomFilter::new(),
+            leaks: HashMap::new(),
+        }
+    }
+
+    /// inspect a query read from the source and its transformed version
+    pub fn inspect(&mut self, original_query: &OriginalQuery, query: &Query) {
+        let original_rows = self.rows(original_query);
+        let rows = self.rows(query);
+
+        // the rows are compared in order - unless some of them have been filtered out.
+        // Values are compared by column name since excluded columns are missing from the transformed rows
+        if original_rows.len() == rows.len() {
+            for (original_row, row) in original_rows.iter().zip(rows.iter()) {
+                for (column, original_value) in original_row {
+                    let original_value = match original_value {
+                        Some(original_value) => original_value,
+                        None => continue,
+                    };
+
+                    let value = match row.iter().find(|(name, _)| name == column) {
+                        Some((_, value)) => value,
+                        None => continue,
+                    };
+
+                    if Some(original_value) != value.as_ref() {
+                        let column_id = self.column_id(column);
+                        let _ = self
+                            .original_column_ids_by_value_hash
+                            .entry(hash(original_value))
+                            .or_insert(column_id);
+                    }
+                }
+            }
+        }
+
+        for (column, value) in rows.into_iter().flatten() {
+            let hash = match value {
+                Some(value) => hash(&value),
+                None => continue,
+            };
+
+            match self.original_column_ids_by_value_hash.get(&hash) {
+                Some(&transformed_column_id) => {
+                    let column_id = self.column_id(&column);
+                    *self
+                        .leaks
+                        .entry((transformed_column_id, column_id))
+                        .or_insert(0) += 1;
+                }
+                None => self.dumped_values.insert(hash),
+            }
+        }
+    }
+
+    /// id of an interned "<database>.<table>.<column>"
+    fn column_id(&mut self, column: &str) -> u32 {
+        if let Some(column_id) = self.column_ids.get(column) {
+            return *column_id;
+        }
+
+        let column_id = self.columns.len() as u32;
+        self.columns.push(column.to_string());
+        let _ = self.column_ids.insert(column.to_string(), column_id);
+        column_id
+    }
+
+    /// "<database>.<table>.<column>" and value of the values long enough to be looked for, by row in the query order
+    fn rows(&self, query: &Query) -> Vec<Vec<(String, Option<String>)>> {
+        let mut rows = vec![];
+
+        for_each_sampled_row(
+            self.dialect,
+            self.database_name.as_str(),
+            query.data(),
+            |database_name, table_name, row| {
+                rows.push(
+                    row.into_iter()
+                        .map(|(column_name, value)| {
+                            (
+                                format!("{}.{}.{}", database_name, table_name, column_name),
+                                value.filter(|value| value.chars().count() >= self.min_length),
+                            )
+                        })
+                        .collect(),
+                );
+            },
+        );
+
+        rows
+    }
+
+    /// leaked values by transformed column - the leaking column of a value dumped before its original is unknown,
+    /// and a few of those leaks can be false positives
+    pub fn leaks(&self) -> Vec<Leak> {
+        let mut located_leaks: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+        for ((transformed_column_id, leaking_column_id), values) in &self.leaks {
+            let _ = located_leaks.insert(
+                (
+                    self.columns[*transformed_column_id as usize].as_str(),
+                    self.columns[*leaking_column_id as usize].as_str(),
+                ),
+                *values,
+            );
+        }
+
+        let mut leaks = located_leaks
+            .into_iter()
+            .map(|((transformed_column, leaking_column), values)| Leak {
+                transformed_column: transformed_column.to_string(),
+                leaking_column: Some(leaking_column.to_string()),
+                values,
+            })
+            .collect::<Vec<_>>();
+
+        let mut unlocated_leaks: BTreeMap<&str, usize> = BTreeMap::new();
+        for (hash, transformed_column_id) in &self.original_column_ids_by_value_hash {
+            if self.dumped_values.contains(*hash) {
+                *unlocated_leaks
+                    .entry(self.columns[*transformed_column_id as usize].as_str())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        for (transformed_column, values) in unlocated_leaks {
+            leaks.push(Leak {
+                transformed_column: transformed_column.to_string(),
+                leaking_column: None,
+                values,
+            });
+        }
+
+        leaks
+    }
+
+    /// fail with the leaks report when `fail` is enabled and leaks are detected
+    pub fn check(&self) -> Result<(), Error> {
+        let leaks = self.leaks();
+
+        if self.fail && !leaks.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "leak detection: original values of transformed columns found in the dump\n{}",
+                    LeakReport(&leaks)
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// human readable list of leaks - one line by transformed and leaking columns
+pub struct LeakReport<'a>(pub &'a Vec<Leak>);
+
+impl<'a> fmt::Display for LeakReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for leak in self.0 {
+            match &leak.leaking_column {
+                Some(leaking_column) => writeln!(
+                    f,
+                    " - {} -> {} ({} value(s))",
+                    leak.transformed_column, leaking_column, leak.values
+                )?,
+                None => writeln!(
+                    f,
+                    " - {} -> dumped before the original values were read ({} value(s), approximate)",
+                    leak.transformed_column, leak.values
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn hash(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::LeakDetectionConfig;
+    use crate::leak_detector::{Leak, LeakDetector};
+    use crate::scanner::Dialect;
+    use crate::types::Query;
+
+    fn inspect(detector: &mut LeakDetector, original_query: &str, query: &str) {
+        detector.inspect(
+            &Query(original_query.as_bytes().to_vec()),
+            &Query(query.as_bytes().to_vec()),
+        )
+    }
+
+    fn config(fail: bool) -> LeakDetectionConfig {
+        LeakDetectionConfig {
+            fail: Some(fail),
+            min_length: None,
+        }
+    }
+
+    #[test]
+    fn detect_leaks() {
+        let mut detector = LeakDetector::new(Dialect::Postgres, "", &config(true));
+
+        // the denormalized table is dumped before the transformed one
+        inspect(
+            &mut detector,
+            "INSERT INTO public.orders (id, customer_email) VALUES (1, 'jane@doe.com');",
+            "INSERT INTO public.orders (id, customer_email) VALUES (1, 'jane@doe.com');",
+        );
+        inspect(
+            &mut detector,
+            "INSERT INTO public.customers (id, email, country) VALUES (1, 'jane@doe.com', 'France');",
+            "INSERT INTO public.customers (id, email, country) VALUES (1, 'xkcd@doe.com', 'France');",
+        );
+        inspect(
+            &mut detector,
+            "INSERT INTO public.customers (id, email, country) VALUES (2, 'john@doe.com', 'Italy');",
+            "INSERT INTO public.customers (id, email, country) VALUES (2, 'abcd@doe.com', 'Italy');",
+        );
+        inspect(
+            &mut detector,
+            "INSERT INTO public.invoices (id, email) VALUES (1, 'john@doe.com');",
+            "INSERT INTO public.invoices (id, email) VALUES (1, 'john@doe.com');",
+        );
+
+        assert_eq!(
+            detector.leaks(),
+            vec![
+                Leak {
+                    transformed_column: "public.customers.email".to_string(),
+                    leaking_column: Some("public.invoices.email".to_string()),
+                    values: 1,
+                },
+                Leak {
+                    transformed_column: "public.customers.email".to_string(),
+                    leaking_column: None,
+                    values: 1,
+                },
+            ]
+        );
+        assert!(detector.check().is_err());
+
+        // leaks are only reported by default
+        let mut detector = LeakDetector::new(Dialect::Postgres, "", &config(false));
+        inspect(
+            &mut detector,
+            "INSERT INTO public.customers (id, email) VALUES (1, 'jane@doe.com');",
+            "INSERT INTO public.customers (id, email) VALUES (1, 'jane@doe.com');",
+        );
+        assert!(detector.leaks().is_empty());
+        assert!(detector.check().is_ok());
+    }
+
+    #[test]
+    fn detect_leaks_with_excluded_columns() {
+        let mut detector = LeakDetector::new(Dialect::Postgres, "", &config(true));
+
+        // the password column is excluded - the other values are still compared by column name
+        inspect(
+            &mut detector,
+            "\nCOPY public.customers (id, password, email) FROM stdin;\n1\tsecret\tjane@doe.com\n2\thunter2\tjohn@doe.com\n\\.\n",
+            "\nCOPY public.customers (id, email) FROM stdin;\n1\txkcd@doe.com\n2\tjohn@doe.com\n\\.\n",
+        );
+        inspect(
+            &mut detector,
+            "INSERT INTO public.invoices (id, email) VALUES (1, 'jane@doe.com');",
+            "INSERT INTO public.invoices (id, email) VALUES (1, 'jane@doe.com');",
+        );
+
+        assert_eq!(
+            detector.leaks(),
+            vec![Leak {
+                transformed_column: "public.customers.email".to_string(),
+                leaking_column: Some("public.invoices.email".to_string()),
+                values: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn detect_leaks_in_mysql_queries() {
+        let mut detector = LeakDetector::new(Dialect::Mysql, "world", &config(true));
+
+        inspect(
+            &mut detector,
+            "INSERT INTO `customers` (`id`, `name`) VALUES (1,'Jane Doe');",
+            "INSERT INTO `customers` (`id`, `name`) VALUES (1,'Mary Roe');",
+        );
+        inspect(
+            &mut detector,
+            "INSERT INTO `orders` (`id`, `customer_name`) VALUES (1,'Jane Doe');",
+            "INSERT INTO `orders` (`id`, `customer_name`) VALUES (1,'Jane Doe');",
+        );
+
+        assert_eq!(
+            detector.leaks(),
+            vec![Leak {
+                transformed_column: "world.customers.name".to_string(),
+                leaking_column: Some("world.orders.customer_name".to_string()),
+                values: 1,
+            }]
+        );
+    }
+}
@@ -13,8 +13,9 @@ use indicatif::{ProgressBar, ProgressStyle};
 use migration::{migrations, Migrator};
 use utils::get_replibyte_version;
 
-use crate::cli::{DumpCommand, RestoreCommand, SubCommand, TransformerCommand, CLI};
-use crate::config::{Config, DatabaseSubsetConfig, DatastoreConfig};
+use crate::cli::{DumpCommand, RestoreCommand, SourceCommand, SubCommand, TransformerCommand, CLI};
+use crate::config::{Config, DatastoreConfig};
+use crate::datastore::azure::AzureBlobStorage;
 use crate::datastore::local_disk::LocalDisk;
 use crate::datastore::s3::S3;
 use crate::datastore::Datastore;
@@ -29,8 +30,10 @@ mod config;
 mod connector;
 mod datastore;
 mod destination;
+mod leak_detector;
 mod migration;
 mod runtime;
+mod scanner;
 mod source;
 mod tasks;
 mod telemetry;
@@ -130,6 +133,12 @@ fn run(config: Config, sub_commands: &SubCommand) -> anyhow::Result<()> {
             config.endpoint()?,
         )?),
         DatastoreConfig::LocalDisk(config) => Box::new(LocalDisk::new(config.dir()?)),
+        DatastoreConfig::Azure(config) => Box::new(AzureBlobStorage::new(
+            config.account()?,
+            config.container()?,
+            config.credentials()?,
+            config.endpoint()?,
+        )?),
     };
 
     let migrator = Migrator::new(get_replibyte_version(), &datastore, migrations());
@@ -150,6 +159,10 @@ fn run(config: Config, sub_commands: &SubCommand) -> anyhow::Result<()> {
                 let _ = thread::spawn(move || show_progress_bar(rx_pb));
             }
         },
+        // the configuration is printed on stdout
+        SubCommand::Source(SourceCommand::Scan(_)) => {}
+        // the rows are printed on stdout
+        SubCommand::Transformer(TransformerCommand::Preview(_)) => {}
         _ => {
             let _ = thread::spawn(move || show_progress_bar(rx_pb));
         }
@@ -173,6 +186,7 @@ fn run(config: Config, sub_commands: &SubCommand) -> anyhow::Result<()> {
                 commands::dump::run(args, datastore, config, progress_callback)
             }
             DumpCommand::Delete(args) => commands::dump::delete(datastore, args),
+            DumpCommand::Verify(args) => commands::dump::verify(datastore, args, config),
             DumpCommand::Restore(restore_cmd) => match restore_cmd {
                 RestoreCommand::Local(args) => {
                     commands::dump::restore_local(args, datastore, config, progress_callback)
@@ -187,6 +201,10 @@ fn run(config: Config, sub_commands: &SubCommand) -> anyhow::Result<()> {
                 let _ = commands::transformer::list();
                 Ok(())
             }
+            TransformerCommand::Preview(args) => commands::transformer::preview(args, config),
+        },
+        SubCommand::Source(cmd) => match cmd {
+            SourceCommand::Scan(args) => commands::source::scan(args, config),
         },
     }
 }
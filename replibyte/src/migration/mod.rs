@@ -6,10 +6,12 @@ use log::info;
 use crate::datastore::Datastore;
 use crate::migration::rename_backups_to_dumps::RenameBackupsToDump;
 use crate::migration::update_version_number::UpdateVersionNumber;
+use crate::migration::version_dump_encryption::VersionDumpEncryption;
 use crate::utils::get_replibyte_version;
 
 pub mod rename_backups_to_dumps;
 pub mod update_version_number;
+pub mod version_dump_encryption;
 
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct Version {
@@ -57,6 +59,7 @@ pub fn migrations() -> Vec<Box<dyn Migration>> {
     vec![
         Box::new(UpdateVersionNumber::new(get_replibyte_version())),
         Box::new(RenameBackupsToDump::default()),
+        Box::new(VersionDumpEncryption::default()),
     ]
 }
 
@@ -120,7 +123,9 @@ mod tests {
     use serde_json::json;
 
     use crate::connector::Connector;
-    use crate::datastore::{Datastore, IndexFile, ReadOptions};
+    use crate::datastore::{
+        ChunkQueries, CompressionOptions, Datastore, Dump, EncryptionKey, IndexFile, ReadOptions,
+    };
 
     use super::{Migration, Migrator, Version};
 
@@ -167,7 +172,12 @@ mod tests {
             unimplemented!()
         }
 
-        fn write(&self, _file_part: u16, _data: crate::types::Bytes) -> Result<(), Error> {
+        fn write_chunk(
+            &self,
+            _file_part: u16,
+            _queries: &ChunkQueries,
+            _data: crate::types::Bytes,
+        ) -> Result<(), Error> {
             unimplemented!()
         }
 
@@ -179,15 +189,23 @@ mod tests {
             unimplemented!()
         }
 
-        fn compression_enabled(&self) -> bool {
-            true
+        fn read_chunks(
+            &self,
+            _dump: &Dump,
+            _chunk_callback: &mut dyn FnMut(u16, crate::types::Bytes),
+        ) -> Result<(), Error> {
+            unimplemented!()
         }
 
-        fn set_compression(&mut self, _enable: bool) {
+        fn compression(&self) -> Option<CompressionOptions> {
+            Some(CompressionOptions::default())
+        }
+
+        fn set_compression(&mut self, _compression: Option<CompressionOptions>) {
             unimplemented!()
         }
 
-        fn encryption_key(&self) -> &Option<String> {
+        fn encryption_key(&self) -> &Option<EncryptionKey> {
             unimplemented!()
         }
 
@@ -195,6 +213,10 @@ mod tests {
             unimplemented!()
         }
 
+        fn dump_name(&self) -> String {
+            unimplemented!()
+        }
+
         fn set_dump_name(&mut self, _name: String) {
             unimplemented!()
         }
@@ -0,0 +1,130 @@
+use std::{
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use log::info;
+use serde_json::{json, Value};
+
+use crate::datastore::{Datastore, LEGACY_ENCRYPTION_VERSION};
+
+use super::{Migration, Version};
+
+pub struct VersionDumpEncryption {}
+
+impl VersionDumpEncryption {
+    pub fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Migration for VersionDumpEncryption {
+    fn minimal_version(&self) -> Version {
+        Version::from_str("0.9.6").unwrap()
+    }
+
+    fn run(&self, datastore: &Box<dyn Datastore>) -> Result<(), Error> {
+        info!("migrate: version dump encryption");
+
+        let mut raw_index_file = datastore.raw_index_file()?;
+        let _ = set_legacy_encryption_version(&mut raw_index_file)?;
+        datastore.write_raw_index_file(&raw_index_file)
+    }
+}
+
+fn set_legacy_encryption_version(metadata_json: &mut Value) -> Result<(), Error> {
+    let metadata = match metadata_json.as_object_mut() {
+        Some(metadata) => metadata,
+        None => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "migrate: metadata.json is not an object",
+            ))
+        }
+    };
+
+    let dumps = match metadata.get_mut("dumps") {
+        Some(dumps) => dumps,
+        // nothing to migrate
+        None => return Ok(()),
+    };
+
+    match dumps.as_array_mut() {
+        Some(dumps) => {
+            for dump in dumps.iter_mut().filter_map(|dump| dump.as_object_mut()) {
+                let encrypted = dump
+                    .get("encrypted")
+                    .and_then(|encrypted| encrypted.as_bool())
+                    .unwrap_or(false);
+
+                let is_versioned = dump
+                    .get("encryption_version")
+                    .map(|version| !version.is_null())
+                    .unwrap_or(false);
+
+                // encrypted dumps without version have been written with the legacy encryption
+                if encrypted && !is_versioned {
+                    dump.insert(
+                        "encryption_version".to_string(),
+                        json!(LEGACY_ENCRYPTION_VERSION),
+                    );
+                }
+            }
+            Ok(())
+        }
+        None => Err(Error::new(
+            ErrorKind::Other,
+            "migrate: metadata.json dumps is not an array",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::migration::version_dump_encryption::set_legacy_encryption_version;
+
+    #[test]
+    fn test_set_legacy_encryption_version() {
+        let mut metadata_json = json!([]);
+        assert!(set_legacy_encryption_version(&mut metadata_json).is_err());
+
+        let mut metadata_json = json!({"dumps": []});
+        assert!(set_legacy_encryption_version(&mut metadata_json).is_ok());
+
+        let mut metadata_json = json!({
+            "v": "0.9.6",
+            "dumps": [
+                {
+                    "directory_name":"dump-1653170039392",
+                    "size":62279,
+                    "created_at":1234,
+                    "compressed":true,
+                    "encrypted":true
+                },
+                {
+                    "directory_name":"dump-1653170570014",
+                    "size":62283,
+                    "created_at":5678,
+                    "compressed":true,
+                    "encrypted":false
+                },
+                {
+                    "directory_name":"dump-1653170570015",
+                    "size":62283,
+                    "created_at":5679,
+                    "compressed":true,
+                    "encrypted":true,
+                    "encryption_version":2
+                }
+            ]
+        });
+        assert!(set_legacy_encryption_version(&mut metadata_json).is_ok());
+
+        let dumps = metadata_json.get("dumps").unwrap().as_array().unwrap();
+        assert_eq!(dumps[0].get("encryption_version"), Some(&json!(1)));
+        assert_eq!(dumps[1].get("encryption_version"), None);
+        assert_eq!(dumps[2].get("encryption_version"), Some(&json!(2)));
+    }
+}
@@ -0,0 +1,744 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use chrono::NaiveDate;
+
+use crate::config::TransformerTypeConfig;
+use crate::source::filter::to_text;
+use crate::source::{mysql, postgres};
+use crate::types::{Column, ColumnType, OriginalQuery};
+
+/// confidence given by a column name hint alone - a column is never reported from its name only
+const NAME_HINT_WEIGHT: f64 = 0.4;
+/// confidence given by the ratio of sampled values looking like the PII kind
+const VALUE_SHAPE_WEIGHT: f64 = 0.6;
+/// MySQL dumps do not hold the database name - and MySQL transformers are matched on the table and column names only
+pub const UNKNOWN_MYSQL_DATABASE: &str = "<database>";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    Postgres,
+    Mysql,
+}
+
+/// kinds of personal data (PII) that can be detected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PiiKind {
+    Email,
+    PhoneNumber,
+    FirstName,
+    LastName,
+    FullName,
+    CreditCard,
+    IpAddress,
+    Address,
+}
+
+const PII_KINDS: [PiiKind; 8] = [
+    PiiKind::Email,
+    PiiKind::PhoneNumber,
+    PiiKind::FirstName,
+    PiiKind::LastName,
+    PiiKind::FullName,
+    PiiKind::CreditCard,
+    PiiKind::IpAddress,
+    PiiKind::Address,
+];
+
+const STREET_SUFFIXES: [&str; 18] = [
+    "street",
+    "st",
+    "avenue",
+    "ave",
+    "road",
+    "rd",
+    "boulevard",
+    "blvd",
+    "lane",
+    "ln",
+    "drive",
+    "dr",
+    "way",
+    "court",
+    "ct",
+    "place",
+    "pl",
+    "square",
+];
+
+impl PiiKind {
+    pub fn name(&self) -> &str {
+        match self {
+            PiiKind::Email => "email",
+            PiiKind::PhoneNumber => "phone number",
+            PiiKind::FirstName => "first name",
+            PiiKind::LastName => "last name",
+            PiiKind::FullName => "full name",
+            PiiKind::CreditCard => "credit card",
+            PiiKind::IpAddress => "ip address",
+            PiiKind::Address => "address",
+        }
+    }
+
+    /// the transformer to use on the columns of this kind
+    pub fn transformer(&self) -> TransformerTypeConfig {
+        match self {
+            PiiKind::Email => TransformerTypeConfig::Email,
+            PiiKind::PhoneNumber => TransformerTypeConfig::PhoneNumber,
+            PiiKind::FirstName => TransformerTypeConfig::FirstName,
+            PiiKind::LastName => TransformerTypeConfig::KeepFirstChar,
+            PiiKind::FullName => TransformerTypeConfig::Random,
+            PiiKind::CreditCard => TransformerTypeConfig::CreditCard,
+            PiiKind::IpAddress => TransformerTypeConfig::Random,
+            PiiKind::Address => TransformerTypeConfig::Random,
+        }
+    }
+
+    fn transformer_name(&self) -> &str {
+        match self.transformer() {
+            TransformerTypeConfig::Email => "email",
+            TransformerTypeConfig::PhoneNumber => "phone-number",
+            TransformerTypeConfig::FirstName => "first-name",
+            TransformerTypeConfig::KeepFirstChar => "keep-first-char",
+            TransformerTypeConfig::CreditCard => "credit-card",
+            _ => "random",
+        }
+    }
+
+    /// column names (snake_case words) usually holding this kind of data
+    fn column_name_hints(&self) -> &[&str] {
+        match self {
+            PiiKind::Email => &["email", "mail", "e_mail"],
+            PiiKind::PhoneNumber => &[
+                "phone",
+                "phonenumber",
+                "mobile",
+                "cellphone",
+                "tel",
+                "telephone",
+                "fax",
+            ],
+            PiiKind::FirstName => &["first_name", "firstname", "given_name", "fname", "forename"],
+            PiiKind::LastName => &["last_name", "lastname", "surname", "family_name", "lname"],
+            PiiKind::FullName => &[
+                "full_name",
+                "fullname",
+                "display_name",
+                "contact_name",
+                "customer_name",
+            ],
+            PiiKind::CreditCard => &[
+                "credit_card",
+                "creditcard",
+                "card_number",
+                "cc_number",
+                "card_no",
+                "pan",
+            ],
+            PiiKind::IpAddress => &["ip", "ip_address", "ipaddress", "ip_addr", "remote_addr"],
+            PiiKind::Address => &["address", "street", "address_line", "street_address"],
+        }
+    }
+
+    /// names can not be told apart from other words by their values, so their column name must match
+    fn requires_column_name_hint(&self) -> bool {
+        matches!(
+            self,
+            PiiKind::FirstName | PiiKind::LastName | PiiKind::FullName
+        )
+    }
+
+    fn matches_column_name(&self, column_name: &str) -> bool {
+        // "customerEmail" and "customer_email" are both split into ["customer", "email"]
+        let words =
+            column_name
+                .chars()
+                .fold(String::with_capacity(column_name.len()), |mut name, ch| {
+                    if ch.is_uppercase() && !name.is_empty() && !name.ends_with('_') {
+                        name.push('_');
+                    }
+
+                    match ch {
+                        ch if ch.is_alphabetic() => name.extend(ch.to_lowercase()),
+                        _ if !name.is_empty() && !name.ends_with('_') => name.push('_'),
+                        _ => {}
+                    }
+
+                    name
+                });
+
+        let words = words.trim_matches('_').split('_').collect::<Vec<_>>();
+
+        self.column_name_hints().iter().any(|hint| {
+            let hint = hint.split('_').collect::<Vec<_>>();
+            words
+                .windows(hint.len())
+                .any(|window| window == hint.as_slice())
+        })
+    }
+
+    fn matches_value(&self, value: &str) -> bool {
+        match self {
+            PiiKind::Email => is_email(value),
+            PiiKind::PhoneNumber => is_phone_number(value),
+            PiiKind::FirstName | PiiKind::LastName | PiiKind::FullName => is_name(value),
+            PiiKind::CreditCard => is_credit_card_number(value),
+            PiiKind::IpAddress => value.parse::<IpAddr>().is_ok(),
+            PiiKind::Address => is_address(value),
+        }
+    }
+}
+
+/// a column detected as holding personal data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub database_name: String,
+    pub table_name: String,
+    pub column_name: String,
+    pub kind: PiiKind,
+    /// between 0 and 1
+    pub confidence: f64,
+}
+
+struct ColumnSamples {
+    database_name: String,
+    table_name: String,
+    column_name: String,
+    values: Vec<String>,
+}
+
+/// Scanner samples the values of each column from the queries of a dump,
+/// and classifies the columns likely holding personal data by their name and the shape of their values.
+pub struct Scanner {
+    dialect: Dialect,
+    /// MySQL queries do not hold the database name
+    database_name: String,
+    sample_size: usize,
+    columns: Vec<ColumnSamples>,
+    column_index_by_db_and_table_and_column_name: HashMap<String, usize>,
+}
+
+impl Scanner {
+    pub fn new(dialect: Dialect, database_name: &str, sample_size: usize) -> Self {
+        Scanner {
+            dialect,
+            database_name: database_name.to_string(),
+            sample_size,
+            columns: vec![],
+            column_index_by_db_and_table_and_column_name: HashMap::new(),
+        }
+    }
+
+    /// sample the values of a query read from the source
+    pub fn scan(&mut self, query: &OriginalQuery) {
+        let dialect = self.dialect;
+        let database_name = self.database_name.clone();
+
+        for_each_value(
+            dialect,
+            database_name.as_str(),
+            query.data(),
+            |database_name, table_name, column_name, value| {
+                self.sample(database_name, table_name, column_name, value)
+            },
+        );
+    }
+
+    fn sample(
+        &mut self,
+        database_name: &str,
+        table_name: &str,
+        column_name: &str,
+        value: Option<String>,
+    ) {
+        let key = format!("{}.{}.{}", database_name, table_name, column_name);
+
+        let index = match self.column_index_by_db_and_table_and_column_name.get(&key) {
+            Some(index) => *index,
+            None => {
+                self.columns.push(ColumnSamples {
+                    database_name: database_name.to_string(),
+                    table_name: table_name.to_string(),
+                    column_name: column_name.to_string(),
+                    values: vec![],
+                });
+
+                let index = self.columns.len() - 1;
+                let _ = self
+                    .column_index_by_db_and_table_and_column_name
+                    .insert(key, index);
+
+                index
+            }
+        };
+
+        let column = &mut self.columns[index];
+
+        match value {
+            Some(value) if !value.trim().is_empty() && column.values.len() < self.sample_size => {
+                column.values.push(value)
+            }
+            _ => {}
+        }
+    }
+
+    /// columns likely holding personal data with a confidence of at least `min_confidence`, in the dump order
+    pub fn detections(&self, min_confidence: f64) -> Vec<Detection> {
+        self.columns
+            .iter()
+            .filter_map(|column| {
+                PII_KINDS
+                    .iter()
+                    .map(|kind| (*kind, confidence(*kind, column)))
+                    .filter(|(_, confidence)| *confidence >= min_confidence && *confidence > 0.0)
+                    // the first kind wins on equal confidence
+                    .fold(
+                        None,
+                        |best: Option<(PiiKind, f64)>, (kind, confidence)| match best {
+                            Some((_, best_confidence)) if best_confidence >= confidence => best,
+                            _ => Some((kind, confidence)),
+                        },
+                    )
+                    .map(|(kind, confidence)| Detection {
+                        database_name: column.database_name.clone(),
+                        table_name: column.table_name.clone(),
+                        column_name: column.column_name.clone(),
+                        kind,
+                        confidence,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// call `value_callback` with the database, table and column names and the text of each value of a query, in the query order
+pub fn for_each_value<F: FnMut(&str, &str, &str, Option<String>)>(
+    dialect: Dialect,
+    database_name: &str,
+    query: &[u8],
+    mut value_callback: F,
+) {
+    for_each_query_row(
+        dialect,
+        database_name,
+        query,
+        sample_value,
+        |database_name, table_name, row| {
+            for (column_name, value) in row {
+                value_callback(database_name, table_name, column_name.as_str(), value);
+            }
+        },
+    );
+}
+
+/// call `row_callback` with the database and table names and the column names and values of each row of a query, in the query order
+pub fn for_each_row<F: FnMut(&str, &str, Vec<(String, Option<String>)>)>(
+    dialect: Dialect,
+    database_name: &str,
+    query: &[u8],
+    row_callback: F,
+) {
+    for_each_query_row(dialect, database_name, query, to_text, row_callback);
+}
+
+/// same as `for_each_row` - with the text of the string, char and number values only
+pub fn for_each_sampled_row<F: FnMut(&str, &str, Vec<(String, Option<String>)>)>(
+    dialect: Dialect,
+    database_name: &str,
+    query: &[u8],
+    row_callback: F,
+) {
+    for_each_query_row(dialect, database_name, query, sample_value, row_callback);
+}
+
+fn for_each_query_row<F: FnMut(&str, &str, Vec<(String, Option<String>)>)>(
+    dialect: Dialect,
+    database_name: &str,
+    query: &[u8],
+    value_text: fn(&Column) -> Option<String>,
+    mut row_callback: F,
+) {
+    let query = String::from_utf8_lossy(query);
+
+    match dialect {
+        Dialect::Postgres => for_each_postgres_row(query.as_ref(), value_text, &mut row_callback),
+        Dialect::Mysql => {
+            for_each_mysql_row(database_name, query.as_ref(), value_text, &mut row_callback)
+        }
+    }
+}
+
+fn for_each_postgres_row<F: FnMut(&str, &str, Vec<(String, Option<String>)>)>(
+    query: &str,
+    value_text: fn(&Column) -> Option<String>,
+    row_callback: &mut F,
+) {
+    let query = query.trim_start_matches('\n');
+
+    // a `COPY ... FROM stdin;` block is read as a whole: the statement, the data rows and the end of data marker
+    let (statement, rows) = match query.split_once('\n') {
+        Some((statement, rows)) if statement.starts_with("COPY ") => (statement, rows),
+        _ => (query, ""),
+    };
+
+    let tokens = dump_parser::postgres::get_tokens_from_query_str(statement);
+
+    match postgres::get_row_type(&tokens) {
+        postgres::RowType::CopyFrom {
+            database_name,
+            table_name,
+        } => {
+            let column_names = dump_parser::postgres::get_column_names_from_copy_query(&tokens);
+
+            for row in rows.lines() {
+                if row == postgres::END_OF_COPY_DATA {
+                    break;
+                }
+
+                let values = match dump_parser::postgres::get_column_values_from_copy_row(row) {
+                    Ok(values) => values,
+                    // the rows which are not valid UTF-8 are not sampled
+                    Err(_) => continue,
+                };
+
+                row_callback(
+                    database_name.as_str(),
+                    table_name.as_str(),
+                    column_names.iter().cloned().zip(values).collect(),
+                );
+            }
+        }
+        postgres::RowType::InsertInto {
+            database_name,
+            table_name,
+        } => {
+            let column_names =
+                dump_parser::postgres::get_column_names_from_insert_into_query(&tokens);
+            let column_values =
+                dump_parser::postgres::get_column_values_from_insert_into_query(&tokens);
+
+            row_callback(
+                database_name.as_str(),
+                table_name.as_str(),
+                column_names
+                    .iter()
+                    .zip(column_values)
+                    .map(|(column_name, value_token)| {
+                        let column =
+                            postgres::to_column(column_name, ColumnType::Unknown, value_token);
+                        (column_name.clone(), value_text(&column))
+                    })
+                    .collect(),
+            );
+        }
+        _ => {}
+    }
+}
+
+fn for_each_mysql_row<F: FnMut(&str, &str, Vec<(String, Option<String>)>)>(
+    database_name: &str,
+    query: &str,
+    value_text: fn(&Column) -> Option<String>,
+    row_callback: &mut F,
+) {
+    let tokens = dump_parser::mysql::get_tokens_from_query_str(query);
+
+    if let mysql::RowType::InsertInto { table_name } = mysql::get_row_type(&tokens) {
+        let column_names = dump_parser::mysql::get_column_names_from_insert_into_query(&tokens);
+        let column_values = dump_parser::mysql::get_column_values_from_insert_into_query(&tokens);
+
+        row_callback(
+            database_name,
+            table_name.as_str(),
+            column_names
+                .iter()
+                .zip(column_values)
+                .map(|(column_name, value_token)| {
+                    let column = mysql::to_column(column_name, ColumnType::Unknown, value_token);
+                    (column_name.to_string(), value_text(&column))
+                })
+                .collect(),
+        );
+    }
+}
+
+/// the text of a sampled value - dates, JSON documents, binary and boolean values are not classified
+fn sample_value(column: &Column) -> Option<String> {
+    match column {
+        Column::StringValue(_, value) => Some(value.clone()),
+        Column::CharValue(_, value) => Some(value.to_string()),
+        Column::NumberValue(_, value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+fn confidence(kind: PiiKind, column: &ColumnSamples) -> f64 {
+    let name_score = match kind.matches_column_name(column.column_name.as_str()) {
+        true => NAME_HINT_WEIGHT,
+        false if kind.requires_column_name_hint() => return 0.0,
+        false => 0.0,
+    };
+
+    if column.values.is_empty() {
+        return name_score;
+    }
+
+    let matching_values = column
+        .values
+        .iter()
+        .filter(|value| kind.matches_value(value.trim()))
+        .count();
+
+    name_score + VALUE_SHAPE_WEIGHT * matching_values as f64 / column.values.len() as f64
+}
+
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local_part, domain)) => {
+            !local_part.is_empty()
+                && !value.contains(char::is_whitespace)
+                && !domain.contains('@')
+                && domain.contains('.')
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
+fn is_phone_number(value: &str) -> bool {
+    // a date (2022-05-01) is not a phone number
+    if let Some(date) = value.get(..10) {
+        if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok() {
+            return false;
+        }
+    }
+
+    let digits = value.chars().filter(|ch| ch.is_ascii_digit()).count();
+
+    // a formatted phone number - plain numbers are ids or amounts more often than phone numbers
+    value
+        .chars()
+        .all(|ch| ch.is_ascii_digit() || " +-()".contains(ch))
+        && (value.starts_with('+') || value.contains(|ch: char| " -(".contains(ch)))
+        && (7..=15).contains(&digits)
+}
+
+fn is_name(value: &str) -> bool {
+    value.len() <= 64
+        && value.starts_with(char::is_uppercase)
+        && value
+            .chars()
+            .all(|ch| ch.is_alphabetic() || " '-.".contains(ch))
+}
+
+fn is_credit_card_number(value: &str) -> bool {
+    let digits = value
+        .chars()
+        .filter(|ch| *ch != ' ' && *ch != '-')
+        .collect::<String>();
+
+    (13..=19).contains(&digits.len())
+        && digits.chars().all(|ch| ch.is_ascii_digit())
+        && is_luhn_valid(digits.as_str())
+}
+
+/// check the Luhn checksum of a card number
+fn is_luhn_valid(digits: &str) -> bool {
+    let sum = digits
+        .chars()
+        .rev()
+        .filter_map(|ch| ch.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit,
+            _ if digit * 2 > 9 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum::<u32>();
+
+    sum % 10 == 0
+}
+
+fn is_address(value: &str) -> bool {
+    let words = value
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|ch| ch == ',' || ch == '.')
+                .to_lowercase()
+        })
+        .collect::<Vec<_>>();
+
+    // "1600 Pennsylvania Avenue" - a street number followed by a street name
+    words.len() >= 3
+        && words[0].starts_with(|ch: char| ch.is_ascii_digit())
+        && words[1..]
+            .iter()
+            .any(|word| STREET_SUFFIXES.contains(&word.as_str()))
+}
+
+/// YAML `transformers` configuration for the detected columns, with their confidence score as comment
+pub fn to_transformers_yaml(detections: &Vec<Detection>) -> String {
+    let mut yaml = String::from("transformers:\n");
+    let mut current_table: Option<(&str, &str)> = None;
+
+    for detection in detections {
+        let table = (
+            detection.database_name.as_str(),
+            detection.table_name.as_str(),
+        );
+
+        if current_table != Some(table) {
+            yaml.push_str(&format!(
+                "  - database: {}\n    table: {}\n    columns:\n",
+                to_yaml_string(table.0),
+                to_yaml_string(table.1),
+            ));
+
+            current_table = Some(table);
+        }
+
+        yaml.push_str(&format!(
+            "      - name: {} # {} (confidence: {:.2})\n        transformer_name: {}\n",
+            to_yaml_string(detection.column_name.as_str()),
+            detection.kind.name(),
+            detection.confidence,
+            detection.kind.transformer_name(),
+        ));
+    }
+
+    yaml
+}
+
+fn to_yaml_string(value: &str) -> String {
+    if !value.is_empty()
+        && value.starts_with(|ch: char| ch.is_alphanumeric() || ch == '_')
+        && value
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-' || ch == '.')
+    {
+        return value.to_string();
+    }
+
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::config::{TransformerConfig, TransformerTypeConfig};
+    use crate::scanner::{
+        is_address, is_credit_card_number, is_email, is_phone_number, to_transformers_yaml,
+        Dialect, PiiKind, Scanner,
+    };
+    use crate::types::Query;
+
+    #[derive(Deserialize)]
+    struct TransformersConfig {
+        transformers: Vec<TransformerConfig>,
+    }
+
+    #[test]
+    fn detect_value_shapes() {
+        assert!(is_email("john.doe@example.com"));
+        assert!(!is_email("john.doe@localhost"));
+        assert!(!is_email("john doe@example.com"));
+
+        assert!(is_phone_number("+33 6 12 34 56 78"));
+        assert!(is_phone_number("(555) 123-4567"));
+        assert!(!is_phone_number("2022-05-01"));
+        assert!(!is_phone_number("1234567890"));
+
+        assert!(is_credit_card_number("4242 4242 4242 4242"));
+        assert!(is_credit_card_number("5555-5555-5555-4444"));
+        assert!(!is_credit_card_number("4242 4242 4242 4241"));
+
+        assert!(is_address("1600 Pennsylvania Avenue NW"));
+        assert!(is_address("10, Downing St."));
+        assert!(!is_address("Downing Street"));
+
+        assert!(PiiKind::Email.matches_column_name("customerEmail"));
+        assert!(PiiKind::IpAddress.matches_column_name("last_sign_in_ip"));
+        assert!(!PiiKind::IpAddress.matches_column_name("zip"));
+        assert!(!PiiKind::FullName.matches_column_name("product_name"));
+    }
+
+    #[test]
+    fn scan_postgres_queries() {
+        let mut scanner = Scanner::new(Dialect::Postgres, "", 100);
+
+        for query in [
+            "INSERT INTO public.customers (id, first_name, contact, last_ip) VALUES (1, 'Lucas', 'lucas@example.com', '10.0.0.1');",
+            "INSERT INTO public.customers (id, first_name, contact, last_ip) VALUES (2, 'Georges', 'georges@example.com', '10.0.0.2');",
+            "COPY public.orders (id, card, phone, status) FROM stdin;\n1\t4242 4242 4242 4242\t+33 6 12 34 56 78\tPaid\n2\t\\N\t+33 6 12 34 56 79\tShipped\n\\.",
+        ] {
+            scanner.scan(&Query(query.as_bytes().to_vec()));
+        }
+
+        let detections = scanner
+            .detections(0.5)
+            .into_iter()
+            .map(|detection| {
+                (
+                    format!(
+                        "{}.{}.{}",
+                        detection.database_name, detection.table_name, detection.column_name
+                    ),
+                    detection.kind,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            detections,
+            vec![
+                (
+                    "public.customers.first_name".to_string(),
+                    PiiKind::FirstName
+                ),
+                ("public.customers.contact".to_string(), PiiKind::Email),
+                ("public.customers.last_ip".to_string(), PiiKind::IpAddress),
+                ("public.orders.card".to_string(), PiiKind::CreditCard),
+                ("public.orders.phone".to_string(), PiiKind::PhoneNumber),
+            ]
+        );
+
+        let yaml = to_transformers_yaml(&scanner.detections(0.5));
+        let config: TransformersConfig = serde_yaml::from_str(yaml.as_str()).unwrap();
+
+        assert_eq!(config.transformers.len(), 2);
+        assert_eq!(config.transformers[0].database, "public");
+        assert_eq!(config.transformers[0].table, "customers");
+        assert_eq!(config.transformers[0].columns.len(), 3);
+        assert_eq!(config.transformers[0].columns[1].name, "contact");
+        assert_eq!(
+            config.transformers[0].columns[1].transformer,
+            TransformerTypeConfig::Email
+        );
+        assert_eq!(
+            config.transformers[1].columns[0].transformer,
+            TransformerTypeConfig::CreditCard
+        );
+    }
+
+    #[test]
+    fn scan_mysql_queries() {
+        let mut scanner = Scanner::new(Dialect::Mysql, "shop", 100);
+
+        for query in [
+            "INSERT INTO `users` (`id`, `name`, `mail`) VALUES (1,'Widget','it\\'s@example.com');",
+            "INSERT INTO `users` (`id`, `name`, `mail`) VALUES (2,'Gadget',NULL);",
+        ] {
+            scanner.scan(&Query(query.as_bytes().to_vec()));
+        }
+
+        let detections = scanner.detections(0.5);
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].database_name, "shop");
+        assert_eq!(detections[0].table_name, "users");
+        assert_eq!(detections[0].column_name, "mail");
+        assert_eq!(detections[0].kind, PiiKind::Email);
+        assert_eq!(detections[0].confidence, 1.0);
+    }
+}
@@ -0,0 +1,639 @@
+use std::collections::HashSet;
+
+use crate::config::{ExcludeColumnsConfig, ExcludedValueConfig};
+use crate::types::Column;
+
+/// first words of the table constraints and indexes of a `CREATE TABLE` statement
+const TABLE_CONSTRAINT_WORDS: [&str; 10] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "UNIQUE",
+    "FOREIGN",
+    "CHECK",
+    "EXCLUDE",
+    "KEY",
+    "INDEX",
+    "FULLTEXT",
+    "SPATIAL",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exclusion {
+    /// the values are written as NULL
+    Null,
+    /// the column is left out of the data - the column gets its declared DEFAULT on restore
+    Remove,
+}
+
+/// ExcludedColumns writes the excluded columns of the `INSERT INTO ...` and `COPY ...` queries as NULL or leaves them out,
+/// and removes the columns dropped from the schema from the `CREATE TABLE ...` queries.
+pub struct ExcludedColumns<'a> {
+    configs: &'a Vec<ExcludeColumnsConfig>,
+}
+
+impl<'a> ExcludedColumns<'a> {
+    pub fn new(configs: &'a Vec<ExcludeColumnsConfig>) -> Self {
+        ExcludedColumns { configs }
+    }
+
+    /// MySQL dumps do not hold the database name - the columns are matched on the table name only when `database_name` is None
+    fn table_configs<'b>(
+        &'b self,
+        database_name: Option<&'b str>,
+        table_name: &'b str,
+    ) -> impl Iterator<Item = &'a ExcludeColumnsConfig> + 'b {
+        self.configs.iter().filter(move |config| {
+            config.table == unquote(table_name)
+                && match database_name {
+                    Some(database_name) => config.database == unquote(database_name),
+                    None => true,
+                }
+        })
+    }
+
+    pub fn has_exclusions(&self, database_name: Option<&str>, table_name: &str) -> bool {
+        self.table_configs(database_name, table_name)
+            .next()
+            .is_some()
+    }
+
+    pub fn exclusion(
+        &self,
+        database_name: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> Option<Exclusion> {
+        let column_name = unquote(column_name);
+
+        self.table_configs(database_name, table_name)
+            .find(|config| config.columns.iter().any(|column| column == column_name))
+            .map(|config| {
+                match (
+                    config.drop_from_schema.unwrap_or(false),
+                    &config.replace_with,
+                ) {
+                    (true, _) | (false, Some(ExcludedValueConfig::Default)) => Exclusion::Remove,
+                    _ => Exclusion::Null,
+                }
+            })
+    }
+
+    /// columns of an `INSERT INTO ...` query without their excluded values
+    pub fn exclude_columns(
+        &self,
+        database_name: Option<&str>,
+        table_name: &str,
+        columns: Vec<Column>,
+    ) -> Vec<Column> {
+        if !self.has_exclusions(database_name, table_name) {
+            return columns;
+        }
+
+        columns
+            .into_iter()
+            .filter_map(
+                |column| match self.exclusion(database_name, table_name, column.name()) {
+                    None => Some(column),
+                    Some(Exclusion::Null) => Some(Column::None(column.name().to_string())),
+                    Some(Exclusion::Remove) => None,
+                },
+            )
+            .collect()
+    }
+
+    fn dropped_columns(&self, database_name: Option<&str>, table_name: &str) -> HashSet<&'a str> {
+        self.table_configs(database_name, table_name)
+            .filter(|config| config.drop_from_schema.unwrap_or(false))
+            .flat_map(|config| config.columns.iter().map(|column| column.as_str()))
+            .collect()
+    }
+
+    fn is_dropped_column(
+        &self,
+        database_name: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> bool {
+        self.dropped_columns(database_name, table_name)
+            .contains(unquote(column_name))
+    }
+
+    /// `CREATE TABLE ...` query without the columns dropped from the schema - and without the indexes and constraints on them
+    pub fn exclude_from_create_table(
+        &self,
+        database_name: Option<&str>,
+        table_name: &str,
+        query: &str,
+    ) -> String {
+        let dropped_columns = self.dropped_columns(database_name, table_name);
+
+        if dropped_columns.is_empty() {
+            return query.to_string();
+        }
+
+        strip_create_table_definitions(query, &dropped_columns)
+    }
+
+    /// true when a PostgreSQL statement following the `CREATE TABLE ...` one still references a column dropped from the schema:
+    /// `ALTER TABLE ... ADD CONSTRAINT ...` (also a foreign key to the column), `ALTER TABLE ... ALTER COLUMN ... SET DEFAULT ...`,
+    /// `CREATE INDEX ...`, `ALTER SEQUENCE ... OWNED BY ...` and `COMMENT ON COLUMN ...` - the statement has to be dropped too
+    pub fn references_dropped_column(&self, query: &str) -> bool {
+        if !self
+            .configs
+            .iter()
+            .any(|config| config.drop_from_schema.unwrap_or(false))
+        {
+            return false;
+        }
+
+        let words = statement_words(query);
+        let keyword_at = |pos: usize, keyword: &str| {
+            words
+                .get(pos)
+                .map(|word| word.eq_ignore_ascii_case(keyword))
+                .unwrap_or(false)
+        };
+        let position_of = |keyword: &str| {
+            words
+                .iter()
+                .position(|word| word.eq_ignore_ascii_case(keyword))
+        };
+
+        if keyword_at(0, "ALTER") && keyword_at(1, "TABLE") {
+            let pos = if keyword_at(2, "ONLY") { 3 } else { 2 };
+            let (database_name, table_name, pos) = match qualified_name(&words, pos) {
+                Some(name) => name,
+                None => return false,
+            };
+
+            // ALTER TABLE ONLY <database>.<table> ALTER COLUMN <column> SET DEFAULT ...;
+            if keyword_at(pos, "ALTER") && keyword_at(pos + 1, "COLUMN") {
+                return match words.get(pos + 2) {
+                    Some(column_name) => {
+                        self.is_dropped_column(database_name, table_name, column_name)
+                    }
+                    None => false,
+                };
+            }
+
+            // ALTER TABLE ONLY <database>.<table> ADD CONSTRAINT <name> FOREIGN KEY (<columns>) REFERENCES <database>.<table>(<columns>);
+            if keyword_at(pos, "ADD") {
+                let references = position_of("REFERENCES").unwrap_or(words.len());
+
+                return words[pos..references].iter().any(|column_name| {
+                    self.is_dropped_column(database_name, table_name, column_name)
+                }) || match qualified_name(&words, references + 1) {
+                    Some((database_name, table_name, pos)) => {
+                        words[pos..].iter().any(|column_name| {
+                            self.is_dropped_column(database_name, table_name, column_name)
+                        })
+                    }
+                    None => false,
+                };
+            }
+
+            return false;
+        }
+
+        // CREATE [UNIQUE] INDEX <name> ON [ONLY] <database>.<table> USING btree (<columns>);
+        if keyword_at(0, "CREATE") && (keyword_at(1, "INDEX") || keyword_at(2, "INDEX")) {
+            return match position_of("ON") {
+                Some(pos) => {
+                    let pos = if keyword_at(pos + 1, "ONLY") {
+                        pos + 2
+                    } else {
+                        pos + 1
+                    };
+
+                    match qualified_name(&words, pos) {
+                        Some((database_name, table_name, pos)) => {
+                            words[pos..].iter().any(|column_name| {
+                                self.is_dropped_column(database_name, table_name, column_name)
+                            })
+                        }
+                        None => false,
+                    }
+                }
+                None => false,
+            };
+        }
+
+        // ALTER SEQUENCE <database>.<sequence> OWNED BY <database>.<table>.<column>;
+        // COMMENT ON COLUMN <database>.<table>.<column> IS '...';
+        let column_pos = if keyword_at(0, "ALTER") && keyword_at(1, "SEQUENCE") {
+            position_of("OWNED")
+                .filter(|pos| keyword_at(pos + 1, "BY"))
+                .map(|pos| pos + 2)
+        } else if keyword_at(0, "COMMENT") && keyword_at(1, "ON") && keyword_at(2, "COLUMN") {
+            Some(3)
+        } else {
+            None
+        };
+
+        match column_pos.and_then(|pos| qualified_column_name(&words, pos)) {
+            Some((database_name, table_name, column_name)) => {
+                self.is_dropped_column(database_name, table_name, column_name)
+            }
+            None => false,
+        }
+    }
+}
+
+/// `COPY ... (<columns>) FROM stdin;` statement without the removed columns - `exclusions` are the ones of the COPY columns
+pub fn exclude_from_copy_statement(
+    statement: &str,
+    column_names: &[String],
+    exclusions: &[Option<Exclusion>],
+) -> String {
+    let (start, end) = match (statement.find('('), statement.rfind(')')) {
+        (Some(start), Some(end)) if start < end => (start, end),
+        _ => return statement.to_string(),
+    };
+
+    let column_names = column_names
+        .iter()
+        .zip(exclusions)
+        .filter(|(_, exclusion)| **exclusion != Some(Exclusion::Remove))
+        .map(|(column_name, _)| column_name.as_str())
+        .collect::<Vec<_>>();
+
+    format!(
+        "{}{}{}",
+        &statement[..=start],
+        column_names.join(", "),
+        &statement[end..]
+    )
+}
+
+/// COPY data row without the excluded values - `exclusions` are the ones of the COPY columns
+pub fn exclude_from_copy_row(row: &str, exclusions: &[Option<Exclusion>]) -> String {
+    row.split('\t')
+        .zip(exclusions)
+        .filter_map(|(value, exclusion)| match exclusion {
+            None => Some(value),
+            Some(Exclusion::Null) => Some("\\N"),
+            Some(Exclusion::Remove) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+fn unquote(name: &str) -> &str {
+    name.trim_matches(|c| c == '"' || c == '`')
+}
+
+/// identifiers and punctuation of a statement - the string literals are left out and the quoted identifiers are unquoted
+fn statement_words(query: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\'' => {
+                // a quote doubled in the literal starts a new literal - it is skipped the same way
+                for (_, ch) in chars.by_ref() {
+                    if ch == '\'' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let mut end = query.len();
+                for (end_idx, ch) in chars.by_ref() {
+                    if ch == '"' {
+                        end = end_idx;
+                        break;
+                    }
+                }
+                words.push(&query[idx + 1..end]);
+            }
+            ch if ch.is_alphanumeric() || ch == '_' || ch == '$' => {
+                let mut end = idx + ch.len_utf8();
+                while let Some((end_idx, ch)) = chars.peek() {
+                    if !(ch.is_alphanumeric() || *ch == '_' || *ch == '$') {
+                        break;
+                    }
+                    end = end_idx + ch.len_utf8();
+                    let _ = chars.next();
+                }
+                words.push(&query[idx..end]);
+            }
+            ch if ch.is_whitespace() => {}
+            _ => words.push(&query[idx..idx + ch.len_utf8()]),
+        }
+    }
+
+    words
+}
+
+/// `<database>.<table>` or `<table>` name starting at `pos` - and the position following it
+fn qualified_name<'b>(words: &[&'b str], pos: usize) -> Option<(Option<&'b str>, &'b str, usize)> {
+    match (words.get(pos), words.get(pos + 1), words.get(pos + 2)) {
+        (Some(database_name), Some(&"."), Some(table_name)) => {
+            Some((Some(*database_name), *table_name, pos + 3))
+        }
+        (Some(table_name), _, _) => Some((None, *table_name, pos + 1)),
+        _ => None,
+    }
+}
+
+/// `<database>.<table>.<column>` or `<table>.<column>` name starting at `pos`
+fn qualified_column_name<'b>(
+    words: &[&'b str],
+    pos: usize,
+) -> Option<(Option<&'b str>, &'b str, &'b str)> {
+    match words.get(pos..pos + 5) {
+        Some([database_name, ".", table_name, ".", column_name]) => {
+            Some((Some(*database_name), *table_name, *column_name))
+        }
+        _ => match words.get(pos..pos + 3) {
+            Some([table_name, ".", column_name]) => Some((None, *table_name, *column_name)),
+            _ => None,
+        },
+    }
+}
+
+/// split the definitions of a `CREATE TABLE ...` query on the commas which are neither in parentheses nor quoted,
+/// and remove the ones of the dropped columns
+fn strip_create_table_definitions(query: &str, dropped_columns: &HashSet<&str>) -> String {
+    let mut start = None;
+    let mut end = None;
+    let mut commas = vec![];
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+
+    for (idx, ch) in query.char_indices() {
+        match (quote, ch) {
+            (Some(quote_char), ch) if ch == quote_char => quote = None,
+            (Some(_), _) => {}
+            (None, '\'') | (None, '"') | (None, '`') => quote = Some(ch),
+            (None, '(') => {
+                depth += 1;
+                if depth == 1 && start.is_none() {
+                    start = Some(idx);
+                }
+            }
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 && start.is_some() {
+                    end = Some(idx);
+                    break;
+                }
+            }
+            (None, ',') if depth == 1 => commas.push(idx),
+            _ => {}
+        }
+    }
+
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return query.to_string(),
+    };
+
+    let mut definitions = vec![];
+    let mut definition_start = start + 1;
+    for comma in commas {
+        definitions.push(&query[definition_start..comma]);
+        definition_start = comma + 1;
+    }
+    definitions.push(&query[definition_start..end]);
+
+    let mut kept_definitions = definitions
+        .iter()
+        .filter(|definition| !is_dropped_definition(definition, dropped_columns))
+        .map(|definition| definition.to_string())
+        .collect::<Vec<_>>();
+
+    if kept_definitions.len() == definitions.len() {
+        return query.to_string();
+    }
+
+    // keep the line break before the closing parenthesis
+    if let (Some(last_definition), Some(original_last_definition)) =
+        (kept_definitions.last_mut(), definitions.last())
+    {
+        let trailing_whitespaces =
+            &original_last_definition[original_last_definition.trim_end().len()..];
+        *last_definition = format!("{}{}", last_definition.trim_end(), trailing_whitespaces);
+    }
+
+    format!(
+        "{}{}{}",
+        &query[..=start],
+        kept_definitions.join(","),
+        &query[end..]
+    )
+}
+
+fn is_dropped_definition(definition: &str, dropped_columns: &HashSet<&str>) -> bool {
+    let definition = definition.trim_start();
+
+    let mut words = definition
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .filter(|word| !word.is_empty());
+
+    let first_word = match words.next() {
+        Some(word) => word,
+        None => return false,
+    };
+
+    let is_quoted = definition.starts_with('"') || definition.starts_with('`');
+
+    if !is_quoted && TABLE_CONSTRAINT_WORDS.contains(&first_word.to_uppercase().as_str()) {
+        // index or constraint on a dropped column
+        return words.any(|word| dropped_columns.contains(word));
+    }
+
+    dropped_columns.contains(first_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ExcludeColumnsConfig, ExcludedValueConfig};
+    use crate::source::exclude::{
+        exclude_from_copy_row, exclude_from_copy_statement, ExcludedColumns, Exclusion,
+    };
+    use crate::types::Column;
+
+    fn configs() -> Vec<ExcludeColumnsConfig> {
+        vec![
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["secret".to_string()],
+                replace_with: None,
+                drop_from_schema: None,
+            },
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["status".to_string()],
+                replace_with: Some(ExcludedValueConfig::Default),
+                drop_from_schema: None,
+            },
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["document_blob".to_string()],
+                replace_with: None,
+                drop_from_schema: Some(true),
+            },
+        ]
+    }
+
+    #[test]
+    fn exclude_columns() {
+        let configs = configs();
+        let excluded_columns = ExcludedColumns::new(&configs);
+
+        assert_eq!(
+            excluded_columns.exclusion(Some("public"), "documents", "secret"),
+            Some(Exclusion::Null)
+        );
+        assert_eq!(
+            excluded_columns.exclusion(None, "documents", "`status`"),
+            Some(Exclusion::Remove)
+        );
+        assert_eq!(
+            excluded_columns.exclusion(Some("public"), "\"documents\"", "document_blob"),
+            Some(Exclusion::Remove)
+        );
+        assert_eq!(
+            excluded_columns.exclusion(Some("public"), "documents", "id"),
+            None
+        );
+        assert_eq!(
+            excluded_columns.exclusion(Some("private"), "documents", "secret"),
+            None
+        );
+
+        let columns = excluded_columns.exclude_columns(
+            Some("public"),
+            "documents",
+            vec![
+                Column::NumberValue("id".to_string(), 1),
+                Column::StringValue("secret".to_string(), "s3cr3t".to_string()),
+                Column::StringValue("status".to_string(), "draft".to_string()),
+                Column::StringValue("document_blob".to_string(), "blob".to_string()),
+            ],
+        );
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].number_value(), Some(&1));
+        assert!(matches!(&columns[1], Column::None(name) if name == "secret"));
+    }
+
+    #[test]
+    fn exclude_from_copy_rows() {
+        let configs = configs();
+        let excluded_columns = ExcludedColumns::new(&configs);
+
+        let column_names = vec![
+            "id".to_string(),
+            "secret".to_string(),
+            "status".to_string(),
+            "document_blob".to_string(),
+        ];
+        let exclusions = column_names
+            .iter()
+            .map(|column_name| excluded_columns.exclusion(Some("public"), "documents", column_name))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            exclude_from_copy_statement(
+                "COPY public.documents (id, secret, status, document_blob) FROM stdin;",
+                &column_names,
+                &exclusions
+            ),
+            "COPY public.documents (id, secret) FROM stdin;"
+        );
+        assert_eq!(
+            exclude_from_copy_row("1\ts3cr3t\tdraft\tblob", &exclusions),
+            "1\t\\N"
+        );
+    }
+
+    #[test]
+    fn exclude_from_create_table() {
+        let configs = configs();
+        let excluded_columns = ExcludedColumns::new(&configs);
+
+        let query = r#"CREATE TABLE public.documents (
+    id integer NOT NULL,
+    secret text,
+    status character varying(10) DEFAULT 'draft, or not'::character varying,
+    document_blob bytea
+);"#;
+
+        assert_eq!(
+            excluded_columns.exclude_from_create_table(Some("public"), "documents", query),
+            r#"CREATE TABLE public.documents (
+    id integer NOT NULL,
+    secret text,
+    status character varying(10) DEFAULT 'draft, or not'::character varying
+);"#
+        );
+
+        let query = r#"CREATE TABLE `documents` (
+  `id` int NOT NULL,
+  `document_blob` longblob,
+  `key` varchar(10),
+  PRIMARY KEY (`id`),
+  KEY `idx_blob` (`document_blob`(10))
+) ENGINE=InnoDB;"#;
+
+        assert_eq!(
+            excluded_columns.exclude_from_create_table(None, "documents", query),
+            r#"CREATE TABLE `documents` (
+  `id` int NOT NULL,
+  `key` varchar(10),
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB;"#
+        );
+
+        // the other tables are left as they are
+        assert_eq!(
+            excluded_columns.exclude_from_create_table(Some("public"), "employees", query),
+            query
+        );
+    }
+
+    #[test]
+    fn references_dropped_column() {
+        let configs = configs();
+        let excluded_columns = ExcludedColumns::new(&configs);
+
+        for query in [
+            "ALTER TABLE ONLY public.documents ADD CONSTRAINT documents_blob_key UNIQUE (document_blob);",
+            "ALTER TABLE ONLY public.versions ADD CONSTRAINT versions_blob_fkey FOREIGN KEY (blob) REFERENCES public.documents(document_blob);",
+            "ALTER TABLE ONLY public.documents ALTER COLUMN \"document_blob\" SET DEFAULT '\\x00'::bytea;",
+            "CREATE INDEX idx_documents_blob ON public.documents USING btree (id, document_blob);",
+            "CREATE UNIQUE INDEX idx_documents_blob ON ONLY public.documents USING btree (md5(document_blob));",
+            "ALTER SEQUENCE public.documents_blob_seq OWNED BY public.documents.document_blob;",
+            "COMMENT ON COLUMN public.documents.document_blob IS 'the document, as it was uploaded';",
+        ] {
+            assert!(excluded_columns.references_dropped_column(query), "{}", query);
+        }
+
+        for query in [
+            "ALTER TABLE ONLY public.documents ADD CONSTRAINT documents_pkey PRIMARY KEY (id);",
+            "ALTER TABLE ONLY public.documents ADD CONSTRAINT documents_secret_key UNIQUE (secret);",
+            "ALTER TABLE ONLY public.versions ADD CONSTRAINT versions_fkey FOREIGN KEY (document_blob) REFERENCES public.documents(id);",
+            "ALTER TABLE ONLY public.employees ALTER COLUMN document_blob SET DEFAULT NULL;",
+            "ALTER TABLE public.documents OWNER TO postgres;",
+            "CREATE INDEX idx_documents_id ON public.documents USING btree (id) WHERE (status <> 'document_blob');",
+            "ALTER SEQUENCE public.documents_id_seq OWNED BY public.documents.id;",
+            "COMMENT ON COLUMN public.documents.secret IS 'document_blob';",
+            "COMMENT ON TABLE public.documents IS 'documents';",
+        ] {
+            assert!(!excluded_columns.references_dropped_column(query), "{}", query);
+        }
+
+        // nothing is dropped without drop_from_schema
+        assert!(
+            !ExcludedColumns::new(&configs[..1].to_vec()).references_dropped_column(
+                "CREATE INDEX idx_documents_secret ON public.documents USING btree (secret);"
+            )
+        );
+    }
+}
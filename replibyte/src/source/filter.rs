@@ -0,0 +1,347 @@
+use std::io::Error;
+
+use chrono::{NaiveDateTime, Utc};
+use subset::predicate::{matches_all, Predicate};
+
+use crate::config::{FilterConditionConfig, FilterConfig};
+use crate::types::Column;
+
+/// RowFilters keeps or drops the rows of the filtered tables by their column values.
+/// A row is kept when it matches all the `keep_rows` conditions and not all the `drop_rows` conditions of each filter of its table.
+pub struct RowFilters<'a> {
+    filters: Vec<TableFilter<'a>>,
+}
+
+struct TableFilter<'a> {
+    config: &'a FilterConfig,
+    keep_rows: Option<Vec<Condition<'a>>>,
+    drop_rows: Option<Vec<Condition<'a>>>,
+}
+
+struct Condition<'a> {
+    column: &'a str,
+    is_null: Option<bool>,
+    predicates: Vec<Predicate>,
+}
+
+impl<'a> Condition<'a> {
+    /// relative dates are computed from the start of the dump - the same rows are kept all along the dump
+    fn new(config: &'a FilterConditionConfig, now: NaiveDateTime) -> Result<Self, Error> {
+        Ok(Condition {
+            column: config.column.as_str(),
+            is_null: config.is_null,
+            predicates: config
+                .predicates()?
+                .iter()
+                .map(|predicate| predicate.at(now))
+                .collect(),
+        })
+    }
+
+    /// NULL values do not match any predicate - like in SQL
+    fn matches(&self, value: Option<&str>) -> bool {
+        let is_null_match = match self.is_null {
+            Some(is_null) => value.is_none() == is_null,
+            None => true,
+        };
+
+        let predicates_match = match value {
+            _ if self.predicates.is_empty() => true,
+            Some(value) => matches_all(&self.predicates, value),
+            None => false,
+        };
+
+        is_null_match && predicates_match
+    }
+}
+
+impl<'a> RowFilters<'a> {
+    pub fn new(filters: &'a [FilterConfig]) -> Result<Self, Error> {
+        let now = Utc::now().naive_utc();
+
+        let conditions = |configs: &'a Option<Vec<FilterConditionConfig>>| match configs {
+            Some(configs) => configs
+                .iter()
+                .map(|config| Condition::new(config, now))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Some),
+            None => Ok(None),
+        };
+
+        let filters = filters
+            .iter()
+            .map(|config| {
+                Ok(TableFilter {
+                    config,
+                    keep_rows: conditions(&config.keep_rows)?,
+                    drop_rows: conditions(&config.drop_rows)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(RowFilters { filters })
+    }
+
+    /// MySQL dumps do not hold the database name - the filters are matched on the table name only when `database_name` is None
+    fn table_filters<'b>(
+        &'b self,
+        database_name: Option<&'b str>,
+        table_name: &'b str,
+    ) -> impl Iterator<Item = &'b TableFilter<'a>> + 'b {
+        self.filters.iter().filter(move |filter| {
+            filter.config.table == table_name
+                && match database_name {
+                    Some(database_name) => filter.config.database == database_name,
+                    None => true,
+                }
+        })
+    }
+
+    pub fn has_filters(&self, database_name: Option<&str>, table_name: &str) -> bool {
+        self.table_filters(database_name, table_name)
+            .next()
+            .is_some()
+    }
+
+    /// `value` gives the text of a column value - None for NULL and missing columns
+    pub fn keep_row<V: Fn(&str) -> Option<String>>(
+        &self,
+        database_name: Option<&str>,
+        table_name: &str,
+        value: V,
+    ) -> bool {
+        self.table_filters(database_name, table_name).all(|filter| {
+            let matches_all = |conditions: &Vec<Condition>| {
+                conditions
+                    .iter()
+                    .all(|condition| condition.matches(value(condition.column).as_deref()))
+            };
+
+            let keep = match &filter.keep_rows {
+                Some(conditions) => matches_all(conditions),
+                None => true,
+            };
+
+            let drop = match &filter.drop_rows {
+                Some(conditions) => !conditions.is_empty() && matches_all(conditions),
+                None => false,
+            };
+
+            keep && !drop
+        })
+    }
+}
+
+/// the text of a column value as it is compared by the filters
+pub fn to_text(column: &Column) -> Option<String> {
+    match column {
+        Column::NumberValue(_, value) => Some(value.to_string()),
+        Column::FloatNumberValue(_, value) => Some(value.to_string()),
+        Column::StringValue(_, value) => Some(value.clone()),
+        Column::CharValue(_, value) => Some(value.to_string()),
+        Column::BooleanValue(_, value) => Some(value.to_string()),
+        Column::DateValue(_, value) => Some(value.clone()),
+        Column::JsonValue(_, value) => Some(value.clone()),
+        Column::UuidValue(_, value) => Some(value.clone()),
+        Column::BytesValue(_, value) => Some(String::from_utf8_lossy(value).to_string()),
+        Column::ArrayValue(_, value) => Some(value.clone()),
+        Column::RawValue(_, value) => Some(value.clone()),
+        Column::None(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{Duration, Utc};
+
+    use crate::config::{
+        FilterConditionConfig, FilterConfig, PredicateValueConfig, PredicatesConfig,
+    };
+    use crate::source::filter::RowFilters;
+
+    fn condition(column: &str, predicates: PredicatesConfig) -> FilterConditionConfig {
+        FilterConditionConfig {
+            column: column.to_string(),
+            predicates,
+            is_null: None,
+        }
+    }
+
+    fn string(value: &str) -> PredicateValueConfig {
+        PredicateValueConfig::String(value.to_string())
+    }
+
+    fn keep_row(filters: &RowFilters, database_name: Option<&str>, row: &[(&str, &str)]) -> bool {
+        let row = row
+            .iter()
+            .map(|(column, value)| (column.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        filters.keep_row(database_name, "events", |column| {
+            row.get(column).filter(|value| *value != "NULL").cloned()
+        })
+    }
+
+    #[test]
+    fn keep_and_drop_rows() {
+        let filters = vec![
+            FilterConfig {
+                database: "public".to_string(),
+                table: "events".to_string(),
+                keep_rows: Some(vec![condition(
+                    "created_at",
+                    PredicatesConfig {
+                        gt: Some(string("now-30d")),
+                        ..Default::default()
+                    },
+                )]),
+                drop_rows: Some(vec![
+                    condition(
+                        "type",
+                        PredicatesConfig {
+                            in_values: Some(vec![string("login"), string("logout")]),
+                            ..Default::default()
+                        },
+                    ),
+                    condition(
+                        "user_id",
+                        PredicatesConfig {
+                            lt: Some(PredicateValueConfig::Integer(100)),
+                            ..Default::default()
+                        },
+                    ),
+                ]),
+            },
+            FilterConfig {
+                database: "public".to_string(),
+                table: "events".to_string(),
+                keep_rows: Some(vec![FilterConditionConfig {
+                    column: "deleted_at".to_string(),
+                    predicates: PredicatesConfig::default(),
+                    is_null: Some(true),
+                }]),
+                drop_rows: None,
+            },
+        ];
+
+        let filters = RowFilters::new(&filters).unwrap();
+        let recent = (Utc::now() - Duration::days(2))
+            .format("%Y-%m-%d %H:%M:%S+00")
+            .to_string();
+
+        assert!(filters.has_filters(Some("public"), "events"));
+        assert!(filters.has_filters(None, "events"));
+        assert!(!filters.has_filters(Some("public"), "customers"));
+        assert!(!filters.has_filters(Some("private"), "events"));
+
+        let row = [
+            ("created_at", recent.as_str()),
+            ("type", "purchase"),
+            ("user_id", "1"),
+            ("deleted_at", "NULL"),
+        ];
+        assert!(keep_row(&filters, Some("public"), &row));
+        assert!(keep_row(&filters, None, &row));
+
+        // rows of the other databases are not filtered
+        let row = [("created_at", "2000-01-01"), ("deleted_at", "2000-01-02")];
+        assert!(!keep_row(&filters, Some("public"), &row));
+        assert!(keep_row(&filters, Some("private"), &row));
+
+        let row = [
+            ("created_at", recent.as_str()),
+            ("type", "login"),
+            ("user_id", "1"),
+            ("deleted_at", "NULL"),
+        ];
+        assert!(!keep_row(&filters, Some("public"), &row));
+
+        let row = [
+            ("created_at", recent.as_str()),
+            ("type", "login"),
+            ("user_id", "100"),
+            ("deleted_at", "NULL"),
+        ];
+        assert!(keep_row(&filters, Some("public"), &row));
+
+        // NULL values do not match any predicate
+        let row = [("created_at", "NULL"), ("deleted_at", "NULL")];
+        assert!(!keep_row(&filters, Some("public"), &row));
+    }
+
+    #[test]
+    fn compare_values() {
+        let filters = vec![FilterConfig {
+            database: "public".to_string(),
+            table: "events".to_string(),
+            keep_rows: Some(vec![
+                condition(
+                    "created_at",
+                    PredicatesConfig {
+                        gte: Some(string("2022-05-01")),
+                        ..Default::default()
+                    },
+                ),
+                condition(
+                    "archived",
+                    PredicatesConfig {
+                        eq: Some(PredicateValueConfig::Boolean(false)),
+                        ..Default::default()
+                    },
+                ),
+                condition(
+                    "country",
+                    PredicatesConfig {
+                        neq: Some(string("FR")),
+                        ..Default::default()
+                    },
+                ),
+            ]),
+            drop_rows: None,
+        }];
+
+        let filters = RowFilters::new(&filters).unwrap();
+
+        let row = [
+            ("created_at", "2022-05-01 10:00:00+02"),
+            ("archived", "f"),
+            ("country", "US"),
+        ];
+        assert!(keep_row(&filters, Some("public"), &row));
+
+        let row = [
+            ("created_at", "2022-05-01T01:00:00+02:00"),
+            ("archived", "false"),
+            ("country", "US"),
+        ];
+        assert!(!keep_row(&filters, Some("public"), &row));
+
+        let row = [
+            ("created_at", "2022-06-01"),
+            ("archived", "true"),
+            ("country", "US"),
+        ];
+        assert!(!keep_row(&filters, Some("public"), &row));
+
+        let row = [
+            ("created_at", "2022-06-01"),
+            ("archived", "0"),
+            ("country", "FR"),
+        ];
+        assert!(!keep_row(&filters, Some("public"), &row));
+    }
+
+    #[test]
+    fn invalid_condition() {
+        let filters = vec![FilterConfig {
+            database: "public".to_string(),
+            table: "events".to_string(),
+            keep_rows: None,
+            drop_rows: Some(vec![condition("type", PredicatesConfig::default())]),
+        }];
+
+        assert!(RowFilters::new(&filters).is_err());
+    }
+}
@@ -1,16 +1,32 @@
-use std::io::Error;
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, Error, ErrorKind, Read, Write};
 
-use crate::config::{DatabaseSubsetConfig, OnlyTablesConfig, SkipConfig};
+use dump_parser::utils::ListQueryResult;
+use log::info;
+use subset::dialect::{Dialect, DumpSubset};
+use subset::{PassthroughTable, Subset, SubsetOptions, SubsetStrategy};
+
+use crate::config::{
+    DatabaseSubsetConfig, DatabaseSubsetConfigStrategy, ExcludeColumnsConfig, FilterConfig,
+    OnlyTablesConfig, SkipConfig, StrictConfig,
+};
 use crate::connector::Connector;
 use crate::transformer::Transformer;
 use crate::types::{OriginalQuery, Query};
 
+pub mod exclude;
+pub mod filter;
 pub mod mongodb;
 pub mod mongodb_stdin;
 pub mod mysql;
 pub mod mysql_stdin;
 pub mod postgres;
+pub mod postgres_direct;
 pub mod postgres_stdin;
+pub mod sqlite;
+pub mod workers;
 
 pub trait Source: Connector {
     fn read<F: FnMut(OriginalQuery, Query)>(
@@ -18,6 +34,25 @@ pub trait Source: Connector {
         options: SourceOptions,
         query_callback: F,
     ) -> Result<(), Error>;
+
+    /// read the source until the callback returns `ListQueryResult::Break` - the sources which can't be stopped early
+    /// are read until the end without calling back the next queries
+    fn read_until<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+        &self,
+        options: SourceOptions,
+        mut query_callback: F,
+    ) -> Result<(), Error> {
+        let mut is_stopped = false;
+
+        self.read(options, |original_query, query| {
+            if !is_stopped {
+                is_stopped = matches!(
+                    query_callback(original_query, query),
+                    ListQueryResult::Break
+                );
+            }
+        })
+    }
 }
 
 pub struct SourceOptions<'a> {
@@ -25,4 +60,175 @@ pub struct SourceOptions<'a> {
     pub skip_config: &'a Vec<SkipConfig>,
     pub database_subset: &'a Option<DatabaseSubsetConfig>,
     pub only_tables: &'a Vec<OnlyTablesConfig>,
+    pub filters: &'a Vec<FilterConfig>,
+    pub exclude_columns: &'a Vec<ExcludeColumnsConfig>,
+    pub strict: &'a Option<StrictConfig>,
+    // number of threads transforming the statements - the sources read with a single thread ignore it
+    pub workers: usize,
+}
+
+/// Columns of a dump checked in strict mode - each column must be transformed or declared safe
+pub struct StrictColumns {
+    transformed_columns: HashSet<String>,
+    safe_columns: HashSet<String>,
+    unsafe_columns: BTreeSet<String>,
+}
+
+impl StrictColumns {
+    pub fn new(transformed_columns: HashSet<String>, safe_columns: HashSet<String>) -> Self {
+        StrictColumns {
+            transformed_columns,
+            safe_columns,
+            unsafe_columns: BTreeSet::new(),
+        }
+    }
+
+    /// add a column seen in the dump - a nested field is safe when its parent is
+    pub fn add(&mut self, column: String) {
+        if self.transformed_columns.contains(&column) {
+            return;
+        }
+
+        let is_safe = column
+            .match_indices('.')
+            .map(|(idx, _)| &column[..idx])
+            .chain(std::iter::once(column.as_str()))
+            .any(|name| self.safe_columns.contains(name));
+
+        if !is_safe {
+            let _ = self.unsafe_columns.insert(column);
+        }
+    }
+
+    pub fn check(&self) -> Result<(), Error> {
+        if self.unsafe_columns.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "strict mode: {} column(s) are neither transformed nor in <source.strict.safe_columns>: {}",
+                self.unsafe_columns.len(),
+                self.unsafe_columns
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ))
+    }
+}
+
+/// Write the dump into a temporary file to list and check all its columns,
+/// so nothing is transformed and sent if a column is neither transformed nor declared safe.
+pub fn check_strict_columns<R: Read, L>(
+    mut dump_reader: BufReader<R>,
+    mut strict_columns: StrictColumns,
+    list_columns: L,
+) -> Result<BufReader<File>, Error>
+where
+    L: FnOnce(BufReader<File>, &mut StrictColumns) -> Result<(), Error>,
+{
+    let mut named_temp_file = tempfile::NamedTempFile::new()?;
+    let mut temp_dump_file = named_temp_file.as_file_mut();
+    let _ = io::copy(&mut dump_reader, &mut temp_dump_file)?;
+
+    let _ = list_columns(
+        BufReader::new(named_temp_file.reopen()?),
+        &mut strict_columns,
+    )?;
+
+    let _ = strict_columns.check()?;
+
+    Ok(BufReader::new(named_temp_file.reopen()?))
+}
+
+/// Write the dump into a temporary file and subset it with the dialect `D` of the source
+pub fn subset<D: Dialect, R: Read>(
+    mut dump_reader: BufReader<R>,
+    subset_config: &DatabaseSubsetConfig,
+) -> Result<BufReader<File>, Error> {
+    let mut named_temp_file = tempfile::NamedTempFile::new()?;
+    let mut temp_dump_file = named_temp_file.as_file_mut();
+    let _ = io::copy(&mut dump_reader, &mut temp_dump_file)?;
+
+    let strategy = match &subset_config.strategy {
+        DatabaseSubsetConfigStrategy::Random(opt) => opt.subset_strategy(
+            subset_config.database.as_str(),
+            subset_config.table.as_str(),
+        )?,
+        DatabaseSubsetConfigStrategy::Predicate(opt) => SubsetStrategy::Predicate {
+            database: subset_config.database.as_str(),
+            table: subset_config.table.as_str(),
+            column: opt.column.as_str(),
+            predicates: opt.predicates()?,
+        },
+    };
+
+    let empty_vec = Vec::new();
+    let passthrough_tables = subset_config
+        .passthrough_tables
+        .as_ref()
+        .unwrap_or(&empty_vec)
+        .iter()
+        .map(|table| PassthroughTable::new(subset_config.database.as_str(), table.as_str()))
+        .collect::<HashSet<_>>();
+
+    let subset_options = SubsetOptions::new(&passthrough_tables)
+        .with_children_max_depth(subset_config.children_max_depth.unwrap_or(0));
+    let subset = DumpSubset::<D>::new(named_temp_file.path(), strategy, subset_options)?;
+
+    let named_subset_file = tempfile::NamedTempFile::new()?;
+    let mut subset_file = named_subset_file.as_file();
+
+    let _ = subset.read(
+        |row| {
+            match subset_file.write(format!("{}\n", row).as_bytes()) {
+                Ok(_) => {}
+                Err(err) => {
+                    panic!("{}", err)
+                }
+            };
+        },
+        |progress| {
+            info!("Database subset completion: {}%", progress.percent());
+        },
+    )?;
+
+    Ok(BufReader::new(
+        File::open(named_subset_file.path()).unwrap(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::source::StrictColumns;
+
+    #[test]
+    fn strict_columns() {
+        let mut strict_columns = StrictColumns::new(
+            HashSet::from(["public.users.email".to_string()]),
+            HashSet::from([
+                "public.users.id".to_string(),
+                "test.users.profile".to_string(),
+            ]),
+        );
+
+        strict_columns.add("public.users.id".to_string());
+        strict_columns.add("public.users.email".to_string());
+        strict_columns.add("test.users.profile.age".to_string());
+        assert!(strict_columns.check().is_ok());
+
+        strict_columns.add("public.users.last_name".to_string());
+        strict_columns.add("public.users.first_name".to_string());
+        strict_columns.add("public.users.first_name".to_string());
+
+        let err = strict_columns.check().unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("2 column(s) are neither transformed nor in <source.strict.safe_columns>: public.users.first_name, public.users.last_name"));
+    }
 }
@@ -3,14 +3,18 @@ use std::io::{BufReader, Error, ErrorKind, Read};
 use std::process::{Command, Stdio};
 
 use crate::connector::Connector;
-use crate::source::Source;
+use crate::source::filter::RowFilters;
+use crate::source::{check_strict_columns, Source, StrictColumns};
 use crate::transformer::Transformer;
 use crate::types::{Column, OriginalQuery, Query};
 use crate::utils::{binary_exists, wait_for_command};
 use crate::SourceOptions;
 
 use bson::{Bson, Document};
-use dump_parser::mongodb::Archive;
+use dump_parser::mongodb::{ArchiveReader, ArchiveWriter};
+
+/// documents are sent in archives of (about) this size, so the whole dump is never loaded in memory
+const ARCHIVE_BATCH_SIZE: usize = 10 * 1024 * 1024;
 
 pub struct MongoDB<'a> {
     uri: &'a str,
@@ -223,6 +227,87 @@ pub(crate) fn find_all_keys_with_array_wildcard_op(
 
 /// consume reader and apply transformation on INSERT INTO queries if needed
 pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
+    reader: BufReader<R>,
+    source_options: SourceOptions,
+    query_callback: F,
+) -> Result<(), Error> {
+    match source_options.strict {
+        None => transform(reader, source_options, query_callback),
+        Some(strict_config) => {
+            let transformed_columns = source_options
+                .transformers
+                .iter()
+                .map(|transformer| transformer.database_and_table_and_column_name())
+                .collect::<HashSet<_>>();
+
+            let safe_columns = strict_config
+                .safe_columns()
+                .into_iter()
+                .map(|(database, table, column)| format!("{}.{}.{}", database, table, column))
+                .collect::<HashSet<_>>();
+
+            let reader = check_strict_columns(
+                reader,
+                StrictColumns::new(transformed_columns, safe_columns),
+                list_columns,
+            )?;
+
+            transform(reader, source_options, query_callback)
+        }
+    }
+}
+
+/// list the fields of all the documents - array items are named with the `$[]` operator
+fn list_columns<R: Read>(
+    reader: BufReader<R>,
+    strict_columns: &mut StrictColumns,
+) -> Result<(), Error> {
+    let mut archive_reader = ArchiveReader::from_reader(reader)?;
+
+    archive_reader.read_documents(|metadata_doc, doc| {
+        list_document_columns(metadata_doc.prefix().as_str(), &doc, strict_columns);
+        Ok(())
+    })
+}
+
+fn list_document_columns(prefix: &str, doc: &Document, strict_columns: &mut StrictColumns) {
+    for (key, bson) in doc {
+        list_bson_columns(format!("{}.{}", prefix, key), bson, strict_columns);
+    }
+}
+
+fn list_bson_columns(key: String, bson: &Bson, strict_columns: &mut StrictColumns) {
+    match bson {
+        Bson::Document(nested_doc) => {
+            list_document_columns(key.as_str(), nested_doc, strict_columns)
+        }
+        Bson::Array(arr) => {
+            for bson in arr {
+                list_bson_columns(format!("{}.$[]", key), bson, strict_columns);
+            }
+        }
+        _ => strict_columns.add(key),
+    }
+}
+
+/// the text of a field value as it is compared by the filters - nested fields are named `<field>.<nested field>`
+fn field_text(doc: &Document, field: &str) -> Option<String> {
+    if let Some((key, nested_field)) = field.split_once('.') {
+        if let Some(Bson::Document(nested_doc)) = doc.get(key) {
+            return field_text(nested_doc, nested_field);
+        }
+    }
+
+    match doc.get(field)? {
+        Bson::Null | Bson::Undefined => None,
+        Bson::String(value) => Some(value.clone()),
+        Bson::ObjectId(value) => Some(value.to_hex()),
+        Bson::DateTime(value) => Some(value.to_rfc3339_string()),
+        bson => Some(bson.to_string()),
+    }
+}
+
+fn transform<R: Read, F: FnMut(OriginalQuery, Query)>(
     reader: BufReader<R>,
     source_options: SourceOptions,
     mut query_callback: F,
@@ -240,43 +325,84 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
             transformer,
         );
     }
-    // init archive from reader
-    let mut archive = Archive::from_reader(reader)?;
-
-    let original_query = Query(archive.clone().into_bytes()?);
-
-    archive.alter_docs(|prefixed_collections| {
-        for (prefix, collection) in prefixed_collections.to_owned() {
-            let mut new_collection = vec![];
-            for doc in collection {
-                let new_doc = recursively_transform_document(
-                    prefix.clone(), // prefix is <db_name>.<collection_name>
-                    doc,
-                    &transformer_by_db_and_table_and_column_name,
-                    &wildcard_keys,
-                );
-                new_collection.push(new_doc);
-            }
-            prefixed_collections.insert(prefix, new_collection);
+    // init archive reader - the documents are read one by one
+    let mut archive_reader = ArchiveReader::from_reader(reader)?;
+    let mut original_archive = ArchiveWriter::new(archive_reader.header().clone());
+    let mut archive = ArchiveWriter::new(archive_reader.header().clone());
+    let mut prefixes = HashSet::new();
+    let mut has_sent_archive = false;
+    let row_filters = RowFilters::new(source_options.filters)?;
+
+    archive_reader.read_documents(|metadata_doc, doc| {
+        let keep_document = row_filters.keep_row(
+            Some(metadata_doc.db.as_str()),
+            metadata_doc.collection.as_str(),
+            |field| field_text(&doc, field),
+        );
+
+        if !keep_document {
+            // a collection without any kept document is restored empty
+            return Ok(());
+        }
+
+        let prefix = metadata_doc.prefix(); // prefix is <db_name>.<collection_name>
+        let _ = prefixes.insert(prefix.clone());
+
+        original_archive.write_document(metadata_doc, &doc)?;
+
+        let new_doc = recursively_transform_document(
+            prefix,
+            doc,
+            &transformer_by_db_and_table_and_column_name,
+            &wildcard_keys,
+        );
+
+        archive.write_document(metadata_doc, &new_doc)?;
+
+        // cut on the original size - a resumed dump reads the same archives whatever the transformed values
+        if original_archive.size() > ARCHIVE_BATCH_SIZE {
+            // each archive is self-contained, so it can be restored on its own
+            query_callback(
+                Query(original_archive.flush()?),
+                Query(archive.flush()?),
+            );
+            has_sent_archive = true;
+        }
+
+        Ok(())
+    })?;
+
+    // collections without any document must be restored as well
+    for metadata_doc in archive_reader.metadata_docs() {
+        if !prefixes.contains(&metadata_doc.prefix()) {
+            original_archive.write_collection(metadata_doc);
+            archive.write_collection(metadata_doc);
         }
-    });
+    }
 
-    let query = Query(archive.into_bytes()?);
+    if !archive.is_empty() || !has_sent_archive {
+        query_callback(Query(original_archive.flush()?), Query(archive.flush()?));
+    }
 
-    query_callback(original_query, query);
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::config::{SafeColumnsConfig, StrictConfig};
     use crate::source::SourceOptions;
     use crate::transformer::random::RandomTransformer;
     use crate::Source;
     use bson::{doc, Bson};
+    use dump_parser::mongodb::ArchiveReader;
+    use dump_parser::utils::decode_hex;
     use std::collections::{HashMap, HashSet};
+    use std::io::BufReader;
     use std::vec;
 
-    use crate::source::mongodb::{find_all_keys_with_array_wildcard_op, MongoDB};
+    use crate::source::mongodb::{
+        find_all_keys_with_array_wildcard_op, read_and_transform, MongoDB,
+    };
     use crate::transformer::transient::TransientTransformer;
     use crate::transformer::Transformer;
 
@@ -307,6 +433,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         assert!(p.read(source_options, |_, _| {}).is_ok());
@@ -319,6 +449,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         assert!(p.read(source_options, |_, _| {}).is_err());
@@ -334,6 +468,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         p.read(source_options, |original_query, query| {
@@ -343,6 +481,117 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn read_and_transform_archive() {
+        // archive with a single collection "Users" in db "test2" with a single document: {name: "John", age: 42}
+        let archive = decode_hex("6de299816600000010636f6e63757272656e745f636f6c6c656374696f6e7300040000000276657273696f6e0004000000302e3100027365727665725f76657273696f6e0006000000352e302e360002746f6f6c5f76657273696f6e00080000003130302e352e32000003010000026462000600000074657374320002636f6c6c656374696f6e0006000000557365727300026d6574616461746100ad0000007b22696e6465786573223a5b7b2276223a7b22246e756d626572496e74223a2232227d2c226b6579223a7b225f6964223a7b22246e756d626572496e74223a2231227d7d2c226e616d65223a225f69645f227d5d2c2275756964223a223732306531616132326231373435643739663139373530626162323933303837222c22636f6c6c656374696f6e4e616d65223a225573657273222c2274797065223a22636f6c6c656374696f6e227d001073697a6500000000000274797065000b000000636f6c6c656374696f6e0000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f46000012435243000000000000000000002e000000075f696400623f23928e7f1feed4d5e3e1026e616d6500050000004a6f686e0010616765002a00000000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f4600011243524300ff2a87dec3c86e6e00ffffffff").unwrap();
+
+        let t1: Box<dyn Transformer> =
+            Box::new(RandomTransformer::new("test2", "Users", "name", None));
+        let transformers = vec![t1];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(archive.as_slice()),
+            source_options,
+            |original_query, query| queries.push((original_query, query)),
+        )
+        .unwrap();
+
+        assert_eq!(queries.len(), 1);
+        let (original_query, query) = queries.first().unwrap();
+        assert_eq!(original_query.data(), &archive);
+
+        let mut archive_reader =
+            ArchiveReader::from_reader(BufReader::new(query.data().as_slice())).unwrap();
+        let mut documents = vec![];
+        archive_reader
+            .read_documents(|_, document| {
+                documents.push(document);
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(documents.len(), 1);
+        assert_ne!(documents[0].get_str("name").unwrap(), "John");
+        assert_eq!(documents[0].get_i32("age").unwrap(), 42);
+    }
+
+    #[test]
+    fn read_and_transform_archive_in_strict_mode() {
+        // same archive as above: {_id: ObjectId(..), name: "John", age: 42} in "test2.Users"
+        let archive = decode_hex("6de299816600000010636f6e63757272656e745f636f6c6c656374696f6e7300040000000276657273696f6e0004000000302e3100027365727665725f76657273696f6e0006000000352e302e360002746f6f6c5f76657273696f6e00080000003130302e352e32000003010000026462000600000074657374320002636f6c6c656374696f6e0006000000557365727300026d6574616461746100ad0000007b22696e6465786573223a5b7b2276223a7b22246e756d626572496e74223a2232227d2c226b6579223a7b225f6964223a7b22246e756d626572496e74223a2231227d7d2c226e616d65223a225f69645f227d5d2c2275756964223a223732306531616132326231373435643739663139373530626162323933303837222c22636f6c6c656374696f6e4e616d65223a225573657273222c2274797065223a22636f6c6c656374696f6e227d001073697a6500000000000274797065000b000000636f6c6c656374696f6e0000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f46000012435243000000000000000000002e000000075f696400623f23928e7f1feed4d5e3e1026e616d6500050000004a6f686e0010616765002a00000000ffffffff3c000000026462000600000074657374320002636f6c6c656374696f6e000600000055736572730008454f4600011243524300ff2a87dec3c86e6e00ffffffff").unwrap();
+
+        let t1: Box<dyn Transformer> =
+            Box::new(RandomTransformer::new("test2", "Users", "name", None));
+        let transformers = vec![t1];
+
+        let strict_config = |columns: Vec<&str>| {
+            Some(StrictConfig {
+                safe_columns: Some(vec![SafeColumnsConfig {
+                    database: "test2".to_string(),
+                    table: "Users".to_string(),
+                    columns: columns.into_iter().map(|c| c.to_string()).collect(),
+                }]),
+            })
+        };
+
+        // "age" is neither transformed nor safe - nothing is sent
+        let strict = strict_config(vec!["_id"]);
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &strict,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        let err = read_and_transform(
+            BufReader::new(archive.as_slice()),
+            source_options,
+            |original_query, query| queries.push((original_query, query)),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().ends_with(": test2.Users.age"));
+        assert!(queries.is_empty());
+
+        let strict = strict_config(vec!["_id", "age"]);
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &strict,
+            workers: 1,
+        };
+
+        read_and_transform(
+            BufReader::new(archive.as_slice()),
+            source_options,
+            |original_query, query| queries.push((original_query, query)),
+        )
+        .unwrap();
+
+        assert_eq!(queries.len(), 1);
+    }
+
     #[test]
     fn recursive_document_transform() {
         let database_name = "test";
@@ -366,6 +615,7 @@ mod tests {
                 database_name,
                 table_name,
                 &c.to_string(),
+                None,
             ));
             t
         }));
@@ -438,6 +688,7 @@ mod tests {
             database_name,
             table_name,
             column_name.into(),
+            None,
         ));
         let transformers_vec = vec![t];
         // create a set of wildcards to be used in the transformation
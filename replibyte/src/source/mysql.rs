@@ -1,26 +1,30 @@
 use std::borrow::BorrowMut;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Error, ErrorKind, Read};
 use std::process::{Command, Stdio};
 
-use dump_parser::mysql::Keyword::NoKeyword;
+use dump_parser::mysql::Keyword::{NoKeyword, Null};
 use dump_parser::mysql::{
-    get_column_names_from_insert_into_query, get_column_values_from_insert_into_query,
+    escape_string_value, get_column_names_from_insert_into_query,
+    get_column_types_from_create_table_query, get_column_values_from_insert_into_query,
     get_single_quoted_string_value_at_position, get_tokens_from_query_str,
-    match_keyword_at_position, Keyword, Token,
+    match_keyword_at_position, unescape_string_value, Keyword, Token,
 };
-use dump_parser::utils::{list_sql_queries_from_dump_reader, ListQueryResult};
+use dump_parser::utils::{decode_hex, list_sql_queries_from_dump_reader, ListQueryResult};
+use subset::mysql::Mysql as MysqlDialect;
 
 use crate::connector::Connector;
-use crate::source::Source;
+use crate::source::exclude::ExcludedColumns;
+use crate::source::filter::{to_text, RowFilters};
+use crate::source::{check_strict_columns, subset, Source, StrictColumns};
 use crate::transformer::Transformer;
-use crate::types::{Column, InsertIntoQuery, OriginalQuery, Query};
-use crate::utils::{binary_exists, wait_for_command};
+use crate::types::{encode_hex, Column, ColumnType, InsertIntoQuery, OriginalQuery, Query};
+use crate::utils::{binary_exists, kill_command, wait_for_command};
 
 use super::SourceOptions;
 
 #[derive(Debug, PartialEq)]
-enum RowType {
+pub(crate) enum RowType {
     InsertInto { table_name: String },
     CreateTable { table_name: String },
     Others,
@@ -62,7 +66,18 @@ impl<'a> Source for Mysql<'a> {
     fn read<F: FnMut(OriginalQuery, Query)>(
         &self,
         options: SourceOptions,
-        query_callback: F,
+        mut query_callback: F,
+    ) -> Result<(), Error> {
+        self.read_until(options, |original_query, query| {
+            query_callback(original_query, query);
+            ListQueryResult::Continue
+        })
+    }
+
+    fn read_until<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+        &self,
+        options: SourceOptions,
+        mut query_callback: F,
     ) -> Result<(), Error> {
         let s_port = self.port.to_string();
         let password = &format!("-p{}", self.password);
@@ -114,9 +129,29 @@ impl<'a> Source for Mysql<'a> {
             .take()
             .ok_or_else(|| Error::new(ErrorKind::Other, "Could not capture standard output."))?;
 
-        let reader = BufReader::new(stdout);
+        let mut is_stopped = false;
+        let query_callback = |original_query: OriginalQuery, query: Query| {
+            let result = query_callback(original_query, query);
+            is_stopped = matches!(result, ListQueryResult::Break);
+            result
+        };
 
-        read_and_transform(reader, options, query_callback);
+        match &options.database_subset {
+            None => {
+                let reader = BufReader::new(stdout);
+                read_and_transform_until(reader, options, query_callback)?;
+            }
+            Some(subset_config) => {
+                let dump_reader = BufReader::new(stdout);
+                let reader = subset::<MysqlDialect, _>(dump_reader, subset_config)?;
+                read_and_transform_until(reader, options, query_callback)?;
+            }
+        };
+
+        if is_stopped {
+            // mysqldump is not waited for - the rest of the dump is not read
+            return kill_command(&mut process);
+        }
 
         wait_for_command(&mut process)
     }
@@ -126,7 +161,95 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
     reader: BufReader<R>,
     options: SourceOptions,
     mut query_callback: F,
-) {
+) -> Result<(), Error> {
+    read_and_transform_until(reader, options, |original_query, query| {
+        query_callback(original_query, query);
+        ListQueryResult::Continue
+    })
+}
+
+/// same as `read_and_transform` - the next queries are not read once the callback returns `ListQueryResult::Break`
+pub fn read_and_transform_until<R: Read, F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    query_callback: F,
+) -> Result<(), Error> {
+    match options.strict {
+        None => transform(reader, options, query_callback),
+        Some(strict_config) => {
+            // MySQL columns are named after their table only
+            let transformed_columns = options
+                .transformers
+                .iter()
+                .map(|transformer| transformer.table_and_column_name())
+                // the excluded columns are not dumped as they are
+                .chain(options.exclude_columns.iter().flat_map(|config| {
+                    config
+                        .columns
+                        .iter()
+                        .map(move |column| format!("{}.{}", config.table, column))
+                }))
+                .collect::<HashSet<_>>();
+
+            let safe_columns = strict_config
+                .safe_columns()
+                .into_iter()
+                .map(|(_, table, column)| format!("{}.{}", table, column))
+                .collect::<HashSet<_>>();
+
+            let reader = check_strict_columns(
+                reader,
+                StrictColumns::new(transformed_columns, safe_columns),
+                list_columns,
+            )?;
+
+            transform(reader, options, query_callback)
+        }
+    }
+}
+
+/// list the columns of the `CREATE TABLE ...` and `INSERT INTO ...` queries
+fn list_columns<R: Read>(
+    reader: BufReader<R>,
+    strict_columns: &mut StrictColumns,
+) -> Result<(), Error> {
+    match list_sql_queries_from_dump_reader(reader, |query| {
+        let tokens = get_tokens_from_query_str(query);
+
+        let (table_name, column_names) = match get_row_type(&tokens) {
+            RowType::CreateTable { table_name } => (
+                table_name,
+                get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, _)| column_name)
+                    .collect::<Vec<_>>(),
+            ),
+            RowType::InsertInto { table_name } => (
+                table_name,
+                get_column_names_from_insert_into_query(&tokens)
+                    .into_iter()
+                    .map(|column_name| column_name.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            RowType::Others => return ListQueryResult::Continue,
+        };
+
+        for column_name in column_names {
+            strict_columns.add(format!("{}.{}", table_name, column_name));
+        }
+
+        ListQueryResult::Continue
+    }) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    }
+}
+
+fn transform<R: Read, F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    mut query_callback: F,
+) -> Result<(), Error> {
     // create a map variable with Transformer by column_name
     let mut transformer_by_db_and_table_and_column_name: HashMap<String, &Box<dyn Transformer>> =
         HashMap::with_capacity(options.transformers.len());
@@ -136,6 +259,12 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
             .insert(transformer.table_and_column_name(), transformer);
     }
 
+    // column types from the `CREATE TABLE ...` queries, by table
+    let mut column_types_by_table: HashMap<String, HashMap<String, ColumnType>> = HashMap::new();
+
+    let row_filters = RowFilters::new(options.filters)?;
+    let excluded_columns = ExcludedColumns::new(options.exclude_columns);
+
     match list_sql_queries_from_dump_reader(reader, |query| {
         let tokens = get_tokens_from_query_str(query);
 
@@ -144,9 +273,24 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
                 let (original_columns, columns) = transform_columns(
                     table_name.as_str(),
                     &tokens,
+                    column_types_by_table.get(table_name.as_str()),
                     &transformer_by_db_and_table_and_column_name,
                 );
 
+                // MySQL dumps do not hold the database name
+                let keep_row = row_filters.keep_row(None, table_name.as_str(), |column_name| {
+                    original_columns
+                        .iter()
+                        .find(|column| column.name() == column_name)
+                        .and_then(to_text)
+                });
+
+                if !keep_row {
+                    return ListQueryResult::Continue;
+                }
+
+                let columns = excluded_columns.exclude_columns(None, table_name.as_str(), columns);
+
                 query_callback(
                     to_query(
                         None,
@@ -164,33 +308,53 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
                     ),
                 )
             }
-            RowType::CreateTable { table_name: _ } => {
-                no_change_query_callback(query_callback.borrow_mut(), query);
+            RowType::CreateTable { table_name } => {
+                let column_types = get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, sql_type)| {
+                        (column_name, ColumnType::from_sql_type(sql_type.as_str()))
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                let result = query_callback(
+                    Query(query.as_bytes().to_vec()),
+                    Query(
+                        excluded_columns
+                            .exclude_from_create_table(None, table_name.as_str(), query)
+                            .into_bytes(),
+                    ),
+                );
+
+                let _ = column_types_by_table.insert(table_name, column_types);
+
+                result
             }
             RowType::Others => {
                 // other rows than `INSERT INTO ...` and `CREATE TABLE ...`
-                no_change_query_callback(query_callback.borrow_mut(), query);
+                no_change_query_callback(query_callback.borrow_mut(), query)
             }
         }
-
-        ListQueryResult::Continue
     }) {
-        Ok(_) => {}
-        Err(err) => panic!("{:?}", err),
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
     }
 }
 
-fn no_change_query_callback<F: FnMut(OriginalQuery, Query)>(query_callback: &mut F, query: &str) {
+fn no_change_query_callback<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+    query_callback: &mut F,
+    query: &str,
+) -> ListQueryResult {
     query_callback(
         // there is no diff between the original and the modified one
         Query(query.as_bytes().to_vec()),
         Query(query.as_bytes().to_vec()),
-    );
+    )
 }
 
 fn transform_columns(
     table_name: &str,
     tokens: &Vec<Token>,
+    column_types: Option<&HashMap<String, ColumnType>>,
     transformer_by_db_and_table_and_column_name: &HashMap<String, &Box<dyn Transformer>>,
 ) -> (Vec<Column>, Vec<Column>) {
     // find database name by filtering out all queries starting with
@@ -205,65 +369,103 @@ fn transform_columns(
     assert_eq!(column_names.len(), column_values.len(), "Column names do not match values: got {} names and {} values", column_names.len(), column_values.len());
     
     let mut original_columns = vec![];
-    let mut columns = vec![];
 
     for (i, column_name) in column_names.iter().enumerate() {
         let value_token = column_values.get(i).unwrap();
+        let column_type = column_types
+            .and_then(|column_types| column_types.get(*column_name))
+            .copied()
+            .unwrap_or(ColumnType::Unknown);
 
-        let column = match value_token {
-            Token::Number(column_value, _) => {
-                if column_value.contains(".") {
-                    Column::FloatNumberValue(
-                        column_name.to_string(),
-                        column_value.parse::<f64>().unwrap(),
-                    )
-                } else {
-                    Column::NumberValue(
-                        column_name.to_string(),
-                        column_value.parse::<i128>().unwrap(),
-                    )
-                }
-            }
-            Token::Char(column_value) => {
-                Column::CharValue(column_name.to_string(), column_value.clone())
-            }
-            Token::SingleQuotedString(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::NationalStringLiteral(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::HexStringLiteral(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::Word(w)
-                if (w.value == "true" || w.value == "false")
-                    && w.quote_style == None
-                    && w.keyword == NoKeyword =>
-            {
-                Column::BooleanValue(column_name.to_string(), w.value.parse::<bool>().unwrap())
-            }
-            _ => Column::None(column_name.to_string()),
-        };
+        original_columns.push(to_column(column_name, column_type, value_token));
+    }
 
-        // get the right transformer for the right column name
-        let original_column = column.clone();
+    // transformers can read the other columns of the row, so the row is parsed before being transformed
+    let mut columns = vec![];
 
-        let table_and_column_name = format!("{}.{}", table_name, *column_name);
+    for column in &original_columns {
+        // get the right transformer for the right column name
+        let table_and_column_name = format!("{}.{}", table_name, column.name());
 
         let column =
             match transformer_by_db_and_table_and_column_name.get(table_and_column_name.as_str()) {
-                Some(transformer) => transformer.transform(column), // apply transformation on the column
-                None => column,
+                Some(transformer) => transformer.transform_with_row(column.clone(), &original_columns), // apply transformation on the column
+                None => column.clone(),
             };
 
-        original_columns.push(original_column);
         columns.push(column);
     }
 
     (original_columns, columns)
 }
 
+/// type the value of an `INSERT INTO ...` query from its token and the type of its column
+pub(crate) fn to_column(column_name: &str, column_type: ColumnType, value_token: &Token) -> Column {
+    let column_name = column_name.to_string();
+
+    match value_token {
+        Token::Number(column_value, _) => to_number_column(column_name, column_value),
+        Token::Char(column_value) => Column::CharValue(column_name, column_value.clone()),
+        Token::SingleQuotedString(column_value) => {
+            let column_value = unescape_string_value(column_value);
+
+            match column_type {
+                ColumnType::Date => Column::DateValue(column_name, column_value),
+                ColumnType::Json => Column::JsonValue(column_name, column_value),
+                ColumnType::Uuid => Column::UuidValue(column_name, column_value),
+                _ => Column::StringValue(column_name, column_value),
+            }
+        }
+        Token::NationalStringLiteral(column_value) => {
+            Column::StringValue(column_name, unescape_string_value(column_value))
+        }
+        // binary values dumped with --hex-blob: 0x0102FF
+        Token::HexStringLiteral(column_value) => {
+            let bytes = Some(column_value)
+                .filter(|hex| hex.len() % 2 == 0)
+                .and_then(|hex| decode_hex(hex).ok());
+
+            match bytes {
+                Some(bytes) => Column::BytesValue(column_name, bytes),
+                None => Column::RawValue(column_name, format!("0x{}", column_value)),
+            }
+        }
+        Token::Word(w) if w.keyword == Null => Column::None(column_name),
+        Token::Word(w)
+            if (w.value == "true" || w.value == "false")
+                && w.quote_style == None
+                && w.keyword == NoKeyword =>
+        {
+            Column::BooleanValue(column_name, w.value.parse::<bool>().unwrap())
+        }
+        Token::Word(w) => Column::RawValue(
+            column_name,
+            match w.quote_style {
+                Some(quote) => format!("{}{}{}", quote, w.value, quote),
+                None => w.value.clone(),
+            },
+        ),
+        _ => Column::None(column_name),
+    }
+}
+
+/// a value is a number only if it can be written back as it is - e.g. `1.50` is kept as a raw value
+pub(crate) fn to_number_column(column_name: String, column_value: &str) -> Column {
+    if let Ok(value) = column_value.parse::<i128>() {
+        if value.to_string() == column_value {
+            return Column::NumberValue(column_name, value);
+        }
+    }
+
+    if let Ok(value) = column_value.parse::<f64>() {
+        if value.is_finite() && value.to_string() == column_value {
+            return Column::FloatNumberValue(column_name, value);
+        }
+    }
+
+    Column::RawValue(column_name, column_value.to_string())
+}
+
 fn is_insert_into_statement(tokens: &Vec<Token>) -> bool {
     match_keyword_at_position(Keyword::Insert, &tokens, 0)
         && match_keyword_at_position(Keyword::Into, &tokens, 2)
@@ -274,7 +476,7 @@ fn is_create_table_statement(tokens: &Vec<Token>) -> bool {
         && match_keyword_at_position(Keyword::Table, &tokens, 2)
 }
 
-fn get_row_type(tokens: &Vec<Token>) -> RowType {
+pub(crate) fn get_row_type(tokens: &Vec<Token>) -> RowType {
     let mut row_type = RowType::Others;
 
     if is_insert_into_statement(&tokens) {
@@ -312,16 +514,38 @@ fn to_query(database: Option<&str>, query: InsertIntoQuery) -> Query {
             }
             Column::StringValue(column_name, value) => {
                 column_names.push(column_name);
-                values.push(format!("'{}'", value));
+                values.push(format!("'{}'", escape_string_value(value.as_str())));
             }
             Column::CharValue(column_name, value) => {
                 column_names.push(column_name);
-                values.push(format!("'{}'", value));
+                values.push(format!(
+                    "'{}'",
+                    escape_string_value(value.to_string().as_str())
+                ));
             }
             Column::BooleanValue(column_name, value) => {
                 column_names.push(column_name);
                 values.push(value.to_string());
             }
+            Column::DateValue(column_name, value)
+            | Column::JsonValue(column_name, value)
+            | Column::UuidValue(column_name, value)
+            | Column::ArrayValue(column_name, value) => {
+                column_names.push(column_name);
+                values.push(format!("'{}'", escape_string_value(value.as_str())));
+            }
+            Column::BytesValue(column_name, value) => {
+                column_names.push(column_name);
+                if value.is_empty() {
+                    values.push("''".to_string());
+                } else {
+                    values.push(format!("0x{}", encode_hex(&value).to_uppercase()));
+                }
+            }
+            Column::RawValue(column_name, value) => {
+                column_names.push(column_name);
+                values.push(value);
+            }
             Column::None(column_name) => {
                 column_names.push(column_name);
                 values.push("NULL".to_string());
@@ -351,12 +575,19 @@ fn to_query(database: Option<&str>, query: InsertIntoQuery) -> Query {
 
 #[cfg(test)]
 mod tests {
+    use crate::config::{
+        FilterConditionConfig, FilterConfig, PredicateValueConfig, PredicatesConfig,
+    };
     use crate::connector::Connector;
-    use crate::source::mysql::{is_create_table_statement, is_insert_into_statement, RowType};
+    use crate::source::mysql::{
+        is_create_table_statement, is_insert_into_statement, read_and_transform, RowType,
+    };
     use crate::source::SourceOptions;
     use crate::transformer::{transient::TransientTransformer, Transformer};
     use crate::Source;
     use dump_parser::mysql::Tokenizer;
+    use std::io::BufReader;
+    use std::str;
 
     use super::{get_row_type, Mysql};
 
@@ -380,6 +611,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         assert!(p.read(source_options, |_original_query, _query| {}).is_ok());
@@ -392,6 +627,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
         assert!(p
             .read(source_options, |_original_query, _query| {})
@@ -408,6 +647,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
         let _ = p.read(source_options, |original_query, query| {
             assert!(original_query.data().len() > 0);
@@ -514,4 +757,113 @@ CONSTRAINT `city_ibfk_1` FOREIGN KEY (`CountryCode`) REFERENCES `country` (`Code
         let tokens = tokenizer.tokenize().unwrap();
         assert_eq!(is_create_table_statement(&tokens), true);
     }
+
+    #[test]
+    fn read_and_transform_typed_columns() {
+        let dump = r#"CREATE TABLE `customers` (
+  `id` int(10) unsigned NOT NULL AUTO_INCREMENT,
+  `preferences` json DEFAULT NULL,
+  `picture` blob,
+  `balance` decimal(10,2) DEFAULT NULL,
+  `created_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+INSERT INTO `customers` (`id`, `preferences`, `picture`, `balance`, `created_at`) VALUES (1, '{\"lang\": \"fr\", \"note\": \"it\'s\"}', 0x0102FF, 12.50, '2022-05-01 10:00:00');
+INSERT INTO `customers` (`id`, `preferences`, `picture`, `balance`, `created_at`) VALUES (2, NULL, NULL, NULL, CURRENT_TIMESTAMP);
+"#;
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            },
+        )
+        .unwrap();
+
+        let insert_queries = queries
+            .iter()
+            .filter(|(_, query)| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 2);
+
+        // values are written back as they are in the dump
+        for (original_query, query) in insert_queries {
+            assert_eq!(original_query, query);
+            assert!(dump.contains(query.as_str()));
+        }
+    }
+
+    #[test]
+    fn read_and_transform_with_filters() {
+        let dump = r#"INSERT INTO `audit_log` (`id`, `type`) VALUES (1,'login');
+INSERT INTO `audit_log` (`id`, `type`) VALUES (2,'update');
+INSERT INTO `customers` (`id`, `name`) VALUES (1,'login');
+"#;
+
+        let filters = vec![FilterConfig {
+            database: "world".to_string(),
+            table: "audit_log".to_string(),
+            keep_rows: None,
+            drop_rows: Some(vec![FilterConditionConfig {
+                column: "type".to_string(),
+                predicates: PredicatesConfig {
+                    in_values: Some(vec![
+                        PredicateValueConfig::String("login".to_string()),
+                        PredicateValueConfig::String("logout".to_string()),
+                    ]),
+                    ..Default::default()
+                },
+                is_null: None,
+            }]),
+        }];
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &filters,
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |_, query| queries.push(str::from_utf8(query.data()).unwrap().to_string()),
+        )
+        .unwrap();
+
+        let insert_queries = queries
+            .iter()
+            .filter(|query| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 2);
+        assert!(insert_queries[0].contains("`audit_log`"));
+        assert!(insert_queries[0].contains("'update'"));
+        assert!(insert_queries[1].contains("`customers`"));
+    }
 }
@@ -1,7 +1,11 @@
 use std::io::{stdin, BufReader, Error};
 
+use dump_parser::utils::ListQueryResult;
+use subset::mysql::Mysql as MysqlDialect;
+
 use crate::connector::Connector;
-use crate::source::mysql::read_and_transform;
+use crate::source::mysql::read_and_transform_until;
+use crate::source::subset;
 use crate::types::{OriginalQuery, Query};
 use crate::Source;
 use crate::SourceOptions;
@@ -29,12 +33,32 @@ impl Connector for MysqlStdin {
 
 impl Source for MysqlStdin {
     fn read<F: FnMut(OriginalQuery, Query)>(
+        &self,
+        options: SourceOptions,
+        mut query_callback: F,
+    ) -> Result<(), Error> {
+        self.read_until(options, |original_query, query| {
+            query_callback(original_query, query);
+            ListQueryResult::Continue
+        })
+    }
+
+    fn read_until<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
         &self,
         options: SourceOptions,
         query_callback: F,
     ) -> Result<(), Error> {
-        let reader = BufReader::new(stdin());
-        read_and_transform(reader, options, query_callback);
+        match &options.database_subset {
+            None => {
+                let reader = BufReader::new(stdin());
+                read_and_transform_until(reader, options, query_callback)?;
+            }
+            Some(subset_config) => {
+                let dump_reader = BufReader::new(stdin());
+                let reader = subset::<MysqlDialect, _>(dump_reader, subset_config)?;
+                read_and_transform_until(reader, options, query_callback)?;
+            }
+        };
 
         Ok(())
     }
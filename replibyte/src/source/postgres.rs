@@ -1,33 +1,41 @@
-use std::borrow::BorrowMut;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io;
-use std::io::{BufReader, Error, ErrorKind, Read, Write};
+use std::io::{BufReader, Error, ErrorKind, Read};
 use std::process::{Command, Stdio};
+use std::sync::Arc;
 
-use log::info;
-
-use dump_parser::postgres::Keyword::NoKeyword;
+use dump_parser::postgres::Keyword::{NoKeyword, Null};
 use dump_parser::postgres::{
-    get_column_names_from_insert_into_query, get_column_values_from_insert_into_query,
-    get_tokens_from_query_str, get_word_value_at_position, match_keyword_at_position, Keyword,
+    escape_copy_value, get_column_names_from_copy_query, get_column_names_from_insert_into_query,
+    get_column_types_from_create_table_query, get_column_values_from_copy_row,
+    get_column_values_from_insert_into_query, get_tokens_from_query_str,
+    get_word_value_at_position, is_copy_from_stdin_query, match_keyword_at_position, Keyword,
     Token,
 };
-use dump_parser::utils::{list_sql_queries_from_dump_reader, ListQueryResult};
-use subset::postgres::{PostgresSubset, SubsetStrategy};
-use subset::{PassthroughTable, Subset, SubsetOptions};
+use dump_parser::utils::{decode_hex, list_sql_queries_from_dump_reader, ListQueryResult};
+use subset::postgres::Postgres as PostgresDialect;
 
-use crate::config::DatabaseSubsetConfigStrategy;
 use crate::connector::Connector;
-use crate::source::Source;
+use crate::source::exclude::{
+    exclude_from_copy_row, exclude_from_copy_statement, ExcludedColumns, Exclusion,
+};
+use crate::source::filter::{to_text, RowFilters};
+use crate::source::workers::run_in_order;
+use crate::source::{check_strict_columns, subset, Source, StrictColumns};
 use crate::transformer::Transformer;
-use crate::types::{Column, InsertIntoQuery, OriginalQuery, Query};
-use crate::utils::{binary_exists, wait_for_command};
-use crate::DatabaseSubsetConfig;
+use crate::types::{encode_hex, Column, ColumnType, InsertIntoQuery, OriginalQuery, Query};
+use crate::utils::{binary_exists, kill_command, wait_for_command};
 
 use super::SourceOptions;
 
-enum RowType {
+/// end of data marker of a `COPY ... FROM stdin;` block
+pub(crate) const END_OF_COPY_DATA: &str = "\\.";
+/// COPY blocks are split into smaller (valid) COPY blocks of this size to keep the memory usage low
+const COPY_BATCH_SIZE: usize = 10 * 1024 * 1024;
+/// COPY data rows are sent to the workers by batches of this size
+const COPY_ROWS_BATCH_SIZE: usize = 1024 * 1024;
+
+pub(crate) enum RowType {
     InsertInto {
         database_name: String,
         table_name: String,
@@ -40,9 +48,104 @@ enum RowType {
         database_name: String,
         table_name: String,
     },
+    CopyFrom {
+        database_name: String,
+        table_name: String,
+    },
     Others,
 }
 
+/// `COPY ... FROM stdin;` block being read - its data rows are transformed by the workers
+struct CopyBlock {
+    database_name: String,
+    table_name: String,
+    column_names: Vec<String>,
+    column_types: Vec<ColumnType>,
+    statement: String,
+    /// statement without the columns left out of the data
+    transformed_statement: String,
+    skip: bool,
+    has_transformers: bool,
+    has_filters: bool,
+    exclusions: Vec<Option<Exclusion>>,
+    has_exclusions: bool,
+}
+
+/// transformed rows of a `COPY ... FROM stdin;` block waiting to be sent
+#[derive(Default)]
+struct CopyRows {
+    original_rows: Vec<String>,
+    rows: Vec<String>,
+    /// size of the original rows - the blocks are cut at the same rows whatever the transformed values,
+    /// so a resumed dump reads the same queries again
+    size: usize,
+}
+
+impl CopyRows {
+    fn push(&mut self, original_row: String, row: String) {
+        self.size += original_row.len();
+        self.original_rows.push(original_row);
+        self.rows.push(row);
+    }
+
+    /// send the buffered rows as a complete COPY block - so it can be restored on its own
+    fn flush<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+        &mut self,
+        block: &CopyBlock,
+        query_callback: &mut F,
+    ) -> ListQueryResult {
+        let result = query_callback(
+            to_copy_query(block.statement.as_str(), &self.original_rows),
+            to_copy_query(block.transformed_statement.as_str(), &self.rows),
+        );
+
+        self.original_rows.clear();
+        self.rows.clear();
+        self.size = 0;
+
+        result
+    }
+}
+
+/// part of the dump sent to a worker
+enum Statement {
+    /// `INSERT INTO ...` query - tokenized by the worker
+    InsertInto {
+        query: String,
+        column_types_by_db_and_table: Arc<HashMap<String, HashMap<String, ColumnType>>>,
+    },
+    /// data rows of a `COPY ... FROM stdin;` block
+    CopyRows {
+        block: Arc<CopyBlock>,
+        rows: Vec<String>,
+    },
+    /// end of a `COPY ... FROM stdin;` block
+    EndOfCopy(Arc<CopyBlock>),
+    /// query which does not need to be transformed
+    Query(OriginalQuery, Query),
+}
+
+/// statement transformed by a worker
+enum TransformedStatement {
+    /// None when the row is skipped or filtered out
+    Query(Option<(OriginalQuery, Query)>),
+    CopyRows {
+        block: Arc<CopyBlock>,
+        rows: Vec<(String, String)>,
+    },
+    EndOfCopy(Arc<CopyBlock>),
+    /// the statement can't be transformed - the dump is stopped
+    Error(Error),
+}
+
+/// configuration of the transformation shared by the workers
+struct Transformation<'a> {
+    transformer_by_db_and_table_and_column_name: HashMap<String, &'a Box<dyn Transformer>>,
+    skip_tables_map: HashMap<String, bool>,
+    row_filters: RowFilters<'a>,
+    excluded_columns: ExcludedColumns<'a>,
+}
+
 pub struct Postgres<'a> {
     host: &'a str,
     port: u16,
@@ -79,13 +182,23 @@ impl<'a> Source for Postgres<'a> {
     fn read<F: FnMut(OriginalQuery, Query)>(
         &self,
         options: SourceOptions,
-        query_callback: F,
+        mut query_callback: F,
+    ) -> Result<(), Error> {
+        self.read_until(options, |original_query, query| {
+            query_callback(original_query, query);
+            ListQueryResult::Continue
+        })
+    }
+
+    fn read_until<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+        &self,
+        options: SourceOptions,
+        mut query_callback: F,
     ) -> Result<(), Error> {
         let s_port = self.port.to_string();
 
         let mut dump_args = vec![
-            "--column-inserts", // dump data as INSERT commands with column names
-            "--no-owner",       // skip restoration of object ownership
+            "--no-owner", // skip restoration of object ownership
             "-h",
             self.host,
             "-p",
@@ -106,6 +219,12 @@ impl<'a> Source for Postgres<'a> {
 
         dump_args.append(&mut only_tables_args);
 
+        if options.database_subset.is_some() {
+            // the subset needs to dump data as INSERT commands with column names,
+            // otherwise data are dumped as (faster) COPY blocks
+            dump_args.push("--column-inserts");
+        }
+
         dump_args.push(self.database);
 
         // TODO: as for mysql we can exclude tables directly here so we can remove the skip_tables_map checks
@@ -121,78 +240,159 @@ impl<'a> Source for Postgres<'a> {
             .take()
             .ok_or_else(|| Error::new(ErrorKind::Other, "Could not capture standard output."))?;
 
+        let mut is_stopped = false;
+        let query_callback = |original_query: OriginalQuery, query: Query| {
+            let result = query_callback(original_query, query);
+            is_stopped = matches!(result, ListQueryResult::Break);
+            result
+        };
+
         match &options.database_subset {
             None => {
                 let reader = BufReader::new(stdout);
-                read_and_transform(reader, options, query_callback);
+                read_and_transform_until(reader, options, query_callback)?;
             }
             Some(subset_config) => {
                 let dump_reader = BufReader::new(stdout);
-                let reader = subset(dump_reader, subset_config)?;
-                read_and_transform(reader, options, query_callback);
+                let reader = subset::<PostgresDialect, _>(dump_reader, subset_config)?;
+                read_and_transform_until(reader, options, query_callback)?;
             }
         };
 
+        if is_stopped {
+            // pg_dump is not waited for - the rest of the dump is not read
+            return kill_command(&mut process);
+        }
+
         wait_for_command(&mut process)
     }
 }
 
-pub fn subset<R: Read>(
-    mut dump_reader: BufReader<R>,
-    subset_config: &DatabaseSubsetConfig,
-) -> Result<BufReader<File>, Error> {
-    let mut named_temp_file = tempfile::NamedTempFile::new()?;
-    let mut temp_dump_file = named_temp_file.as_file_mut();
-    let _ = io::copy(&mut dump_reader, &mut temp_dump_file)?;
+/// consume reader and apply transformation on INSERT INTO queries if needed
+pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    mut query_callback: F,
+) -> Result<(), Error> {
+    read_and_transform_until(reader, options, |original_query, query| {
+        query_callback(original_query, query);
+        ListQueryResult::Continue
+    })
+}
 
-    let strategy = match subset_config.strategy {
-        DatabaseSubsetConfigStrategy::Random(opt) => SubsetStrategy::RandomPercent {
-            database: subset_config.database.as_str(),
-            table: subset_config.table.as_str(),
-            percent: opt.percent,
-        },
-    };
+/// same as `read_and_transform` - the next queries are not read once the callback returns `ListQueryResult::Break`
+pub fn read_and_transform_until<R: Read, F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    query_callback: F,
+) -> Result<(), Error> {
+    match options.strict {
+        None => transform(reader, options, query_callback),
+        Some(strict_config) => {
+            let transformed_columns = options
+                .transformers
+                .iter()
+                .map(|transformer| transformer.database_and_quoted_table_and_column_name())
+                // the excluded columns are not dumped as they are
+                .chain(options.exclude_columns.iter().flat_map(|config| {
+                    config.columns.iter().map(move |column| {
+                        format!("{}.{}.{}", config.database, config.table, column)
+                    })
+                }))
+                .collect::<HashSet<_>>();
+
+            let safe_columns = strict_config
+                .safe_columns()
+                .into_iter()
+                .map(|(database, table, column)| format!("{}.{}.{}", database, table, column))
+                .collect::<HashSet<_>>();
+
+            let reader = check_strict_columns(
+                reader,
+                StrictColumns::new(transformed_columns, safe_columns),
+                |reader, strict_columns| list_columns(reader, &options, strict_columns),
+            )?;
+
+            transform(reader, options, query_callback)
+        }
+    }
+}
 
-    let empty_vec = Vec::new();
-    let passthrough_tables = subset_config
-        .passthrough_tables
-        .as_ref()
-        .unwrap_or(&empty_vec)
+/// list the columns of the `CREATE TABLE ...`, `INSERT INTO ...` and `COPY ...` queries
+fn list_columns<R: Read>(
+    reader: BufReader<R>,
+    options: &SourceOptions,
+    strict_columns: &mut StrictColumns,
+) -> Result<(), Error> {
+    let skip_tables = options
+        .skip_config
         .iter()
-        .map(|table| PassthroughTable::new(subset_config.database.as_str(), table.as_str()))
+        .map(|skip| format!("{}.{}", skip.database, skip.table))
         .collect::<HashSet<_>>();
 
-    let subset_options = SubsetOptions::new(&passthrough_tables);
-    let subset = PostgresSubset::new(named_temp_file.path(), strategy, subset_options)?;
+    // the data rows of a `COPY ... FROM stdin;` block are not queries
+    let mut is_copy_block = false;
 
-    let named_subset_file = tempfile::NamedTempFile::new()?;
-    let mut subset_file = named_subset_file.as_file();
+    match list_sql_queries_from_dump_reader(reader, |query| {
+        if is_copy_block {
+            is_copy_block = query != END_OF_COPY_DATA;
+            return ListQueryResult::Continue;
+        }
 
-    let _ = subset.read(
-        |row| {
-            match subset_file.write(format!("{}\n", row).as_bytes()) {
-                Ok(_) => {}
-                Err(err) => {
-                    panic!("{}", err)
-                }
-            };
-        },
-        |progress| {
-            info!("Database subset completion: {}%", progress.percent());
-        },
-    )?;
+        let tokens = get_tokens_from_query_str(query);
 
-    Ok(BufReader::new(
-        File::open(named_subset_file.path()).unwrap(),
-    ))
+        let (database_name, table_name, column_names) = match get_row_type(&tokens) {
+            RowType::CreateTable {
+                database_name,
+                table_name,
+            } => (
+                database_name,
+                table_name,
+                get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, _)| column_name)
+                    .collect::<Vec<_>>(),
+            ),
+            RowType::InsertInto {
+                database_name,
+                table_name,
+            } => (
+                database_name,
+                table_name,
+                get_column_names_from_insert_into_query(&tokens),
+            ),
+            RowType::CopyFrom {
+                database_name,
+                table_name,
+            } => {
+                is_copy_block = true;
+                (
+                    database_name,
+                    table_name,
+                    get_column_names_from_copy_query(&tokens),
+                )
+            }
+            _ => return ListQueryResult::Continue,
+        };
+
+        if !skip_tables.contains(&format!("{}.{}", database_name, table_name)) {
+            for column_name in column_names {
+                strict_columns.add(format!("{}.{}.{}", database_name, table_name, column_name));
+            }
+        }
+
+        ListQueryResult::Continue
+    }) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    }
 }
 
-/// consume reader and apply transformation on INSERT INTO queries if needed
-pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
+fn transform<R: Read, F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
     reader: BufReader<R>,
     options: SourceOptions,
     mut query_callback: F,
-) {
+) -> Result<(), Error> {
     // create a map variable with Transformer by column_name
     let mut transformer_by_db_and_table_and_column_name: HashMap<String, &Box<dyn Transformer>> =
         HashMap::with_capacity(options.transformers.len());
@@ -210,81 +410,438 @@ pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
         let _ = skip_tables_map.insert(format!("{}.{}", skip.database, skip.table), true);
     }
 
+    let transformation = Transformation {
+        transformer_by_db_and_table_and_column_name,
+        skip_tables_map,
+        row_filters: RowFilters::new(options.filters)?,
+        excluded_columns: ExcludedColumns::new(options.exclude_columns),
+    };
+
+    // transformed rows of the current `COPY ... FROM stdin;` block
+    let mut copy_rows = CopyRows::default();
+    // the first statement which can't be transformed - the next ones are not read
+    let transform_error: RefCell<Option<Error>> = RefCell::new(None);
+    // the callback does not need the next statements - they are not read either
+    let is_stopped = Cell::new(false);
+
+    // the statements are transformed in parallel and sent in the order of the dump
+    run_in_order(
+        options.workers,
+        |send| {
+            read_statements(reader, &transformation, send, &|| {
+                is_stopped.get() || transform_error.borrow().is_some()
+            })
+        },
+        |statement| transform_statement(statement, &transformation),
+        |transformed_statement| match transformed_statement {
+            _ if is_stopped.get() || transform_error.borrow().is_some() => {}
+            TransformedStatement::Query(Some((original_query, query))) => is_stopped.set(matches!(
+                query_callback(original_query, query),
+                ListQueryResult::Break
+            )),
+            TransformedStatement::Query(None) => {}
+            TransformedStatement::CopyRows { block, rows } => {
+                for (original_row, row) in rows {
+                    copy_rows.push(original_row, row);
+
+                    if copy_rows.size > COPY_BATCH_SIZE {
+                        if let ListQueryResult::Break = copy_rows.flush(&block, &mut query_callback)
+                        {
+                            is_stopped.set(true);
+                            break;
+                        }
+                    }
+                }
+            }
+            TransformedStatement::EndOfCopy(block) => is_stopped.set(matches!(
+                copy_rows.flush(&block, &mut query_callback),
+                ListQueryResult::Break
+            )),
+            TransformedStatement::Error(err) => *transform_error.borrow_mut() = Some(err),
+        },
+    )?;
+
+    match transform_error.into_inner() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// read the queries of the dump and send the statements to transform - the column types and the
+/// `COPY ... FROM stdin;` blocks depend on the previous queries, so they are tracked while reading
+fn read_statements<R: Read>(
+    reader: BufReader<R>,
+    transformation: &Transformation,
+    send: &mut dyn FnMut(Statement),
+    stopped: &dyn Fn() -> bool,
+) -> Result<(), Error> {
+    // column types from the `CREATE TABLE ...` queries, by "<database>.<table>"
+    let mut column_types_by_db_and_table: Arc<HashMap<String, HashMap<String, ColumnType>>> =
+        Arc::new(HashMap::new());
+
+    // current `COPY ... FROM stdin;` block - the next queries are its data rows
+    let mut copy_block: Option<Arc<CopyBlock>> = None;
+    let mut copy_rows: Vec<String> = vec![];
+    let mut copy_rows_size = 0usize;
+
     match list_sql_queries_from_dump_reader(reader, |query| {
+        if stopped() {
+            return ListQueryResult::Break;
+        }
+
+        if let Some(block) = copy_block.clone() {
+            if query == "\n" {
+                // new line following the COPY statement
+                return ListQueryResult::Continue;
+            }
+
+            if query == END_OF_COPY_DATA {
+                if !block.skip {
+                    if !copy_rows.is_empty() {
+                        send(Statement::CopyRows {
+                            block: block.clone(),
+                            rows: std::mem::take(&mut copy_rows),
+                        });
+                    }
+
+                    send(Statement::EndOfCopy(block));
+                }
+
+                copy_block = None;
+                return ListQueryResult::Continue;
+            }
+
+            if block.skip {
+                return ListQueryResult::Continue;
+            }
+
+            copy_rows_size += query.len();
+            copy_rows.push(query.to_string());
+
+            if copy_rows_size > COPY_ROWS_BATCH_SIZE {
+                send(Statement::CopyRows {
+                    block,
+                    rows: std::mem::take(&mut copy_rows),
+                });
+                copy_rows_size = 0;
+            }
+
+            return ListQueryResult::Continue;
+        }
+
+        // `INSERT INTO ...` queries are tokenized by the workers
+        if starts_with_insert(query) {
+            send(Statement::InsertInto {
+                query: query.to_string(),
+                column_types_by_db_and_table: column_types_by_db_and_table.clone(),
+            });
+
+            return ListQueryResult::Continue;
+        }
+
         let tokens = get_tokens_from_query_str(query);
 
         match get_row_type(&tokens) {
-            RowType::InsertInto {
+            RowType::InsertInto { .. } => send(Statement::InsertInto {
+                query: query.to_string(),
+                column_types_by_db_and_table: column_types_by_db_and_table.clone(),
+            }),
+            RowType::CreateTable {
                 database_name,
                 table_name,
             } => {
-                if !skip_tables_map.contains_key(&format!("{}.{}", database_name, table_name)) {
-                    let (original_columns, columns) = transform_columns(
-                        database_name.as_str(),
-                        table_name.as_str(),
-                        &tokens,
-                        &transformer_by_db_and_table_and_column_name,
-                    );
-
-                    query_callback(
-                        to_query(
-                            Some(database_name.as_str()),
-                            InsertIntoQuery {
-                                table_name: table_name.to_string(),
-                                columns: original_columns,
-                            },
+                let column_types = get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, sql_type)| {
+                        (column_name, ColumnType::from_sql_type(sql_type.as_str()))
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                // the statements already sent keep the column types they were sent with
+                let _ = Arc::make_mut(&mut column_types_by_db_and_table)
+                    .insert(format!("{}.{}", database_name, table_name), column_types);
+
+                if !transformation
+                    .skip_tables_map
+                    .contains_key(&format!("{}.{}", database_name, table_name))
+                {
+                    send(Statement::Query(
+                        Query(query.as_bytes().to_vec()),
+                        Query(
+                            transformation
+                                .excluded_columns
+                                .exclude_from_create_table(
+                                    Some(database_name.as_str()),
+                                    table_name.as_str(),
+                                    query,
+                                )
+                                .into_bytes(),
                         ),
-                        to_query(
-                            Some(database_name.as_str()),
-                            InsertIntoQuery {
-                                table_name: table_name.to_string(),
-                                columns,
-                            },
-                        ),
-                    )
+                    ));
                 }
             }
-            RowType::CreateTable {
+            RowType::AlterTable {
                 database_name,
                 table_name,
             } => {
-                if !skip_tables_map.contains_key(&format!("{}.{}", database_name, table_name)) {
-                    no_change_query_callback(query_callback.borrow_mut(), query);
+                // the constraints and defaults on the columns dropped from the schema are dropped too
+                if !transformation
+                    .skip_tables_map
+                    .contains_key(&format!("{}.{}", database_name, table_name))
+                    && !transformation
+                        .excluded_columns
+                        .references_dropped_column(query)
+                {
+                    send(no_change_statement(query));
                 }
             }
-            RowType::AlterTable {
+            RowType::CopyFrom {
                 database_name,
                 table_name,
             } => {
-                if !skip_tables_map.contains_key(&format!("{}.{}", database_name, table_name)) {
-                    no_change_query_callback(query_callback.borrow_mut(), query);
-                }
+                let column_names = get_column_names_from_copy_query(&tokens);
+                let column_types =
+                    column_types_by_db_and_table.get(&format!("{}.{}", database_name, table_name));
+                let column_types = column_names
+                    .iter()
+                    .map(|column_name| get_column_type(column_types, column_name.as_str()))
+                    .collect::<Vec<_>>();
+                let has_transformers = column_names.iter().any(|column_name| {
+                    transformation
+                        .transformer_by_db_and_table_and_column_name
+                        .contains_key(
+                            format!("{}.{}.{}", database_name, table_name, column_name).as_str(),
+                        )
+                });
+
+                let has_filters = transformation
+                    .row_filters
+                    .has_filters(Some(database_name.as_str()), table_name.as_str());
+                let exclusions = column_names
+                    .iter()
+                    .map(|column_name| {
+                        transformation.excluded_columns.exclusion(
+                            Some(database_name.as_str()),
+                            table_name.as_str(),
+                            column_name.as_str(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let has_exclusions = exclusions.iter().any(|exclusion| exclusion.is_some());
+                let statement = query.trim_start_matches('\n').to_string();
+                let transformed_statement =
+                    exclude_from_copy_statement(statement.as_str(), &column_names, &exclusions);
+
+                copy_block = Some(Arc::new(CopyBlock {
+                    skip: transformation
+                        .skip_tables_map
+                        .contains_key(&format!("{}.{}", database_name, table_name)),
+                    database_name,
+                    table_name,
+                    column_names,
+                    column_types,
+                    transformed_statement,
+                    statement,
+                    has_transformers,
+                    has_filters,
+                    exclusions,
+                    has_exclusions,
+                }));
+                copy_rows_size = 0;
             }
             RowType::Others => {
-                // other rows than `INSERT INTO ...` and `CREATE TABLE ...`
-                no_change_query_callback(query_callback.borrow_mut(), query);
+                // other rows than `INSERT INTO ...` and `CREATE TABLE ...` - without the indexes, sequence ownerships
+                // and comments on the columns dropped from the schema
+                if !transformation
+                    .excluded_columns
+                    .references_dropped_column(query)
+                {
+                    send(no_change_statement(query));
+                }
             }
         }
 
         ListQueryResult::Continue
     }) {
-        Ok(_) => {}
-        Err(err) => panic!("{:?}", err),
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    }
+}
+
+/// tokenize and transform a statement - called by the workers
+fn transform_statement(
+    statement: Statement,
+    transformation: &Transformation,
+) -> TransformedStatement {
+    match statement {
+        Statement::InsertInto {
+            query,
+            column_types_by_db_and_table,
+        } => TransformedStatement::Query(transform_insert_into(
+            query.as_str(),
+            &column_types_by_db_and_table,
+            transformation,
+        )),
+        Statement::CopyRows { block, rows } => {
+            match transform_copy_rows(&block, rows, transformation) {
+                Ok(rows) => TransformedStatement::CopyRows { block, rows },
+                Err(err) => TransformedStatement::Error(err),
+            }
+        }
+        Statement::EndOfCopy(block) => TransformedStatement::EndOfCopy(block),
+        Statement::Query(original_query, query) => {
+            TransformedStatement::Query(Some((original_query, query)))
+        }
+    }
+}
+
+fn transform_insert_into(
+    query: &str,
+    column_types_by_db_and_table: &HashMap<String, HashMap<String, ColumnType>>,
+    transformation: &Transformation,
+) -> Option<(OriginalQuery, Query)> {
+    let tokens = get_tokens_from_query_str(query);
+
+    let (database_name, table_name) = match get_row_type(&tokens) {
+        RowType::InsertInto {
+            database_name,
+            table_name,
+        } => (database_name, table_name),
+        // the query only starts like an `INSERT INTO ...` query
+        _ => {
+            return Some((
+                Query(query.as_bytes().to_vec()),
+                Query(query.as_bytes().to_vec()),
+            ))
+        }
+    };
+
+    if transformation
+        .skip_tables_map
+        .contains_key(&format!("{}.{}", database_name, table_name))
+    {
+        return None;
+    }
+
+    let (original_columns, columns) = transform_columns(
+        database_name.as_str(),
+        table_name.as_str(),
+        &tokens,
+        column_types_by_db_and_table.get(&format!("{}.{}", database_name, table_name)),
+        &transformation.transformer_by_db_and_table_and_column_name,
+    );
+
+    let keep_row = transformation.row_filters.keep_row(
+        Some(database_name.as_str()),
+        table_name.as_str(),
+        |column_name| {
+            original_columns
+                .iter()
+                .find(|column| column.name() == column_name)
+                .and_then(to_text)
+        },
+    );
+
+    if !keep_row {
+        return None;
+    }
+
+    let columns = transformation.excluded_columns.exclude_columns(
+        Some(database_name.as_str()),
+        table_name.as_str(),
+        columns,
+    );
+
+    Some((
+        to_query(
+            Some(database_name.as_str()),
+            InsertIntoQuery {
+                table_name: table_name.to_string(),
+                columns: original_columns,
+            },
+        ),
+        to_query(
+            Some(database_name.as_str()),
+            InsertIntoQuery {
+                table_name,
+                columns,
+            },
+        ),
+    ))
+}
+
+/// filter and transform the data rows of a `COPY ... FROM stdin;` block - the original row is kept with each transformed row
+fn transform_copy_rows(
+    block: &CopyBlock,
+    rows: Vec<String>,
+    transformation: &Transformation,
+) -> Result<Vec<(String, String)>, Error> {
+    let mut transformed_rows = Vec::with_capacity(rows.len());
+
+    for original_row in rows {
+        if block.has_filters {
+            let values = get_column_values_from_copy_row(original_row.as_str())?;
+
+            let keep_row = transformation.row_filters.keep_row(
+                Some(block.database_name.as_str()),
+                block.table_name.as_str(),
+                |column_name| {
+                    block
+                        .column_names
+                        .iter()
+                        .position(|name| name == column_name)
+                        .and_then(|idx| values.get(idx).cloned().flatten())
+                },
+            );
+
+            if !keep_row {
+                continue;
+            }
+        }
+
+        let row = if block.has_transformers {
+            transform_copy_row(
+                block.database_name.as_str(),
+                block.table_name.as_str(),
+                &block.column_names,
+                &block.column_types,
+                original_row.as_str(),
+                &transformation.transformer_by_db_and_table_and_column_name,
+            )?
+        } else {
+            original_row.clone()
+        };
+
+        let row = if block.has_exclusions {
+            exclude_from_copy_row(row.as_str(), &block.exclusions)
+        } else {
+            row
+        };
+
+        transformed_rows.push((original_row, row));
     }
+
+    Ok(transformed_rows)
+}
+
+/// cheap check of the first keyword - the `INSERT INTO ...` queries are tokenized by the workers
+fn starts_with_insert(query: &str) -> bool {
+    matches!(query.get(..6), Some(keyword) if keyword.eq_ignore_ascii_case("insert"))
 }
 
-fn no_change_query_callback<F: FnMut(OriginalQuery, Query)>(query_callback: &mut F, query: &str) {
-    query_callback(
+fn no_change_statement(query: &str) -> Statement {
+    Statement::Query(
         // there is no diff between the original and the modified one
         Query(query.as_bytes().to_vec()),
         Query(query.as_bytes().to_vec()),
-    );
+    )
 }
 
 fn transform_columns(
     database_name: &str,
     table_name: &str,
     tokens: &Vec<Token>,
+    column_types: Option<&HashMap<String, ColumnType>>,
     transformer_by_db_and_table_and_column_name: &HashMap<String, &Box<dyn Transformer>>,
 ) -> (Vec<Column>, Vec<Column>) {
     // find database name by filtering out all queries starting with
@@ -299,64 +856,245 @@ fn transform_columns(
     assert_eq!(column_names.len(), column_values.len(), "Column names do not match values: got {} names and {} values", column_names.len(), column_values.len());
 
     let mut original_columns = vec![];
-    let mut columns = vec![];
 
     for (i, column_name) in column_names.iter().enumerate() {
         let value_token = column_values.get(i).unwrap();
+        let column_type = get_column_type(column_types, column_name.as_str());
 
-        let column = match value_token {
-            Token::Number(column_value, _) => {
-                if column_value.contains(".") {
-                    Column::FloatNumberValue(
-                        column_name.to_string(),
-                        column_value.parse::<f64>().unwrap(),
-                    )
-                } else {
-                    Column::NumberValue(
-                        column_name.to_string(),
-                        column_value.parse::<i128>().unwrap(),
-                    )
-                }
-            }
-            Token::Char(column_value) => {
-                Column::CharValue(column_name.to_string(), column_value.clone())
-            }
-            Token::SingleQuotedString(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::NationalStringLiteral(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::HexStringLiteral(column_value) => {
-                Column::StringValue(column_name.to_string(), column_value.clone())
-            }
-            Token::Word(w)
-                if (w.value == "true" || w.value == "false")
-                    && w.quote_style == None
-                    && w.keyword == NoKeyword =>
-            {
-                Column::BooleanValue(column_name.to_string(), w.value.parse::<bool>().unwrap())
+        original_columns.push(to_column(column_name, column_type, value_token));
+    }
+
+    let columns = transform_row(
+        database_name,
+        table_name,
+        &original_columns,
+        transformer_by_db_and_table_and_column_name,
+    );
+
+    (original_columns, columns)
+}
+
+fn get_column_type(
+    column_types: Option<&HashMap<String, ColumnType>>,
+    column_name: &str,
+) -> ColumnType {
+    column_types
+        .and_then(|column_types| column_types.get(column_name))
+        .copied()
+        .unwrap_or(ColumnType::Unknown)
+}
+
+/// type the value of an `INSERT INTO ...` query from its token and the type of its column
+pub(crate) fn to_column(column_name: &str, column_type: ColumnType, value_token: &Token) -> Column {
+    let column_name = column_name.to_string();
+
+    match value_token {
+        Token::Number(column_value, _) => to_number_column(column_name, column_value),
+        Token::Char(column_value) => Column::CharValue(column_name, column_value.clone()),
+        Token::SingleQuotedString(column_value) => {
+            // quotes are escaped as '' by the tokenizer
+            to_string_column(column_name, column_type, column_value.replace("''", "'"))
+        }
+        Token::NationalStringLiteral(column_value) => {
+            Column::StringValue(column_name, column_value.replace("''", "'"))
+        }
+        Token::HexStringLiteral(column_value) => {
+            Column::RawValue(column_name, format!("X'{}'", column_value))
+        }
+        Token::Word(w) if w.keyword == Null => Column::None(column_name),
+        Token::Word(w)
+            if (w.value == "true" || w.value == "false")
+                && w.quote_style == None
+                && w.keyword == NoKeyword =>
+        {
+            Column::BooleanValue(column_name, w.value.parse::<bool>().unwrap())
+        }
+        Token::Word(w) => Column::RawValue(
+            column_name,
+            match w.quote_style {
+                Some(quote) => format!("{}{}{}", quote, w.value, quote),
+                None => w.value.clone(),
+            },
+        ),
+        _ => Column::None(column_name),
+    }
+}
+
+/// a value is a number only if it can be written back as it is - e.g. `1.50` is kept as a raw value
+fn to_number_column(column_name: String, column_value: &str) -> Column {
+    if let Ok(value) = column_value.parse::<i128>() {
+        if value.to_string() == column_value {
+            return Column::NumberValue(column_name, value);
+        }
+    }
+
+    if let Ok(value) = column_value.parse::<f64>() {
+        if value.is_finite() && value.to_string() == column_value {
+            return Column::FloatNumberValue(column_name, value);
+        }
+    }
+
+    Column::RawValue(column_name, column_value.to_string())
+}
+
+/// type a string value from the type of its column - bytea values are hex encoded (`\x0102`)
+fn to_string_column(column_name: String, column_type: ColumnType, column_value: String) -> Column {
+    match column_type {
+        ColumnType::Date => Column::DateValue(column_name, column_value),
+        ColumnType::Json => Column::JsonValue(column_name, column_value),
+        ColumnType::Uuid => Column::UuidValue(column_name, column_value),
+        ColumnType::Array => Column::ArrayValue(column_name, column_value),
+        ColumnType::Bytes => {
+            let bytes = column_value
+                .strip_prefix("\\x")
+                .filter(|hex| hex.len() % 2 == 0)
+                .and_then(|hex| decode_hex(hex).ok());
+
+            match bytes {
+                Some(bytes) => Column::BytesValue(column_name, bytes),
+                // escape format
+                None => Column::StringValue(column_name, column_value),
             }
-            _ => Column::None(column_name.to_string()),
-        };
+        }
+        _ => Column::StringValue(column_name, column_value),
+    }
+}
 
-        // get the right transformer for the right column name
-        let original_column = column.clone();
+fn transform_row(
+    database_name: &str,
+    table_name: &str,
+    original_columns: &Vec<Column>,
+    transformer_by_db_and_table_and_column_name: &HashMap<String, &Box<dyn Transformer>>,
+) -> Vec<Column> {
+    // transformers can read the other columns of the row, so the row is parsed before being transformed
+    let mut columns = vec![];
 
+    for column in original_columns {
+        // get the right transformer for the right column name
         let db_and_table_and_column_name =
-            format!("{}.{}.{}", database_name, table_name, *column_name);
+            format!("{}.{}.{}", database_name, table_name, column.name());
         let column = match transformer_by_db_and_table_and_column_name
             .get(db_and_table_and_column_name.as_str())
         {
-            Some(transformer) => transformer.transform(column), // apply transformation on the column
-            None => column,
+            Some(transformer) => transformer.transform_with_row(column.clone(), original_columns), // apply transformation on the column
+            None => column.clone(),
         };
 
-        original_columns.push(original_column);
         columns.push(column);
     }
 
-    (original_columns, columns)
+    columns
+}
+
+/// parse, transform and re-encode a data row of a `COPY ... FROM stdin;` block
+fn transform_copy_row(
+    database_name: &str,
+    table_name: &str,
+    column_names: &Vec<String>,
+    column_types: &Vec<ColumnType>,
+    row: &str,
+    transformer_by_db_and_table_and_column_name: &HashMap<String, &Box<dyn Transformer>>,
+) -> Result<String, Error> {
+    let column_values = get_column_values_from_copy_row(row)?;
+    assert_eq!(column_names.len(), column_values.len(), "Column names do not match values: got {} names and {} values", column_names.len(), column_values.len());
+
+    let original_columns = column_names
+        .iter()
+        .zip(column_types)
+        .zip(column_values)
+        .map(|((column_name, column_type), column_value)| {
+            to_copy_column(column_name, *column_type, column_value)
+        })
+        .collect::<Vec<_>>();
+
+    let columns = transform_row(
+        database_name,
+        table_name,
+        &original_columns,
+        transformer_by_db_and_table_and_column_name,
+    );
+
+    Ok(to_copy_row(columns))
+}
+
+/// COPY values are untyped - strings are typed from the column type (if the table has been created in the dump),
+/// and a value is a number or a boolean only if it can be written back as it is
+fn to_copy_column(
+    column_name: &str,
+    column_type: ColumnType,
+    column_value: Option<String>,
+) -> Column {
+    let column_value = match column_value {
+        Some(column_value) => column_value,
+        None => return Column::None(column_name.to_string()),
+    };
+
+    match column_type {
+        ColumnType::Number
+        | ColumnType::FloatNumber
+        | ColumnType::Boolean
+        | ColumnType::Unknown => {}
+        column_type => return to_string_column(column_name.to_string(), column_type, column_value),
+    }
+
+    if let Ok(value) = column_value.parse::<i128>() {
+        if value.to_string() == column_value {
+            return Column::NumberValue(column_name.to_string(), value);
+        }
+    }
+
+    if let Ok(value) = column_value.parse::<f64>() {
+        if value.is_finite() && value.to_string() == column_value {
+            return Column::FloatNumberValue(column_name.to_string(), value);
+        }
+    }
+
+    match column_value.as_str() {
+        "t" => Column::BooleanValue(column_name.to_string(), true),
+        "f" => Column::BooleanValue(column_name.to_string(), false),
+        _ => Column::StringValue(column_name.to_string(), column_value),
+    }
+}
+
+fn to_copy_row(columns: Vec<Column>) -> String {
+    columns
+        .into_iter()
+        .map(|column| match column {
+            Column::NumberValue(_, value) => value.to_string(),
+            Column::FloatNumberValue(_, value) => value.to_string(),
+            Column::StringValue(_, value) => escape_copy_value(value.as_str()),
+            Column::CharValue(_, value) => escape_copy_value(value.to_string().as_str()),
+            Column::BooleanValue(_, value) => if value { "t" } else { "f" }.to_string(),
+            Column::DateValue(_, value) => escape_copy_value(value.as_str()),
+            Column::JsonValue(_, value) => escape_copy_value(value.as_str()),
+            Column::UuidValue(_, value) => escape_copy_value(value.as_str()),
+            Column::BytesValue(_, value) => {
+                escape_copy_value(format!("\\x{}", encode_hex(&value)).as_str())
+            }
+            Column::ArrayValue(_, value) => escape_copy_value(value.as_str()),
+            Column::RawValue(_, value) => value,
+            Column::None(_) => "\\N".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+fn to_copy_query(statement: &str, rows: &Vec<String>) -> Query {
+    let mut query_string = String::with_capacity(
+        statement.len() + rows.iter().map(|row| row.len() + 1).sum::<usize>() + 3,
+    );
+
+    query_string.push_str(statement);
+    query_string.push('\n');
+
+    for row in rows {
+        query_string.push_str(row.as_str());
+        query_string.push('\n');
+    }
+
+    query_string.push_str(END_OF_COPY_DATA);
+
+    Query(query_string.into_bytes())
 }
 
 fn is_insert_into_statement(tokens: &Vec<Token>) -> bool {
@@ -374,7 +1112,7 @@ fn is_alter_table_statement(tokens: &Vec<Token>) -> bool {
         && match_keyword_at_position(Keyword::Table, &tokens, 2)
 }
 
-fn get_row_type(tokens: &Vec<Token>) -> RowType {
+pub(crate) fn get_row_type(tokens: &Vec<Token>) -> RowType {
     let mut row_type = RowType::Others;
 
     if is_insert_into_statement(&tokens) {
@@ -422,6 +1160,24 @@ fn get_row_type(tokens: &Vec<Token>) -> RowType {
         }
     }
 
+    if is_copy_from_stdin_query(&tokens) {
+        // COPY <database>.<table> (...) FROM stdin;
+        let (database_name, table_name) = match tokens.get(3) {
+            Some(Token::Period) => (
+                get_word_value_at_position(&tokens, 2),
+                get_word_value_at_position(&tokens, 4),
+            ),
+            _ => (Some("public"), get_word_value_at_position(&tokens, 2)),
+        };
+
+        if let (Some(database_name), Some(table_name)) = (database_name, table_name) {
+            row_type = RowType::CopyFrom {
+                database_name: database_name.to_string(),
+                table_name: table_name.to_string(),
+            };
+        }
+    }
+
     row_type
 }
 
@@ -451,6 +1207,21 @@ fn to_query(database: Option<&str>, query: InsertIntoQuery) -> Query {
                 column_names.push(column_name);
                 values.push(value.to_string());
             }
+            Column::DateValue(column_name, value)
+            | Column::JsonValue(column_name, value)
+            | Column::UuidValue(column_name, value)
+            | Column::ArrayValue(column_name, value) => {
+                column_names.push(column_name);
+                values.push(format!("'{}'", value.replace("'", "''")));
+            }
+            Column::BytesValue(column_name, value) => {
+                column_names.push(column_name);
+                values.push(format!("'\\x{}'", encode_hex(&value)));
+            }
+            Column::RawValue(column_name, value) => {
+                column_names.push(column_name);
+                values.push(value);
+            }
             Column::None(column_name) => {
                 column_names.push(column_name);
                 values.push("NULL".to_string());
@@ -477,19 +1248,27 @@ fn to_query(database: Option<&str>, query: InsertIntoQuery) -> Query {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::io::BufReader;
     use std::str;
     use std::vec;
 
+    use dump_parser::utils::ListQueryResult;
+
     use crate::config::{
         DatabaseSubsetConfig, DatabaseSubsetConfigStrategy, DatabaseSubsetConfigStrategyRandom,
-        SkipConfig,
+        ExcludeColumnsConfig, ExcludedValueConfig, FilterConditionConfig, FilterConfig, PredicateValueConfig, PredicatesConfig,
+        SafeColumnsConfig, SkipConfig, StrictConfig,
+    };
+    use crate::source::postgres::{
+        read_and_transform, read_and_transform_until, to_copy_column, to_copy_row, to_query,
+        CopyRows, Postgres,
     };
-    use crate::source::postgres::{to_query, Postgres};
     use crate::source::SourceOptions;
     use crate::transformer::random::RandomTransformer;
+    use crate::transformer::redacted::{RedactedTransformer, RedactedTransformerOptions};
     use crate::transformer::transient::TransientTransformer;
     use crate::transformer::Transformer;
-    use crate::types::{Column, InsertIntoQuery};
+    use crate::types::{Column, ColumnType, InsertIntoQuery};
     use crate::Source;
 
     fn get_postgres() -> Postgres<'static> {
@@ -510,6 +1289,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         assert!(p.read(source_options, |original_query, query| {}).is_ok());
@@ -522,6 +1305,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         assert!(p.read(source_options, |original_query, query| {}).is_err());
@@ -537,6 +1324,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         let _ = p.read(source_options, |original_query, query| {
@@ -642,6 +1433,700 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_copy_row() {
+        let columns = vec![
+            to_copy_column("id", ColumnType::Unknown, Some("42".to_string())),
+            to_copy_column("height", ColumnType::Unknown, Some("1.78".to_string())),
+            to_copy_column("zip_code", ColumnType::Unknown, Some("01234".to_string())),
+            to_copy_column("is_valid", ColumnType::Unknown, Some("t".to_string())),
+            to_copy_column(
+                "notes",
+                ColumnType::Unknown,
+                Some("first\tline\nsecond \\ line".to_string()),
+            ),
+            to_copy_column("last_name", ColumnType::Unknown, None),
+        ];
+
+        assert_eq!(columns[0].number_value(), Some(&42));
+        assert_eq!(columns[1].float_number_value(), Some(&1.78));
+        assert_eq!(columns[2].string_value(), Some("01234"));
+        assert!(matches!(columns[3], Column::BooleanValue(_, true)));
+        assert!(matches!(columns[5], Column::None(_)));
+
+        assert_eq!(
+            to_copy_row(columns),
+            "42\t1.78\t01234\tt\tfirst\\tline\\nsecond \\\\ line\t\\N"
+        );
+
+        let columns = vec![
+            to_copy_column("code", ColumnType::String, Some("42".to_string())),
+            to_copy_column("created_at", ColumnType::Date, Some("2022-05-01".to_string())),
+            to_copy_column("picture", ColumnType::Bytes, Some("\\x01ab".to_string())),
+            to_copy_column("tags", ColumnType::Array, Some("{a,b}".to_string())),
+        ];
+
+        assert_eq!(columns[0].string_value(), Some("42"));
+        assert_eq!(columns[1].date_value(), Some("2022-05-01"));
+        assert_eq!(columns[2].bytes_value(), Some(&[0x01, 0xab][..]));
+        assert_eq!(columns[3].array_value(), Some("{a,b}"));
+
+        assert_eq!(to_copy_row(columns), "42\t2022-05-01\t\\\\x01ab\t{a,b}");
+    }
+
+    #[test]
+    fn test_copy_rows_size() {
+        // the batches are cut on the original rows, whatever the transformed values
+        let mut copy_rows = CopyRows::default();
+        copy_rows.push("1\tJohn".to_string(), "1\tJohn Doe the 3rd".to_string());
+        copy_rows.push("2\tPaul".to_string(), "2\t".to_string());
+
+        assert_eq!(copy_rows.size, 12);
+    }
+
+    #[test]
+    fn read_and_transform_typed_columns() {
+        let dump = r#"CREATE TABLE public.customers (
+    customer_id uuid NOT NULL,
+    contact_name character varying(30),
+    preferences jsonb,
+    picture bytea,
+    tags text[],
+    balance numeric(10,2),
+    created_at timestamp with time zone DEFAULT now()
+);
+
+INSERT INTO public.customers (customer_id, contact_name, preferences, picture, tags, balance, created_at) VALUES ('0a1b2c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d', 'Maria O''Neil', '{"lang": "fr"}', '\x0102ff', '{vip,"early adopter"}', 12.50, '2022-05-01 10:00:00+02');
+INSERT INTO public.customers (customer_id, contact_name, preferences, picture, tags, balance, created_at) VALUES ('1a1b2c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d', NULL, NULL, NULL, NULL, NULL, DEFAULT);
+"#;
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            },
+        )
+        .unwrap();
+
+        let insert_queries = queries
+            .iter()
+            .filter(|(_, query)| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 2);
+
+        // values are written back as they are in the dump
+        for (original_query, query) in insert_queries {
+            assert_eq!(original_query, query);
+            assert!(dump.contains(query.as_str()));
+        }
+    }
+
+    #[test]
+    fn read_and_transform_copy_blocks() {
+        let dump = r#"CREATE TABLE public.employees (
+    employee_id smallint NOT NULL,
+    last_name character varying(20) NOT NULL,
+    notes text
+);
+
+COPY public.employees (employee_id, last_name, notes) FROM stdin;
+1	Davolio	Education includes\na BA; in psychology
+2	Fuller	\N
+\.
+
+COPY public.territories (territory_id, territory_description) FROM stdin;
+01581	Westboro
+\.
+
+ALTER TABLE ONLY public.employees
+    ADD CONSTRAINT pk_employees PRIMARY KEY (employee_id);
+"#;
+
+        let t1: Box<dyn Transformer> = Box::new(RedactedTransformer::new(
+            "public",
+            "employees",
+            "last_name",
+            RedactedTransformerOptions::default(),
+        ));
+
+        let transformers = vec![t1];
+        let skip_config = vec![SkipConfig {
+            database: "public".to_string(),
+            table: "territories".to_string(),
+        }];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &skip_config,
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            },
+        )
+        .unwrap();
+
+        let copy_queries = queries
+            .iter()
+            .filter(|(_, query)| query.starts_with("COPY"))
+            .collect::<Vec<_>>();
+
+        // the territories table is skipped
+        assert_eq!(copy_queries.len(), 1);
+
+        let (original_query, query) = copy_queries[0];
+        assert_eq!(
+            original_query,
+            "COPY public.employees (employee_id, last_name, notes) FROM stdin;\n\
+            1\tDavolio\tEducation includes\\na BA; in psychology\n\
+            2\tFuller\t\\N\n\
+            \\."
+        );
+        assert_eq!(
+            query,
+            "COPY public.employees (employee_id, last_name, notes) FROM stdin;\n\
+            1\tDav**********\tEducation includes\\na BA; in psychology\n\
+            2\tFul**********\t\\N\n\
+            \\."
+        );
+
+        assert!(queries
+            .iter()
+            .any(|(_, query)| query.starts_with("ALTER TABLE ONLY public.employees")));
+    }
+
+    #[test]
+    fn read_and_transform_copy_blocks_with_invalid_escaped_bytes() {
+        // 0xe9 is not a valid UTF-8 character on its own
+        let dump = r#"COPY public.employees (employee_id, last_name) FROM stdin;
+1	Davolio
+2	Caf\351
+\.
+
+ALTER TABLE ONLY public.employees
+    ADD CONSTRAINT pk_employees PRIMARY KEY (employee_id);
+"#;
+
+        for workers in [1, 4] {
+            let t1: Box<dyn Transformer> = Box::new(RedactedTransformer::new(
+                "public",
+                "employees",
+                "last_name",
+                RedactedTransformerOptions::default(),
+            ));
+
+            let transformers = vec![t1];
+            let source_options = SourceOptions {
+                transformers: &transformers,
+                skip_config: &vec![],
+                database_subset: &None,
+                only_tables: &vec![],
+                filters: &vec![],
+                exclude_columns: &vec![],
+                strict: &None,
+                workers,
+            };
+
+            let mut queries = vec![];
+            let result = read_and_transform(
+                BufReader::new(dump.as_bytes()),
+                source_options,
+                |_, query| queries.push(query),
+            );
+
+            assert!(result.is_err());
+            // the dump stops at the row which can't be transformed
+            assert!(queries.is_empty());
+        }
+    }
+
+    #[test]
+    fn read_and_transform_with_filters() {
+        let dump = r#"INSERT INTO public.events (id, created_at) VALUES (1, '2020-01-01 10:00:00');
+INSERT INTO public.events (id, created_at) VALUES (2, '2022-05-01 10:00:00');
+
+COPY public.audit_log (id, type) FROM stdin;
+1	login
+2	update
+3	\N
+\.
+"#;
+
+        let filters = vec![
+            FilterConfig {
+                database: "public".to_string(),
+                table: "events".to_string(),
+                keep_rows: Some(vec![FilterConditionConfig {
+                    column: "created_at".to_string(),
+                    predicates: PredicatesConfig {
+                        gt: Some(PredicateValueConfig::String("2022-01-01".to_string())),
+                        ..Default::default()
+                    },
+                    is_null: None,
+                }]),
+                drop_rows: None,
+            },
+            FilterConfig {
+                database: "public".to_string(),
+                table: "audit_log".to_string(),
+                keep_rows: None,
+                drop_rows: Some(vec![FilterConditionConfig {
+                    column: "type".to_string(),
+                    predicates: PredicatesConfig {
+                        eq: Some(PredicateValueConfig::String("login".to_string())),
+                        ..Default::default()
+                    },
+                    is_null: None,
+                }]),
+            },
+        ];
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &filters,
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |_, query| queries.push(str::from_utf8(query.data()).unwrap().to_string()),
+        )
+        .unwrap();
+
+        let insert_queries = queries
+            .iter()
+            .filter(|query| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 1);
+        assert!(insert_queries[0].contains("'2022-05-01 10:00:00'"));
+
+        let copy_queries = queries
+            .iter()
+            .filter(|query| query.starts_with("COPY"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(copy_queries.len(), 1);
+        assert_eq!(
+            copy_queries[0],
+            "COPY public.audit_log (id, type) FROM stdin;\n\
+            2\tupdate\n\
+            3\t\\N\n\
+            \\."
+        );
+    }
+
+    #[test]
+    fn read_and_transform_with_workers() {
+        let mut dump =
+            "CREATE TABLE public.users (\n    id integer NOT NULL,\n    name text\n);\n\n"
+                .to_string();
+        for id in 0..500 {
+            dump.push_str(
+                format!(
+                    "INSERT INTO public.users (id, name) VALUES ({}, 'user {}');\n",
+                    id, id
+                )
+                .as_str(),
+            );
+        }
+
+        // the COPY rows are sent to the workers by several batches
+        dump.push_str("\nCOPY public.users (id, name) FROM stdin;\n");
+        for id in 500..100_000 {
+            dump.push_str(format!("{}\tuser {}\n", id, id).as_str());
+        }
+        dump.push_str(
+            "\\.\n\nALTER TABLE ONLY public.users\n    ADD CONSTRAINT pk_users PRIMARY KEY (id);\n",
+        );
+
+        let filters = vec![FilterConfig {
+            database: "public".to_string(),
+            table: "users".to_string(),
+            keep_rows: None,
+            drop_rows: Some(vec![FilterConditionConfig {
+                column: "name".to_string(),
+                predicates: PredicatesConfig {
+                    eq: Some(PredicateValueConfig::String("user 42".to_string())),
+                    ..Default::default()
+                },
+                is_null: None,
+            }]),
+        }];
+
+        let read = |workers: usize| {
+            let t1: Box<dyn Transformer> = Box::new(RedactedTransformer::new(
+                "public",
+                "users",
+                "name",
+                RedactedTransformerOptions::default(),
+            ));
+            let transformers = vec![t1];
+
+            let source_options = SourceOptions {
+                transformers: &transformers,
+                skip_config: &vec![],
+                database_subset: &None,
+                only_tables: &vec![],
+                filters: &filters,
+                exclude_columns: &vec![],
+                strict: &None,
+                workers,
+            };
+
+            let mut queries = vec![];
+            read_and_transform(
+                BufReader::new(dump.as_bytes()),
+                source_options,
+                |_, query| queries.push(str::from_utf8(query.data()).unwrap().to_string()),
+            )
+            .unwrap();
+
+            queries
+        };
+
+        let queries = read(1);
+        let insert_queries = queries
+            .iter()
+            .filter(|query| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 499);
+        assert!(insert_queries[0].starts_with("INSERT INTO public.users (id, name) VALUES (0, "));
+        assert!(!insert_queries[0].contains("'user 0'"));
+        assert!(queries
+            .iter()
+            .rev()
+            .find(|query| query.as_str() != "\n")
+            .unwrap()
+            .contains("ADD CONSTRAINT pk_users"));
+
+        // the statements are sent in the order of the dump
+        assert_eq!(read(4), queries);
+    }
+
+    #[test]
+    fn read_and_transform_until_break() {
+        let mut dump =
+            "CREATE TABLE public.users (\n    id integer NOT NULL,\n    name text\n);\n\n"
+                .to_string();
+        for id in 0..500 {
+            dump.push_str(
+                format!(
+                    "INSERT INTO public.users (id, name) VALUES ({}, 'user {}');\n",
+                    id, id
+                )
+                .as_str(),
+            );
+        }
+
+        dump.push_str("\nCOPY public.users (id, name) FROM stdin;\n");
+        for id in 500..100_000 {
+            dump.push_str(format!("{}\tuser {}\n", id, id).as_str());
+        }
+        dump.push_str("\\.\n");
+
+        let read = |workers: usize, last_query: &str| {
+            let transformers = vec![];
+            let source_options = SourceOptions {
+                transformers: &transformers,
+                skip_config: &vec![],
+                database_subset: &None,
+                only_tables: &vec![],
+                filters: &vec![],
+                exclude_columns: &vec![],
+                strict: &None,
+                workers,
+            };
+
+            let mut queries = vec![];
+            read_and_transform_until(
+                BufReader::new(dump.as_bytes()),
+                source_options,
+                |_, query| {
+                    let query = str::from_utf8(query.data()).unwrap().to_string();
+                    let is_last_query = query.starts_with(last_query);
+                    queries.push(query);
+
+                    if is_last_query {
+                        ListQueryResult::Break
+                    } else {
+                        ListQueryResult::Continue
+                    }
+                },
+            )
+            .unwrap();
+
+            queries
+        };
+
+        for workers in [1, 4] {
+            // the next queries are not sent once the callback breaks
+            let queries = read(workers, "INSERT INTO public.users (id, name) VALUES (9, ");
+            assert!(queries.last().unwrap().contains("VALUES (9, 'user 9')"));
+            assert_eq!(
+                queries
+                    .iter()
+                    .filter(|query| query.starts_with("INSERT INTO"))
+                    .count(),
+                10
+            );
+
+            // the COPY rows are sent by several batches - the next batches are not sent
+            let queries = read(workers, "COPY public.users");
+            assert_eq!(
+                queries
+                    .iter()
+                    .filter(|query| query.starts_with("COPY"))
+                    .count(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn read_and_transform_with_excluded_columns() {
+        let dump = r#"CREATE TABLE public.documents (
+    id integer NOT NULL,
+    secret text,
+    status character varying(10) DEFAULT 'draft'::character varying,
+    document_blob bytea
+);
+
+INSERT INTO public.documents (id, secret, status, document_blob) VALUES (1, 's3cr3t', 'published', '\x0102');
+
+COPY public.documents (id, secret, status, document_blob) FROM stdin;
+2	s3cr3t	published	\\x0102
+\.
+
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_blob_key UNIQUE (document_blob);
+
+CREATE INDEX idx_documents_blob ON public.documents USING btree (document_blob);
+
+COMMENT ON COLUMN public.documents.document_blob IS 'the uploaded document';
+"#;
+
+        let exclude_columns = vec![
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["secret".to_string()],
+                replace_with: Some(ExcludedValueConfig::Null),
+                drop_from_schema: None,
+            },
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["status".to_string()],
+                replace_with: Some(ExcludedValueConfig::Default),
+                drop_from_schema: None,
+            },
+            ExcludeColumnsConfig {
+                database: "public".to_string(),
+                table: "documents".to_string(),
+                columns: vec!["document_blob".to_string()],
+                replace_with: None,
+                drop_from_schema: Some(true),
+            },
+        ];
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &exclude_columns,
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            },
+        )
+        .unwrap();
+
+        let (original_query, query) = queries
+            .iter()
+            .find(|(_, query)| query.starts_with("CREATE TABLE"))
+            .unwrap();
+        assert!(original_query.contains("document_blob bytea"));
+        assert_eq!(
+            query,
+            "CREATE TABLE public.documents (\n    \
+            id integer NOT NULL,\n    \
+            secret text,\n    \
+            status character varying(10) DEFAULT 'draft'::character varying\n\
+            );"
+        );
+
+        let (original_query, query) = queries
+            .iter()
+            .find(|(_, query)| query.starts_with("INSERT INTO"))
+            .unwrap();
+        assert!(original_query.contains("'s3cr3t'"));
+        assert_eq!(
+            query,
+            "INSERT INTO public.documents (id, secret) VALUES (1, NULL);"
+        );
+
+        let (_, query) = queries
+            .iter()
+            .find(|(_, query)| query.starts_with("COPY"))
+            .unwrap();
+        assert_eq!(
+            query,
+            "COPY public.documents (id, secret) FROM stdin;\n\
+            2\t\\N\n\
+            \\."
+        );
+
+        // the constraints, indexes and comments on the dropped column are dropped too
+        assert!(queries
+            .iter()
+            .any(|(_, query)| query.contains("ADD CONSTRAINT documents_pkey")));
+        assert!(!queries
+            .iter()
+            .any(|(_, query)| query.contains("document_blob")));
+    }
+
+    #[test]
+    fn read_and_transform_in_strict_mode() {
+        let dump = r#"CREATE TABLE public.employees (
+    employee_id smallint NOT NULL,
+    last_name character varying(20) NOT NULL,
+    notes text
+);
+
+COPY public.employees (employee_id, last_name, notes) FROM stdin;
+1	Davolio	\N
+\.
+
+COPY public.territories (territory_id, territory_description) FROM stdin;
+01581	Westboro
+\.
+"#;
+
+        let t1: Box<dyn Transformer> = Box::new(RedactedTransformer::new(
+            "public",
+            "employees",
+            "last_name",
+            RedactedTransformerOptions::default(),
+        ));
+
+        let transformers = vec![t1];
+        // skipped tables are not checked
+        let skip_config = vec![SkipConfig {
+            database: "public".to_string(),
+            table: "territories".to_string(),
+        }];
+
+        let strict_config = |columns: Vec<&str>| {
+            Some(StrictConfig {
+                safe_columns: Some(vec![SafeColumnsConfig {
+                    database: "public".to_string(),
+                    table: "employees".to_string(),
+                    columns: columns.into_iter().map(|c| c.to_string()).collect(),
+                }]),
+            })
+        };
+
+        let strict = strict_config(vec!["employee_id"]);
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &skip_config,
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &strict,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        let err = read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |_, query| queries.push(query),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().ends_with(": public.employees.notes"));
+        assert!(queries.is_empty());
+
+        let strict = strict_config(vec!["employee_id", "notes"]);
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &skip_config,
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &strict,
+            workers: 1,
+        };
+
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |_, query| queries.push(query),
+        )
+        .unwrap();
+
+        assert!(!queries.is_empty());
+    }
+
     #[test]
     fn list_rows_and_hide_last_name() {
         let p = get_postgres();
@@ -655,6 +2140,7 @@ mod tests {
             database_name,
             table_name,
             column_name_to_obfuscate,
+            None,
         ));
 
         let transformers = vec![t1, t2];
@@ -663,6 +2149,10 @@ mod tests {
             skip_config: &vec![],
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         let _ = p.read(source_options, |original_query, query| {
@@ -673,7 +2163,7 @@ mod tests {
 
             if query_str.contains(database_name)
                 && query_str.contains(table_name)
-                && query_str.starts_with("INSERT INTO")
+                && (query_str.starts_with("INSERT INTO") || query_str.starts_with("COPY"))
             {
                 assert_ne!(query.data(), original_query.data());
                 // TODO to complete to better check the column change only
@@ -704,6 +2194,10 @@ mod tests {
             skip_config: &skip_config,
             database_subset: &None,
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         let _ = p.read(source_options, |_original_query, query| {
@@ -750,11 +2244,20 @@ mod tests {
                 database: "public".to_string(),
                 table: "orders".to_string(),
                 strategy: DatabaseSubsetConfigStrategy::Random(
-                    DatabaseSubsetConfigStrategyRandom { percent: 50 },
+                    DatabaseSubsetConfigStrategyRandom {
+                        percent: Some(50),
+                        rows: None,
+                        seed: None,
+                    },
                 ),
                 passthrough_tables: None,
+                children_max_depth: None,
             }),
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         let mut rows_percent_50 = vec![];
@@ -787,11 +2290,20 @@ mod tests {
                 database: "public".to_string(),
                 table: "orders".to_string(),
                 strategy: DatabaseSubsetConfigStrategy::Random(
-                    DatabaseSubsetConfigStrategyRandom { percent: 30 },
+                    DatabaseSubsetConfigStrategyRandom {
+                        percent: Some(30),
+                        rows: None,
+                        seed: None,
+                    },
                 ),
                 passthrough_tables: None,
+                children_max_depth: None,
             }),
             only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
         };
 
         let mut rows_percent_30 = vec![];
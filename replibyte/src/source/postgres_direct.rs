@@ -0,0 +1,950 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, SyncSender};
+use std::thread;
+
+use postgres::{Client, Config, NoTls};
+
+use crate::config::OnlyTablesConfig;
+use crate::connector::Connector;
+use crate::source::postgres::{read_and_transform, END_OF_COPY_DATA};
+use crate::source::{Source, SourceOptions};
+use crate::types::{OriginalQuery, Query};
+
+/// size of the parts of the dump sent to the thread transforming it
+pub(crate) const DUMP_PART_SIZE: usize = 64 * 1024;
+/// number of parts of the dump read from the database and waiting to be transformed
+pub(crate) const DUMP_PARTS_IN_FLIGHT: usize = 16;
+
+/// statements at the top of a dump, as written by pg_dump
+const DUMP_HEADER: &str = "SET statement_timeout = 0;
+SET lock_timeout = 0;
+SET client_encoding = 'UTF8';
+SET standard_conforming_strings = on;
+SELECT pg_catalog.set_config('search_path', '', false);
+SET check_function_bodies = false;
+SET client_min_messages = warning;
+
+";
+
+const TABLES_QUERY: &str = "SELECT c.oid, n.nspname::text, c.relname::text, quote_ident(n.nspname),
+    quote_ident(n.nspname) || '.' || quote_ident(c.relname)
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE c.relkind = 'r'
+    AND NOT c.relispartition
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname !~ '^pg_(toast|temp_)'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend d
+        WHERE d.classid = 'pg_catalog.pg_class'::regclass AND d.objid = c.oid AND d.deptype = 'e'
+    )
+ORDER BY n.nspname, c.relname";
+
+const COLUMNS_QUERY: &str = "SELECT a.attrelid, quote_ident(a.attname),
+    pg_catalog.format_type(a.atttypid, a.atttypmod), a.attnotnull,
+    pg_catalog.pg_get_expr(d.adbin, d.adrelid), a.attgenerated = 's'
+FROM pg_catalog.pg_attribute a
+LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+WHERE a.attrelid = ANY($1) AND a.attnum > 0 AND NOT a.attisdropped
+ORDER BY a.attrelid, a.attnum";
+
+const SEQUENCES_QUERY: &str = "SELECT quote_ident(n.nspname) || '.' || quote_ident(c.relname),
+    quote_literal(quote_ident(n.nspname) || '.' || quote_ident(c.relname)),
+    s.seqtypid::regtype::text, s.seqstart, s.seqincrement, s.seqmin, s.seqmax, s.seqcache,
+    s.seqcycle, pg_catalog.pg_sequence_last_value(c.oid),
+    d.refobjid, quote_ident(a.attname), a.attidentity::text
+FROM pg_catalog.pg_sequence s
+JOIN pg_catalog.pg_class c ON c.oid = s.seqrelid
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+LEFT JOIN pg_catalog.pg_depend d ON d.classid = 'pg_catalog.pg_class'::regclass
+    AND d.objid = c.oid
+    AND d.refclassid = 'pg_catalog.pg_class'::regclass
+    AND d.deptype IN ('a', 'i')
+LEFT JOIN pg_catalog.pg_attribute a ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid
+ORDER BY n.nspname, c.relname";
+
+const CONSTRAINTS_QUERY: &str = "SELECT quote_ident(n.nspname) || '.' || quote_ident(c.relname),
+    quote_ident(con.conname), pg_catalog.pg_get_constraintdef(con.oid), con.contype = 'f'
+FROM pg_catalog.pg_constraint con
+JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE con.conrelid = ANY($1) AND con.contype IN ('p', 'u', 'c', 'f', 'x') AND con.conislocal
+ORDER BY n.nspname, c.relname, con.conname";
+
+const INDEXES_QUERY: &str = "SELECT pg_catalog.pg_get_indexdef(i.indexrelid)
+FROM pg_catalog.pg_index i
+JOIN pg_catalog.pg_class c ON c.oid = i.indexrelid
+WHERE i.indrelid = ANY($1)
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_constraint con
+        WHERE con.conindid = i.indexrelid AND con.contype IN ('p', 'u', 'x')
+    )
+ORDER BY c.relname";
+
+/// objects written by pg_dump but not read from the catalog - a dump without them can't be restored
+const UNSUPPORTED_OBJECTS_QUERY: &str = "SELECT CASE t.typtype WHEN 'd' THEN 'domain ' ELSE 'type ' END
+    || quote_ident(n.nspname) || '.' || quote_ident(t.typname)
+FROM pg_catalog.pg_type t
+JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+WHERE (t.typtype IN ('d', 'e', 'r') OR (t.typtype = 'c' AND c.relkind = 'c'))
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname !~ '^pg_(toast|temp_)'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend d
+        WHERE d.classid = 'pg_catalog.pg_type'::regclass AND d.objid = t.oid AND d.deptype = 'e'
+    )
+UNION ALL
+SELECT 'extension ' || quote_ident(e.extname)
+FROM pg_catalog.pg_extension e
+WHERE e.extname <> 'plpgsql'
+UNION ALL
+SELECT CASE c.relkind WHEN 'v' THEN 'view ' WHEN 'm' THEN 'materialized view ' ELSE 'partitioned table ' END
+    || quote_ident(n.nspname) || '.' || quote_ident(c.relname)
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE c.relkind IN ('v', 'm', 'p')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname !~ '^pg_(toast|temp_)'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend d
+        WHERE d.classid = 'pg_catalog.pg_class'::regclass AND d.objid = c.oid AND d.deptype = 'e'
+    )
+UNION ALL
+SELECT CASE p.prokind WHEN 'p' THEN 'procedure ' ELSE 'function ' END
+    || quote_ident(n.nspname) || '.' || quote_ident(p.proname)
+FROM pg_catalog.pg_proc p
+JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname !~ '^pg_(toast|temp_)'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend d
+        WHERE d.classid = 'pg_catalog.pg_proc'::regclass AND d.objid = p.oid AND d.deptype = 'e'
+    )
+ORDER BY 1";
+
+/// Source PostgreSQL database read over a connection, without the pg_dump binary.
+/// The schema is read from the catalog and the rows with `COPY ... TO STDOUT`, and written as pg_dump does.
+pub struct PostgresDirect<'a> {
+    host: &'a str,
+    port: u16,
+    database: &'a str,
+    username: &'a str,
+    password: &'a str,
+}
+
+impl<'a> PostgresDirect<'a> {
+    pub fn new(
+        host: &'a str,
+        port: u16,
+        database: &'a str,
+        username: &'a str,
+        password: &'a str,
+    ) -> Self {
+        PostgresDirect {
+            host,
+            port,
+            database,
+            username,
+            password,
+        }
+    }
+
+    fn connect(&self) -> Result<Client, Error> {
+        Config::new()
+            .host(self.host)
+            .port(self.port)
+            .user(self.username)
+            .password(self.password)
+            .dbname(self.database)
+            .connect(NoTls)
+            .map_err(to_error)
+    }
+}
+
+impl<'a> Connector for PostgresDirect<'a> {
+    fn init(&mut self) -> Result<(), Error> {
+        let mut client = self.connect()?;
+        let unsupported_objects = read_unsupported_objects(&mut client)?;
+
+        if !unsupported_objects.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "<source.reader: direct> can't dump the {} - use <source.reader: pg_dump>",
+                    unsupported_objects.join(", ")
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Source for PostgresDirect<'a> {
+    fn read<F: FnMut(OriginalQuery, Query)>(
+        &self,
+        options: SourceOptions,
+        query_callback: F,
+    ) -> Result<(), Error> {
+        if options.database_subset.is_some() {
+            // the subset is computed from the `INSERT INTO ...` queries of pg_dump
+            return Err(Error::new(
+                ErrorKind::Other,
+                "<source.database_subset> is not supported with <source.reader: direct>",
+            ));
+        }
+
+        let mut client = self.connect()?;
+
+        let only_tables = options.only_tables;
+
+        let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(DUMP_PARTS_IN_FLIGHT);
+
+        thread::scope(|scope| {
+            let dump_join_handle = scope.spawn(move || -> Result<(), Error> {
+                let mut writer = BufWriter::with_capacity(DUMP_PART_SIZE, DumpPartWriter(tx));
+                let _ = write_dump(&mut client, only_tables, &mut writer)?;
+                writer.flush()
+            });
+
+            let reader = BufReader::new(DumpPartReader::new(rx));
+            let read_result = read_and_transform(reader, options, query_callback);
+
+            // the dump ends early when the database can't be read - and it can't be written once the reader fails
+            read_result.and(dump_join_handle.join().unwrap())
+        })
+    }
+}
+
+/// table read from the catalog
+struct Table {
+    oid: u32,
+    schema_name: String,
+    table_name: String,
+    /// quoted schema name
+    schema: String,
+    /// quoted and qualified table name
+    name: String,
+    columns: Vec<TableColumn>,
+}
+
+struct TableColumn {
+    /// quoted column name
+    name: String,
+    sql_type: String,
+    not_null: bool,
+    /// default value - or expression of a generated column
+    default: Option<String>,
+    generated: bool,
+}
+
+impl Table {
+    fn create_statement(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                let mut definition = format!("    {} {}", column.name, column.sql_type);
+
+                if let (true, Some(expression)) = (column.generated, &column.default) {
+                    definition
+                        .push_str(format!(" GENERATED ALWAYS AS ({}) STORED", expression).as_str());
+                }
+
+                if column.not_null {
+                    definition.push_str(" NOT NULL");
+                }
+
+                definition
+            })
+            .collect::<Vec<_>>();
+
+        format!("CREATE TABLE {} (\n{}\n);", self.name, columns.join(",\n"))
+    }
+
+    /// defaults set once the sequences are created
+    fn set_default_statements(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .filter(|column| !column.generated)
+            .filter_map(|column| {
+                column.default.as_ref().map(|default| {
+                    format!(
+                        "ALTER TABLE ONLY {} ALTER COLUMN {} SET DEFAULT {};",
+                        self.name, column.name, default
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// the generated columns are not copied - they are computed on restore
+    fn copy_column_names(&self) -> String {
+        self.columns
+            .iter()
+            .filter(|column| !column.generated)
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// sequence read from the catalog
+struct Sequence {
+    /// quoted and qualified sequence name
+    name: String,
+    /// sequence name as a string literal
+    literal: String,
+    data_type: String,
+    start: i64,
+    increment: i64,
+    min: i64,
+    max: i64,
+    cache: i64,
+    cycle: bool,
+    last_value: Option<i64>,
+    owner: Option<SequenceOwner>,
+}
+
+/// column of a `serial` or an identity column owning a sequence
+struct SequenceOwner {
+    table_oid: u32,
+    /// quoted column name
+    column: String,
+    /// `a` (always) or `d` (by default) for an identity column - empty otherwise
+    identity: String,
+}
+
+impl Sequence {
+    fn options(&self) -> String {
+        format!(
+            "    START WITH {}\n    INCREMENT BY {}\n    MINVALUE {}\n    MAXVALUE {}\n    CACHE {}{}",
+            self.start,
+            self.increment,
+            self.min,
+            self.max,
+            self.cache,
+            if self.cycle { "\n    CYCLE" } else { "" }
+        )
+    }
+
+    /// the sequence of an identity column is created with its column
+    fn create_statements(&self, table: Option<&Table>) -> Vec<String> {
+        let (table, owner) = match (table, &self.owner) {
+            (Some(table), Some(owner)) => (table, owner),
+            _ => {
+                return vec![format!(
+                    "CREATE SEQUENCE {}\n    AS {}\n{};",
+                    self.name,
+                    self.data_type,
+                    self.options()
+                )];
+            }
+        };
+
+        let generated = match owner.identity.as_str() {
+            "a" => "ALWAYS",
+            "d" => "BY DEFAULT",
+            _ => {
+                return vec![
+                    format!(
+                        "CREATE SEQUENCE {}\n    AS {}\n{};",
+                        self.name,
+                        self.data_type,
+                        self.options()
+                    ),
+                    format!(
+                        "ALTER SEQUENCE {} OWNED BY {}.{};",
+                        self.name, table.name, owner.column
+                    ),
+                ];
+            }
+        };
+
+        vec![format!(
+            "ALTER TABLE ONLY {} ALTER COLUMN {} ADD GENERATED {} AS IDENTITY (\n    SEQUENCE NAME {}\n{}\n);",
+            table.name,
+            owner.column,
+            generated,
+            self.name,
+            self.options()
+        )]
+    }
+
+    /// None when no value was generated yet
+    fn set_value_statement(&self) -> Option<String> {
+        self.last_value.map(|last_value| {
+            format!(
+                "SELECT pg_catalog.setval({}, {}, true);",
+                self.literal, last_value
+            )
+        })
+    }
+}
+
+/// constraint added once the rows are copied
+struct Constraint {
+    /// quoted and qualified table name
+    table: String,
+    /// quoted constraint name
+    name: String,
+    definition: String,
+    foreign_key: bool,
+}
+
+impl Constraint {
+    fn add_statement(&self) -> String {
+        format!(
+            "ALTER TABLE ONLY {}\n    ADD CONSTRAINT {} {};",
+            self.table, self.name, self.definition
+        )
+    }
+}
+
+/// write the schema and the rows of the database as a plain pg_dump dump - from a single snapshot
+fn write_dump<W: Write>(
+    client: &mut Client,
+    only_tables: &[OnlyTablesConfig],
+    writer: &mut W,
+) -> Result<(), Error> {
+    // the definitions read from the catalog hold qualified names with an empty search path
+    client
+        .batch_execute(
+            "BEGIN ISOLATION LEVEL REPEATABLE READ, READ ONLY;
+            SELECT pg_catalog.set_config('search_path', '', false);",
+        )
+        .map_err(to_error)?;
+
+    let tables = read_tables(client, only_tables)?;
+    let table_oids = tables.iter().map(|table| table.oid).collect::<Vec<_>>();
+    let find_table = |oid: u32| tables.iter().find(|table| table.oid == oid);
+
+    // the sequences of the other tables are left out - as well as all those not owned when only some tables are dumped
+    let sequences = read_sequences(client)?
+        .into_iter()
+        .filter(|sequence| match &sequence.owner {
+            Some(owner) => find_table(owner.table_oid).is_some(),
+            None => only_tables.is_empty(),
+        })
+        .collect::<Vec<_>>();
+
+    let constraints = read_constraints(client, &table_oids)?;
+    let indexes = read_indexes(client, &table_oids)?;
+
+    writer.write_all(DUMP_HEADER.as_bytes())?;
+
+    let schemas = tables
+        .iter()
+        .map(|table| table.schema.as_str())
+        .filter(|schema| *schema != "public")
+        .collect::<BTreeSet<_>>();
+
+    for schema in schemas {
+        write_statement(writer, format!("CREATE SCHEMA {};", schema).as_str())?;
+    }
+
+    for table in &tables {
+        write_statement(writer, table.create_statement().as_str())?;
+    }
+
+    for sequence in &sequences {
+        let owner_table = sequence
+            .owner
+            .as_ref()
+            .and_then(|owner| find_table(owner.table_oid));
+
+        for statement in sequence.create_statements(owner_table) {
+            write_statement(writer, statement.as_str())?;
+        }
+    }
+
+    for table in &tables {
+        for statement in table.set_default_statements() {
+            write_statement(writer, statement.as_str())?;
+        }
+    }
+
+    // the rows of the skipped tables are dropped with their schema by the transformation
+    for table in &tables {
+        let column_names = table.copy_column_names();
+        writer.write_all(
+            format!("COPY {} ({}) FROM stdin;\n", table.name, column_names).as_bytes(),
+        )?;
+
+        let mut rows = client
+            .copy_out(format!("COPY {} ({}) TO STDOUT", table.name, column_names).as_str())
+            .map_err(to_error)?;
+
+        let _ = io::copy(&mut rows, writer)?;
+        writer.write_all(format!("{}\n\n\n", END_OF_COPY_DATA).as_bytes())?;
+    }
+
+    for statement in sequences.iter().filter_map(Sequence::set_value_statement) {
+        write_statement(writer, statement.as_str())?;
+    }
+
+    // the foreign keys reference the primary keys and the unique constraints
+    for constraint in constraints
+        .iter()
+        .filter(|constraint| !constraint.foreign_key)
+    {
+        write_statement(writer, constraint.add_statement().as_str())?;
+    }
+
+    for index in indexes {
+        write_statement(writer, format!("{};", index).as_str())?;
+    }
+
+    for constraint in constraints
+        .iter()
+        .filter(|constraint| constraint.foreign_key)
+    {
+        write_statement(writer, constraint.add_statement().as_str())?;
+    }
+
+    client.batch_execute("COMMIT;").map_err(to_error)
+}
+
+fn write_statement<W: Write>(writer: &mut W, statement: &str) -> Result<(), Error> {
+    writer.write_all(format!("{}\n\n\n", statement).as_bytes())
+}
+
+fn read_tables(client: &mut Client, only_tables: &[OnlyTablesConfig]) -> Result<Vec<Table>, Error> {
+    let mut tables = client
+        .query(TABLES_QUERY, &[])
+        .map_err(to_error)?
+        .into_iter()
+        .map(|row| Table {
+            oid: row.get(0),
+            schema_name: row.get(1),
+            table_name: row.get(2),
+            schema: row.get(3),
+            name: row.get(4),
+            columns: vec![],
+        })
+        .filter(|table| {
+            only_tables.is_empty()
+                || only_tables.iter().any(|only_table| {
+                    only_table.database == table.schema_name && only_table.table == table.table_name
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let table_oids = tables.iter().map(|table| table.oid).collect::<Vec<_>>();
+
+    for row in client
+        .query(COLUMNS_QUERY, &[&table_oids])
+        .map_err(to_error)?
+    {
+        let oid: u32 = row.get(0);
+
+        if let Some(table) = tables.iter_mut().find(|table| table.oid == oid) {
+            table.columns.push(TableColumn {
+                name: row.get(1),
+                sql_type: row.get(2),
+                not_null: row.get(3),
+                default: row.get(4),
+                generated: row.get(5),
+            });
+        }
+    }
+
+    Ok(tables)
+}
+
+fn read_sequences(client: &mut Client) -> Result<Vec<Sequence>, Error> {
+    let sequences = client
+        .query(SEQUENCES_QUERY, &[])
+        .map_err(to_error)?
+        .into_iter()
+        .map(|row| Sequence {
+            name: row.get(0),
+            literal: row.get(1),
+            data_type: row.get(2),
+            start: row.get(3),
+            increment: row.get(4),
+            min: row.get(5),
+            max: row.get(6),
+            cache: row.get(7),
+            cycle: row.get(8),
+            last_value: row.get(9),
+            owner: match (
+                row.get::<_, Option<u32>>(10),
+                row.get::<_, Option<String>>(11),
+                row.get::<_, Option<String>>(12),
+            ) {
+                (Some(table_oid), Some(column), identity) => Some(SequenceOwner {
+                    table_oid,
+                    column,
+                    identity: identity.unwrap_or_default(),
+                }),
+                _ => None,
+            },
+        })
+        .collect();
+
+    Ok(sequences)
+}
+
+fn read_constraints(client: &mut Client, table_oids: &[u32]) -> Result<Vec<Constraint>, Error> {
+    let constraints = client
+        .query(CONSTRAINTS_QUERY, &[&table_oids])
+        .map_err(to_error)?
+        .into_iter()
+        .map(|row| Constraint {
+            table: row.get(0),
+            name: row.get(1),
+            definition: row.get(2),
+            foreign_key: row.get(3),
+        })
+        .collect();
+
+    Ok(constraints)
+}
+
+fn read_indexes(client: &mut Client, table_oids: &[u32]) -> Result<Vec<String>, Error> {
+    let indexes = client
+        .query(INDEXES_QUERY, &[&table_oids])
+        .map_err(to_error)?
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect();
+
+    Ok(indexes)
+}
+
+fn read_unsupported_objects(client: &mut Client) -> Result<Vec<String>, Error> {
+    let objects = client
+        .query(UNSUPPORTED_OBJECTS_QUERY, &[])
+        .map_err(to_error)?
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect();
+
+    Ok(objects)
+}
+
+fn to_error(err: postgres::Error) -> Error {
+    Error::new(ErrorKind::Other, format!("{}", err))
+}
+
+/// sends the dump by parts to the thread transforming it
+pub(crate) struct DumpPartWriter(pub(crate) SyncSender<Vec<u8>>);
+
+impl Write for DumpPartWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.0.send(buf.to_vec()) {
+            Ok(_) => Ok(buf.len()),
+            Err(_) => Err(Error::new(
+                ErrorKind::BrokenPipe,
+                "the dump is not read anymore",
+            )),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// reads the parts of the dump until the thread reading the database is done
+pub(crate) struct DumpPartReader {
+    rx: Receiver<Vec<u8>>,
+    part: Vec<u8>,
+    position: usize,
+}
+
+impl DumpPartReader {
+    pub(crate) fn new(rx: Receiver<Vec<u8>>) -> Self {
+        DumpPartReader {
+            rx,
+            part: vec![],
+            position: 0,
+        }
+    }
+}
+
+impl Read for DumpPartReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.part.len() {
+            match self.rx.recv() {
+                Ok(part) => {
+                    self.part = part;
+                    self.position = 0;
+                }
+                Err(_) => return Ok(0),
+            }
+        }
+
+        let size = buf.len().min(self.part.len() - self.position);
+        buf[..size].copy_from_slice(&self.part[self.position..self.position + size]);
+        self.position += size;
+
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, BufWriter, Write};
+    use std::sync::mpsc;
+    use std::thread;
+
+    use dump_parser::postgres::{
+        get_column_types_from_create_table_query, get_tokens_from_query_str,
+    };
+
+    use crate::connector::Connector;
+    use crate::source::postgres::{get_row_type, RowType};
+    use crate::source::postgres_direct::{
+        DumpPartReader, DumpPartWriter, PostgresDirect, Sequence, SequenceOwner, Table, TableColumn,
+    };
+    use crate::source::SourceOptions;
+    use crate::transformer::transient::TransientTransformer;
+    use crate::transformer::Transformer;
+    use crate::Source;
+
+    fn get_postgres() -> PostgresDirect<'static> {
+        PostgresDirect::new("localhost", 5432, "root", "root", "password")
+    }
+
+    fn get_invalid_postgres() -> PostgresDirect<'static> {
+        PostgresDirect::new("localhost", 5432, "root", "root", "wrongpassword")
+    }
+
+    fn column(name: &str, sql_type: &str, default: Option<&str>, generated: bool) -> TableColumn {
+        TableColumn {
+            name: name.to_string(),
+            sql_type: sql_type.to_string(),
+            not_null: name == "id",
+            default: default.map(str::to_string),
+            generated,
+        }
+    }
+
+    fn get_table() -> Table {
+        Table {
+            oid: 42,
+            schema_name: "public".to_string(),
+            table_name: "Orders".to_string(),
+            schema: "public".to_string(),
+            name: "public.\"Orders\"".to_string(),
+            columns: vec![
+                column(
+                    "id",
+                    "integer",
+                    Some("nextval('public.\"Orders_id_seq\"'::regclass)"),
+                    false,
+                ),
+                column("price", "numeric(10,2)", None, false),
+                column("total", "numeric", Some("(price * (2)::numeric)"), true),
+            ],
+        }
+    }
+
+    fn get_sequence(identity: Option<&str>) -> Sequence {
+        Sequence {
+            name: "public.\"Orders_id_seq\"".to_string(),
+            literal: "'public.\"Orders_id_seq\"'".to_string(),
+            data_type: "integer".to_string(),
+            start: 1,
+            increment: 1,
+            min: 1,
+            max: 2147483647,
+            cache: 1,
+            cycle: false,
+            last_value: Some(42),
+            owner: identity.map(|identity| SequenceOwner {
+                table_oid: 42,
+                column: "id".to_string(),
+                identity: identity.to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn init() {
+        let mut p = get_postgres();
+        assert!(p.init().is_ok());
+
+        let mut p = get_invalid_postgres();
+        assert!(p.init().is_err());
+    }
+
+    #[test]
+    fn connect() {
+        let p = get_postgres();
+        let t1: Box<dyn Transformer> = Box::new(TransientTransformer::default());
+        let transformers = vec![t1];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        assert!(p.read(source_options, |_, _| {}).is_ok());
+
+        let p = get_invalid_postgres();
+        let t1: Box<dyn Transformer> = Box::new(TransientTransformer::default());
+        let transformers = vec![t1];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        assert!(p.read(source_options, |_, _| {}).is_err());
+    }
+
+    #[test]
+    fn list_rows() {
+        let p = get_postgres();
+        let t1: Box<dyn Transformer> = Box::new(TransientTransformer::default());
+        let transformers = vec![t1];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        let _ = p.read(source_options, |original_query, query| {
+            assert!(original_query.data().len() > 0);
+            assert!(query.data().len() > 0);
+            queries.push(String::from_utf8(query.data().to_vec()).unwrap());
+        });
+
+        assert!(queries
+            .iter()
+            .any(|query| query.starts_with("CREATE TABLE public.customers (")));
+        assert!(queries
+            .iter()
+            .any(|query| query.starts_with("COPY public.customers (")));
+    }
+
+    #[test]
+    fn create_table_statement() {
+        let statement = get_table().create_statement();
+
+        assert_eq!(
+            statement,
+            "CREATE TABLE public.\"Orders\" (
+    id integer NOT NULL,
+    price numeric(10,2),
+    total numeric GENERATED ALWAYS AS ((price * (2)::numeric)) STORED
+);"
+        );
+
+        let tokens = get_tokens_from_query_str(statement.as_str());
+
+        match get_row_type(&tokens) {
+            RowType::CreateTable {
+                database_name,
+                table_name,
+            } => {
+                assert_eq!(database_name, "public");
+                assert_eq!(table_name, "\"Orders\"");
+            }
+            _ => panic!("not a CREATE TABLE statement"),
+        }
+
+        assert_eq!(
+            get_column_types_from_create_table_query(&tokens)
+                .into_iter()
+                .map(|(column_name, _)| column_name)
+                .collect::<Vec<_>>(),
+            vec!["id", "price", "total"]
+        );
+    }
+
+    #[test]
+    fn default_and_copy_statements() {
+        let table = get_table();
+
+        assert_eq!(
+            table.set_default_statements(),
+            vec!["ALTER TABLE ONLY public.\"Orders\" ALTER COLUMN id SET DEFAULT nextval('public.\"Orders_id_seq\"'::regclass);"]
+        );
+        assert_eq!(table.copy_column_names(), "id, price");
+    }
+
+    #[test]
+    fn sequence_statements() {
+        let table = get_table();
+
+        let statements = get_sequence(None).create_statements(None);
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].starts_with(
+            "CREATE SEQUENCE public.\"Orders_id_seq\"\n    AS integer\n    START WITH 1\n"
+        ));
+
+        let statements = get_sequence(Some("")).create_statements(Some(&table));
+        assert_eq!(statements.len(), 2);
+        assert_eq!(
+            statements[1],
+            "ALTER SEQUENCE public.\"Orders_id_seq\" OWNED BY public.\"Orders\".id;"
+        );
+
+        let statements = get_sequence(Some("d")).create_statements(Some(&table));
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].starts_with("ALTER TABLE ONLY public.\"Orders\" ALTER COLUMN id ADD GENERATED BY DEFAULT AS IDENTITY (\n    SEQUENCE NAME public.\"Orders_id_seq\"\n"));
+
+        assert_eq!(
+            get_sequence(Some("a")).set_value_statement().unwrap(),
+            "SELECT pg_catalog.setval('public.\"Orders_id_seq\"', 42, true);"
+        );
+    }
+
+    #[test]
+    fn read_dump_parts() {
+        let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(2);
+
+        let join_handle = thread::spawn(move || {
+            let mut writer = BufWriter::with_capacity(16, DumpPartWriter(tx));
+
+            for idx in 0..1000 {
+                writeln!(writer, "row {}", idx).unwrap();
+            }
+
+            writer.flush().unwrap();
+        });
+
+        let lines = BufReader::new(DumpPartReader::new(rx))
+            .lines()
+            .map(|line| line.unwrap())
+            .collect::<Vec<_>>();
+
+        join_handle.join().unwrap();
+
+        assert_eq!(lines.len(), 1000);
+        assert_eq!(lines[0], "row 0");
+        assert_eq!(lines[999], "row 999");
+    }
+
+    #[test]
+    fn dump_parts_not_read() {
+        let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(2);
+        drop(rx);
+
+        assert!(DumpPartWriter(tx).write_all(b"row").is_err());
+    }
+}
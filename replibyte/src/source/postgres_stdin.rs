@@ -1,7 +1,11 @@
 use std::io::{stdin, BufReader, Error};
 
+use dump_parser::utils::ListQueryResult;
+use subset::postgres::Postgres as PostgresDialect;
+
 use crate::connector::Connector;
-use crate::source::postgres::{read_and_transform, subset};
+use crate::source::postgres::read_and_transform_until;
+use crate::source::subset;
 use crate::types::{OriginalQuery, Query};
 use crate::Source;
 use crate::SourceOptions;
@@ -29,6 +33,17 @@ impl Connector for PostgresStdin {
 
 impl Source for PostgresStdin {
     fn read<F: FnMut(OriginalQuery, Query)>(
+        &self,
+        options: SourceOptions,
+        mut query_callback: F,
+    ) -> Result<(), Error> {
+        self.read_until(options, |original_query, query| {
+            query_callback(original_query, query);
+            ListQueryResult::Continue
+        })
+    }
+
+    fn read_until<F: FnMut(OriginalQuery, Query) -> ListQueryResult>(
         &self,
         options: SourceOptions,
         query_callback: F,
@@ -36,12 +51,12 @@ impl Source for PostgresStdin {
         match &options.database_subset {
             None => {
                 let reader = BufReader::new(stdin());
-                read_and_transform(reader, options, query_callback);
+                read_and_transform_until(reader, options, query_callback)?;
             }
             Some(subset_config) => {
                 let dump_reader = BufReader::new(stdin());
-                let reader = subset(dump_reader, subset_config)?;
-                read_and_transform(reader, options, query_callback);
+                let reader = subset::<PostgresDialect, _>(dump_reader, subset_config)?;
+                read_and_transform_until(reader, options, query_callback)?;
             }
         };
 
@@ -0,0 +1,944 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+
+use dump_parser::sqlite::Keyword::Null;
+use dump_parser::sqlite::{
+    escape_string_value, get_column_names_from_insert_into_query,
+    get_column_types_from_create_table_query, get_column_values_from_insert_into_query,
+    get_table_name_from_query, get_tokens_from_query_str, is_create_table_statement,
+    is_insert_into_statement, list_statements_from_dump_reader, quote_identifier,
+    unescape_string_value, Token,
+};
+use dump_parser::utils::{decode_hex, ListQueryResult};
+
+use crate::config::{OnlyTablesConfig, SkipConfig};
+use crate::connector::Connector;
+use crate::source::exclude::ExcludedColumns;
+use crate::source::filter::{to_text, RowFilters};
+use crate::source::mysql::to_number_column;
+use crate::source::postgres_direct::{
+    DumpPartReader, DumpPartWriter, DUMP_PARTS_IN_FLIGHT, DUMP_PART_SIZE,
+};
+use crate::source::{check_strict_columns, Source, SourceOptions, StrictColumns};
+use crate::transformer::Transformer;
+use crate::types::{encode_hex, Column, ColumnType, InsertIntoQuery, OriginalQuery, Query};
+
+/// statements at the top of a dump, as written by the `.dump` command of sqlite3
+const DUMP_HEADER: &str = "PRAGMA foreign_keys=OFF;\nBEGIN TRANSACTION;\n";
+const DUMP_FOOTER: &str = "COMMIT;\n";
+
+/// tables, views, indexes and triggers - in an order they can be created in
+const SCHEMA_QUERY: &str = "SELECT type, name, tbl_name, sql FROM sqlite_master
+WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\'
+ORDER BY CASE type WHEN 'table' THEN 0 WHEN 'view' THEN 1 WHEN 'index' THEN 2 ELSE 3 END, rowid";
+
+#[derive(Debug, PartialEq)]
+enum RowType {
+    InsertInto { table_name: String },
+    CreateTable { table_name: String },
+    Others,
+}
+
+/// Source reading a SQLite database file - the dump is written as the `.dump` command of sqlite3 does
+pub struct Sqlite<'a> {
+    path: &'a str,
+}
+
+impl<'a> Sqlite<'a> {
+    pub fn new(path: &'a str) -> Self {
+        Sqlite { path }
+    }
+}
+
+impl<'a> Connector for Sqlite<'a> {
+    fn init(&mut self) -> Result<(), Error> {
+        if !Path::new(self.path).is_file() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("SQLite database '{}' not found", self.path),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Source for Sqlite<'a> {
+    fn read<F: FnMut(OriginalQuery, Query)>(
+        &self,
+        options: SourceOptions,
+        query_callback: F,
+    ) -> Result<(), Error> {
+        if options.database_subset.is_some() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "<source.database_subset> is not supported for SQLite sources",
+            ));
+        }
+
+        let connection = Connection::open_with_flags(
+            self.path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(to_error)?;
+
+        let skip_config = options.skip_config;
+        let only_tables = options.only_tables;
+
+        let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(DUMP_PARTS_IN_FLIGHT);
+
+        thread::scope(|scope| {
+            let dump_join_handle = scope.spawn(move || -> Result<(), Error> {
+                let mut writer = BufWriter::with_capacity(DUMP_PART_SIZE, DumpPartWriter(tx));
+                let _ = write_dump(&connection, skip_config, only_tables, &mut writer)?;
+                writer.flush()
+            });
+
+            let reader = BufReader::new(DumpPartReader::new(rx));
+            let read_result = read_and_transform(reader, options, query_callback);
+
+            // the dump ends early when the database can't be read - and it can't be written once the reader fails
+            read_result.and(dump_join_handle.join().unwrap())
+        })
+    }
+}
+
+/// table, view, index or trigger read from `sqlite_master`
+struct SchemaObject {
+    object_type: String,
+    name: String,
+    /// table of an index or a trigger - the name of a table or a view
+    table_name: String,
+    sql: String,
+}
+
+fn write_dump<W: Write>(
+    connection: &Connection,
+    skip_config: &[SkipConfig],
+    only_tables: &[OnlyTablesConfig],
+    writer: &mut W,
+) -> Result<(), Error> {
+    // the tables are read in a single transaction to dump a consistent snapshot
+    let _ = connection.execute_batch("BEGIN;").map_err(to_error)?;
+
+    let objects = read_schema(connection)?
+        .into_iter()
+        .filter(|object| is_table_dumped(object.table_name.as_str(), skip_config, only_tables))
+        .collect::<Vec<_>>();
+
+    let _ = writer.write_all(DUMP_HEADER.as_bytes())?;
+
+    for object in objects
+        .iter()
+        .filter(|object| object.object_type == "table")
+    {
+        let _ = write_statement(writer, object.sql.as_str())?;
+        let _ = write_rows(connection, object.name.as_str(), writer)?;
+    }
+
+    let _ = write_sequences(connection, &objects, writer)?;
+
+    // the indexes and triggers are created once the rows are inserted
+    for object in objects
+        .iter()
+        .filter(|object| object.object_type != "table")
+    {
+        let _ = write_statement(writer, object.sql.as_str())?;
+    }
+
+    let _ = writer.write_all(DUMP_FOOTER.as_bytes())?;
+
+    connection.execute_batch("COMMIT;").map_err(to_error)
+}
+
+/// the tables are matched by their name only - a SQLite file holds a single database
+fn is_table_dumped(
+    table_name: &str,
+    skip_config: &[SkipConfig],
+    only_tables: &[OnlyTablesConfig],
+) -> bool {
+    (only_tables.is_empty() || only_tables.iter().any(|cfg| cfg.table == table_name))
+        && !skip_config.iter().any(|cfg| cfg.table == table_name)
+}
+
+fn write_statement<W: Write>(writer: &mut W, statement: &str) -> Result<(), Error> {
+    writeln!(writer, "{};", statement)
+}
+
+fn read_schema(connection: &Connection) -> Result<Vec<SchemaObject>, Error> {
+    let mut statement = connection.prepare(SCHEMA_QUERY).map_err(to_error)?;
+
+    let objects = statement
+        .query_map([], |row| {
+            Ok(SchemaObject {
+                object_type: row.get(0)?,
+                name: row.get(1)?,
+                table_name: row.get(2)?,
+                sql: row.get(3)?,
+            })
+        })
+        .map_err(to_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(to_error)?;
+
+    Ok(objects)
+}
+
+/// write the rows of a table - one `INSERT INTO ...` statement by row, with its column names
+fn write_rows<W: Write>(
+    connection: &Connection,
+    table_name: &str,
+    writer: &mut W,
+) -> Result<(), Error> {
+    // the generated columns can't be inserted
+    let mut statement = connection
+        .prepare("SELECT name FROM pragma_table_xinfo(?1) WHERE hidden = 0 ORDER BY cid")
+        .map_err(to_error)?;
+
+    let column_names = statement
+        .query_map([table_name], |row| row.get::<_, String>(0))
+        .map_err(to_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(to_error)?
+        .iter()
+        .map(|column_name| quote_identifier(column_name))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let table_name = quote_identifier(table_name);
+
+    let mut statement = connection
+        .prepare(format!("SELECT {} FROM {}", column_names, table_name).as_str())
+        .map_err(to_error)?;
+
+    let column_count = statement.column_count();
+    let mut rows = statement.query([]).map_err(to_error)?;
+
+    while let Some(row) = rows.next().map_err(to_error)? {
+        let mut values = Vec::with_capacity(column_count);
+
+        for idx in 0..column_count {
+            values.push(to_literal(row.get_ref(idx).map_err(to_error)?));
+        }
+
+        let _ = write_statement(
+            writer,
+            format!(
+                "INSERT INTO {} ({}) VALUES({})",
+                table_name,
+                column_names,
+                values.join(",")
+            )
+            .as_str(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// write the last values of the `AUTOINCREMENT` columns - `sqlite_sequence` is created with the first of them
+fn write_sequences<W: Write>(
+    connection: &Connection,
+    objects: &[SchemaObject],
+    writer: &mut W,
+) -> Result<(), Error> {
+    let table_names = objects
+        .iter()
+        .filter(|object| object.object_type == "table")
+        .map(|object| object.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let sequence_exists = connection
+        .query_row(
+            "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = 'sqlite_sequence'",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(to_error)?
+        > 0;
+
+    if !sequence_exists {
+        return Ok(());
+    }
+
+    let mut statement = connection
+        .prepare("SELECT name, seq FROM sqlite_sequence")
+        .map_err(to_error)?;
+
+    let mut rows = statement.query([]).map_err(to_error)?;
+    let mut sequences = vec![];
+
+    while let Some(row) = rows.next().map_err(to_error)? {
+        let table_name = row.get::<_, String>(0).map_err(to_error)?;
+
+        if table_names.contains(table_name.as_str()) {
+            sequences.push(format!(
+                "INSERT INTO \"sqlite_sequence\" (\"name\",\"seq\") VALUES('{}',{})",
+                escape_string_value(table_name.as_str()),
+                to_literal(row.get_ref(1).map_err(to_error)?)
+            ));
+        }
+    }
+
+    if sequences.is_empty() {
+        // no dumped table has an AUTOINCREMENT column
+        return Ok(());
+    }
+
+    let _ = write_statement(writer, "DELETE FROM sqlite_sequence")?;
+
+    for sequence in sequences {
+        let _ = write_statement(writer, sequence.as_str())?;
+    }
+
+    Ok(())
+}
+
+/// write a value as a SQLite literal - as the `.dump` command of sqlite3 does
+fn to_literal(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(value) => value.to_string(),
+        ValueRef::Real(value) if value.is_infinite() && value > 0.0 => "1e999".to_string(),
+        ValueRef::Real(value) if value.is_infinite() => "-1e999".to_string(),
+        // `{:?}` keeps the decimal point of the integral values - `2.0` is not read back as an integer
+        ValueRef::Real(value) => format!("{:?}", value),
+        ValueRef::Text(value) => format!(
+            "'{}'",
+            escape_string_value(String::from_utf8_lossy(value).as_ref())
+        ),
+        ValueRef::Blob(value) => format!("X'{}'", encode_hex(value)),
+    }
+}
+
+fn to_error(err: rusqlite::Error) -> Error {
+    Error::new(ErrorKind::Other, format!("{}", err))
+}
+
+pub fn read_and_transform<R: Read, F: FnMut(OriginalQuery, Query)>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    query_callback: F,
+) -> Result<(), Error> {
+    match options.strict {
+        None => transform(reader, options, query_callback),
+        Some(strict_config) => {
+            // SQLite columns are named after their table only
+            let transformed_columns = options
+                .transformers
+                .iter()
+                .map(|transformer| transformer.table_and_column_name())
+                // the excluded columns are not dumped as they are
+                .chain(options.exclude_columns.iter().flat_map(|config| {
+                    config
+                        .columns
+                        .iter()
+                        .map(move |column| format!("{}.{}", config.table, column))
+                }))
+                .collect::<HashSet<_>>();
+
+            let safe_columns = strict_config
+                .safe_columns()
+                .into_iter()
+                .map(|(_, table, column)| format!("{}.{}", table, column))
+                .collect::<HashSet<_>>();
+
+            let reader = check_strict_columns(
+                reader,
+                StrictColumns::new(transformed_columns, safe_columns),
+                list_columns,
+            )?;
+
+            transform(reader, options, query_callback)
+        }
+    }
+}
+
+/// list the columns of the `CREATE TABLE ...` and `INSERT INTO ...` queries
+fn list_columns<R: Read>(
+    reader: BufReader<R>,
+    strict_columns: &mut StrictColumns,
+) -> Result<(), Error> {
+    match list_statements_from_dump_reader(reader, |query| {
+        let tokens = get_tokens_from_query_str(query);
+
+        let (table_name, column_names) = match get_row_type(&tokens) {
+            RowType::CreateTable { table_name } => (
+                table_name,
+                get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, _)| column_name)
+                    .collect::<Vec<_>>(),
+            ),
+            RowType::InsertInto { table_name } => (
+                table_name,
+                get_column_names_from_insert_into_query(&tokens)
+                    .into_iter()
+                    .map(|column_name| column_name.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            RowType::Others => return ListQueryResult::Continue,
+        };
+
+        for column_name in column_names {
+            strict_columns.add(format!("{}.{}", table_name, column_name));
+        }
+
+        ListQueryResult::Continue
+    }) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    }
+}
+
+fn transform<R: Read, F: FnMut(OriginalQuery, Query)>(
+    reader: BufReader<R>,
+    options: SourceOptions,
+    mut query_callback: F,
+) -> Result<(), Error> {
+    let mut transformer_by_table_and_column_name: HashMap<String, &dyn Transformer> =
+        HashMap::with_capacity(options.transformers.len());
+
+    for transformer in options.transformers {
+        let _ = transformer_by_table_and_column_name
+            .insert(transformer.table_and_column_name(), transformer.as_ref());
+    }
+
+    // column names and types from the `CREATE TABLE ...` queries, by table
+    let mut columns_by_table: HashMap<String, Vec<(String, ColumnType)>> = HashMap::new();
+
+    let row_filters = RowFilters::new(options.filters)?;
+    let excluded_columns = ExcludedColumns::new(options.exclude_columns);
+
+    // an invalid row stops the dump
+    let mut row_error = None;
+
+    let list_result = list_statements_from_dump_reader(reader, |query| {
+        let tokens = get_tokens_from_query_str(query);
+
+        match get_row_type(&tokens) {
+            RowType::InsertInto { table_name } => {
+                let (original_columns, columns) = match transform_columns(
+                    table_name.as_str(),
+                    &tokens,
+                    columns_by_table.get(table_name.as_str()),
+                    &transformer_by_table_and_column_name,
+                ) {
+                    Ok(columns) => columns,
+                    Err(err) => {
+                        row_error = Some(err);
+                        return ListQueryResult::Break;
+                    }
+                };
+
+                // SQLite dumps do not hold the database name
+                let keep_row = row_filters.keep_row(None, table_name.as_str(), |column_name| {
+                    original_columns
+                        .iter()
+                        .find(|column| column.name() == column_name)
+                        .and_then(to_text)
+                });
+
+                if !keep_row {
+                    return ListQueryResult::Continue;
+                }
+
+                let columns = excluded_columns.exclude_columns(None, table_name.as_str(), columns);
+
+                query_callback(
+                    to_query(InsertIntoQuery {
+                        table_name: table_name.to_string(),
+                        columns: original_columns,
+                    }),
+                    to_query(InsertIntoQuery {
+                        table_name: table_name.to_string(),
+                        columns,
+                    }),
+                )
+            }
+            RowType::CreateTable { table_name } => {
+                let columns = get_column_types_from_create_table_query(&tokens)
+                    .into_iter()
+                    .map(|(column_name, sql_type)| {
+                        (column_name, ColumnType::from_sql_type(sql_type.as_str()))
+                    })
+                    .collect::<Vec<_>>();
+
+                query_callback(
+                    Query(query.as_bytes().to_vec()),
+                    Query(
+                        excluded_columns
+                            .exclude_from_create_table(None, table_name.as_str(), query)
+                            .into_bytes(),
+                    ),
+                );
+
+                let _ = columns_by_table.insert(table_name, columns);
+            }
+            RowType::Others => {
+                // other rows than `INSERT INTO ...` and `CREATE TABLE ...`
+                query_callback(
+                    Query(query.as_bytes().to_vec()),
+                    Query(query.as_bytes().to_vec()),
+                );
+            }
+        }
+
+        ListQueryResult::Continue
+    });
+
+    if let Some(err) = row_error {
+        return Err(err);
+    }
+
+    match list_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(ErrorKind::Other, format!("{:?}", err))),
+    }
+}
+
+fn transform_columns(
+    table_name: &str,
+    tokens: &[Token],
+    table_columns: Option<&Vec<(String, ColumnType)>>,
+    transformer_by_table_and_column_name: &HashMap<String, &dyn Transformer>,
+) -> Result<(Vec<Column>, Vec<Column>), Error> {
+    let column_names = get_column_names_from_insert_into_query(tokens)
+        .into_iter()
+        .map(|column_name| column_name.to_string())
+        .collect::<Vec<_>>();
+
+    // the rows of the `.dump` command of sqlite3 have no column names
+    let column_names = match (column_names.is_empty(), table_columns) {
+        (false, _) => column_names,
+        (true, Some(table_columns)) => table_columns
+            .iter()
+            .map(|(column_name, _)| column_name.clone())
+            .collect::<Vec<_>>(),
+        (true, None) => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "the columns of table '{}' are unknown - its rows must have column names or follow its CREATE TABLE statement",
+                    table_name
+                ),
+            ))
+        }
+    };
+
+    let column_values = get_column_values_from_insert_into_query(tokens);
+
+    if column_names.len() != column_values.len() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "a row of table '{}' has {} values for {} columns",
+                table_name,
+                column_values.len(),
+                column_names.len()
+            ),
+        ));
+    }
+
+    let original_columns = column_names
+        .iter()
+        .zip(column_values.iter())
+        .map(|(column_name, value_tokens)| {
+            let column_type = table_columns
+                .and_then(|table_columns| {
+                    table_columns
+                        .iter()
+                        .find(|(name, _)| name == column_name)
+                        .map(|(_, column_type)| *column_type)
+                })
+                .unwrap_or(ColumnType::Unknown);
+
+            to_column(column_name, column_type, value_tokens)
+        })
+        .collect::<Vec<_>>();
+
+    // transformers can read the other columns of the row, so the row is parsed before being transformed
+    let columns = original_columns
+        .iter()
+        .map(|column| {
+            let table_and_column_name = format!("{}.{}", table_name, column.name());
+
+            match transformer_by_table_and_column_name.get(table_and_column_name.as_str()) {
+                Some(transformer) => {
+                    transformer.transform_with_row(column.clone(), &original_columns)
+                }
+                None => column.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok((original_columns, columns))
+}
+
+/// type the value of an `INSERT INTO ...` query from its tokens and the type of its column
+fn to_column(column_name: &str, column_type: ColumnType, value_tokens: &[&Token]) -> Column {
+    let column_name = column_name.to_string();
+
+    match value_tokens {
+        [Token::Number(column_value)] => to_number_column(column_name, column_value),
+        [Token::SingleQuotedString(column_value)] => {
+            let column_value = unescape_string_value(column_value);
+
+            match column_type {
+                ColumnType::Date => Column::DateValue(column_name, column_value),
+                ColumnType::Json => Column::JsonValue(column_name, column_value),
+                ColumnType::Uuid => Column::UuidValue(column_name, column_value),
+                _ => Column::StringValue(column_name, column_value),
+            }
+        }
+        [Token::HexStringLiteral(column_value)] => {
+            let bytes = Some(column_value)
+                .filter(|hex| hex.len() % 2 == 0)
+                .and_then(|hex| decode_hex(hex).ok());
+
+            match bytes {
+                Some(bytes) => Column::BytesValue(column_name, bytes),
+                None => Column::RawValue(column_name, format!("X'{}'", column_value)),
+            }
+        }
+        [Token::Word(w)] if w.keyword == Null => Column::None(column_name),
+        // keywords and expressions are written back as they are, e.g. `CURRENT_TIMESTAMP`
+        _ => Column::RawValue(
+            column_name,
+            value_tokens
+                .iter()
+                .map(|token| token.to_string())
+                .collect::<String>(),
+        ),
+    }
+}
+
+fn get_row_type(tokens: &[Token]) -> RowType {
+    match get_table_name_from_query(tokens) {
+        Some(table_name) if is_insert_into_statement(tokens) => RowType::InsertInto {
+            table_name: table_name.to_string(),
+        },
+        Some(table_name) if is_create_table_statement(tokens) => RowType::CreateTable {
+            table_name: table_name.to_string(),
+        },
+        _ => RowType::Others,
+    }
+}
+
+fn to_query(query: InsertIntoQuery) -> Query {
+    let (column_names, values): (Vec<String>, Vec<String>) = query
+        .columns
+        .into_iter()
+        .map(|column| match column {
+            Column::NumberValue(column_name, value) => (column_name, value.to_string()),
+            Column::FloatNumberValue(column_name, value) => (column_name, value.to_string()),
+            Column::StringValue(column_name, value)
+            | Column::DateValue(column_name, value)
+            | Column::JsonValue(column_name, value)
+            | Column::UuidValue(column_name, value)
+            | Column::ArrayValue(column_name, value) => (
+                column_name,
+                format!("'{}'", escape_string_value(value.as_str())),
+            ),
+            Column::CharValue(column_name, value) => (
+                column_name,
+                format!("'{}'", escape_string_value(value.to_string().as_str())),
+            ),
+            Column::BooleanValue(column_name, value) => (column_name, value.to_string()),
+            Column::BytesValue(column_name, value) => {
+                (column_name, format!("X'{}'", encode_hex(&value)))
+            }
+            Column::RawValue(column_name, value) => (column_name, value),
+            Column::None(column_name) => (column_name, "NULL".to_string()),
+        })
+        .unzip();
+
+    let query_string = format!(
+        "INSERT INTO {} ({}) VALUES({});",
+        quote_identifier(query.table_name.as_str()),
+        column_names
+            .iter()
+            .map(|column_name| quote_identifier(column_name))
+            .collect::<Vec<_>>()
+            .join(","),
+        values.join(","),
+    );
+
+    Query(query_string.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+    use std::str;
+
+    use rusqlite::Connection;
+    use tempfile::NamedTempFile;
+
+    use crate::config::{ExcludeColumnsConfig, ExcludedValueConfig, OnlyTablesConfig, SkipConfig};
+    use crate::connector::Connector;
+    use crate::source::sqlite::{get_row_type, read_and_transform, RowType, Sqlite};
+    use crate::source::{Source, SourceOptions};
+    use crate::transformer::{transient::TransientTransformer, Transformer};
+    use dump_parser::sqlite::get_tokens_from_query_str;
+
+    fn create_database() -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        let connection = Connection::open(file.path()).unwrap();
+
+        connection
+            .execute_batch(
+                "CREATE TABLE customers (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, balance REAL, picture BLOB);
+CREATE INDEX customers_name ON customers (name);
+CREATE TABLE \"audit log\" (id INTEGER, type TEXT, total INTEGER GENERATED ALWAYS AS (id * 2) VIRTUAL);
+CREATE VIEW customer_names AS SELECT name FROM customers;
+INSERT INTO customers (name, balance, picture) VALUES ('Lucas', 2.0, X'01FF'), ('O''Brien', NULL, NULL);
+INSERT INTO \"audit log\" (id, type) VALUES (1, 'login');",
+            )
+            .unwrap();
+
+        file
+    }
+
+    fn read_queries(sqlite: &Sqlite, options: SourceOptions) -> Vec<(String, String)> {
+        let mut queries = vec![];
+
+        sqlite
+            .read(options, |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            })
+            .unwrap();
+
+        queries
+    }
+
+    #[test]
+    fn connect() {
+        let file = create_database();
+        let mut sqlite = Sqlite::new(file.path().to_str().unwrap());
+        assert!(sqlite.init().is_ok());
+
+        let mut sqlite = Sqlite::new("/tmp/replibyte-not-a-database.db");
+        assert!(sqlite.init().is_err());
+    }
+
+    #[test]
+    fn list_rows() {
+        let file = create_database();
+        let sqlite = Sqlite::new(file.path().to_str().unwrap());
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let queries = read_queries(&sqlite, source_options)
+            .into_iter()
+            .map(|(original_query, query)| {
+                assert_eq!(original_query, query);
+                query
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            queries,
+            vec![
+                "PRAGMA foreign_keys=OFF;",
+                "BEGIN TRANSACTION;",
+                "CREATE TABLE customers (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, balance REAL, picture BLOB);",
+                "INSERT INTO \"customers\" (\"id\",\"name\",\"balance\",\"picture\") VALUES(1,'Lucas',2.0,X'01ff');",
+                "INSERT INTO \"customers\" (\"id\",\"name\",\"balance\",\"picture\") VALUES(2,'O''Brien',NULL,NULL);",
+                "CREATE TABLE \"audit log\" (id INTEGER, type TEXT, total INTEGER GENERATED ALWAYS AS (id * 2) VIRTUAL);",
+                "INSERT INTO \"audit log\" (\"id\",\"type\") VALUES(1,'login');",
+                "DELETE FROM sqlite_sequence;",
+                "INSERT INTO \"sqlite_sequence\" (\"name\",\"seq\") VALUES('customers',2);",
+                "CREATE VIEW customer_names AS SELECT name FROM customers;",
+                "CREATE INDEX customers_name ON customers (name);",
+                "COMMIT;",
+            ]
+        );
+    }
+
+    #[test]
+    fn list_rows_of_some_tables() {
+        let file = create_database();
+        let sqlite = Sqlite::new(file.path().to_str().unwrap());
+
+        let transformers = vec![];
+        let skip_config = vec![SkipConfig {
+            database: "main".to_string(),
+            table: "customers".to_string(),
+        }];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &skip_config,
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let queries = read_queries(&sqlite, source_options);
+        // the views are dumped as tables - `customer_names` still selects the skipped table
+        assert!(queries.iter().all(|(_, query)| {
+            !query.contains("TABLE customers")
+                && !query.contains("\"customers\"")
+                && !query.contains("ON customers")
+        }));
+        assert!(queries
+            .iter()
+            .any(|(_, query)| query.starts_with("INSERT INTO \"audit log\"")));
+
+        let only_tables = vec![OnlyTablesConfig {
+            database: "main".to_string(),
+            table: "customers".to_string(),
+        }];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &only_tables,
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        let queries = read_queries(&sqlite, source_options);
+        assert!(queries
+            .iter()
+            .any(|(_, query)| query.starts_with("INSERT INTO \"customers\"")));
+        assert!(queries
+            .iter()
+            .all(|(_, query)| !query.contains("audit log") && !query.contains("VIEW")));
+    }
+
+    #[test]
+    fn test_get_row_type() {
+        let tokens = get_tokens_from_query_str("INSERT INTO main.\"my table\" VALUES(1);");
+        assert_eq!(
+            get_row_type(&tokens),
+            RowType::InsertInto {
+                table_name: "my table".to_string()
+            }
+        );
+
+        let tokens = get_tokens_from_query_str("CREATE TABLE IF NOT EXISTS [users] (id INTEGER);");
+        assert_eq!(
+            get_row_type(&tokens),
+            RowType::CreateTable {
+                table_name: "users".to_string()
+            }
+        );
+
+        let tokens = get_tokens_from_query_str("CREATE INDEX users_id ON users (id);");
+        assert_eq!(get_row_type(&tokens), RowType::Others);
+    }
+
+    #[test]
+    fn read_and_transform_dump_without_column_names() {
+        let dump = r#"PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE customers (id INTEGER PRIMARY KEY, email VARCHAR(255), created_at DATETIME, note TEXT);
+INSERT INTO customers VALUES(1,'lucas@example.com','2022-05-01 10:00:00','it''s;
+multiline');
+INSERT INTO customers VALUES(2,'paul@example.com',CURRENT_TIMESTAMP,replace('a\nb','\n',char(10)));
+COMMIT;
+"#;
+
+        let transformers = vec![
+            Box::new(TransientTransformer::new("main", "customers", "email"))
+                as Box<dyn Transformer>,
+        ];
+        let exclude_columns = vec![ExcludeColumnsConfig {
+            database: "main".to_string(),
+            table: "customers".to_string(),
+            columns: vec!["created_at".to_string()],
+            replace_with: Some(ExcludedValueConfig::Default),
+            drop_from_schema: None,
+        }];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &exclude_columns,
+            strict: &None,
+            workers: 1,
+        };
+
+        let mut queries = vec![];
+        read_and_transform(
+            BufReader::new(dump.as_bytes()),
+            source_options,
+            |original_query, query| {
+                queries.push((
+                    str::from_utf8(original_query.data()).unwrap().to_string(),
+                    str::from_utf8(query.data()).unwrap().to_string(),
+                ));
+            },
+        )
+        .unwrap();
+
+        let insert_queries = queries
+            .iter()
+            .filter(|(_, query)| query.starts_with("INSERT INTO"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(insert_queries.len(), 2);
+
+        // the values are typed and written back as they are in the dump
+        assert_eq!(
+            insert_queries[0].0,
+            "INSERT INTO \"customers\" (\"id\",\"email\",\"created_at\",\"note\") VALUES(1,'lucas@example.com','2022-05-01 10:00:00','it''s;\nmultiline');"
+        );
+        assert_eq!(
+            insert_queries[1].0,
+            "INSERT INTO \"customers\" (\"id\",\"email\",\"created_at\",\"note\") VALUES(2,'paul@example.com',CURRENT_TIMESTAMP,replace('a\\nb','\\n',char(10)));"
+        );
+        assert_eq!(
+            insert_queries[1].1,
+            "INSERT INTO \"customers\" (\"id\",\"email\",\"note\") VALUES(2,'paul@example.com',replace('a\\nb','\\n',char(10)));"
+        );
+    }
+
+    #[test]
+    fn read_and_transform_rows_of_unknown_table() {
+        let dump = "INSERT INTO customers VALUES(1,'lucas@example.com');\n";
+
+        let transformers = vec![];
+        let source_options = SourceOptions {
+            transformers: &transformers,
+            skip_config: &vec![],
+            database_subset: &None,
+            only_tables: &vec![],
+            filters: &vec![],
+            exclude_columns: &vec![],
+            strict: &None,
+            workers: 1,
+        };
+
+        assert!(
+            read_and_transform(BufReader::new(dump.as_bytes()), source_options, |_, _| {}).is_err()
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// number of inputs waiting or being processed by each worker before the producer waits for the outputs
+const INPUTS_IN_FLIGHT_BY_WORKER: usize = 4;
+
+/// Process the inputs sent by `produce` with `work` on `workers` threads, and `consume` the outputs
+/// in the order of their inputs - on the calling thread, as `produce`.
+/// With a single worker, each input is processed on the calling thread as soon as it is sent.
+pub fn run_in_order<I, O, P, W, C>(
+    workers: usize,
+    produce: P,
+    work: W,
+    mut consume: C,
+) -> Result<(), Error>
+where
+    I: Send,
+    O: Send,
+    P: FnOnce(&mut dyn FnMut(I)) -> Result<(), Error>,
+    W: Fn(I) -> O + Sync,
+    C: FnMut(O),
+{
+    if workers <= 1 {
+        return produce(&mut |input| consume(work(input)));
+    }
+
+    let (input_tx, input_rx) = mpsc::channel::<(usize, I)>();
+    let (output_tx, output_rx) = mpsc::channel::<(usize, thread::Result<O>)>();
+    let input_rx = Mutex::new(input_rx);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let input_rx = &input_rx;
+            let output_tx = output_tx.clone();
+            let work = &work;
+
+            let _ = scope.spawn(move || loop {
+                let input = input_rx.lock().unwrap().recv();
+
+                let (idx, input) = match input {
+                    Ok(input) => input,
+                    // all the inputs are processed
+                    Err(_) => break,
+                };
+
+                // a panic is raised again on the calling thread when its output is consumed
+                let output = catch_unwind(AssertUnwindSafe(|| work(input)));
+
+                if output_tx.send((idx, output)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(output_tx);
+
+        let mut outputs = OrderedOutputs::new(&mut consume);
+        let mut sent = 0usize;
+        let max_in_flight = workers * INPUTS_IN_FLIGHT_BY_WORKER;
+
+        let result = produce(&mut |input| {
+            let _ = input_tx.send((sent, input));
+            sent += 1;
+
+            while let Ok((idx, output)) = output_rx.try_recv() {
+                outputs.push(idx, output);
+            }
+
+            // keep the memory bounded when the inputs are produced faster than they are processed
+            while sent - outputs.next_idx >= max_in_flight {
+                match output_rx.recv() {
+                    Ok((idx, output)) => outputs.push(idx, output),
+                    Err(_) => break,
+                }
+            }
+        });
+
+        // the workers stop once the remaining inputs are processed
+        drop(input_tx);
+
+        for (idx, output) in output_rx {
+            outputs.push(idx, output);
+        }
+
+        result
+    })
+}
+
+/// outputs received in any order and consumed in the order of their inputs
+struct OrderedOutputs<'a, O, C: FnMut(O)> {
+    consume: &'a mut C,
+    next_idx: usize,
+    pending: BTreeMap<usize, thread::Result<O>>,
+}
+
+impl<'a, O, C: FnMut(O)> OrderedOutputs<'a, O, C> {
+    fn new(consume: &'a mut C) -> Self {
+        OrderedOutputs {
+            consume,
+            next_idx: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    fn push(&mut self, idx: usize, output: thread::Result<O>) {
+        let _ = self.pending.insert(idx, output);
+
+        while let Some(output) = self.pending.remove(&self.next_idx) {
+            self.next_idx += 1;
+
+            match output {
+                Ok(output) => (self.consume)(output),
+                Err(panic) => resume_unwind(panic),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Error, ErrorKind};
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::source::workers::run_in_order;
+
+    fn produce(count: u64) -> impl FnOnce(&mut dyn FnMut(u64)) -> Result<(), Error> {
+        move |send| {
+            for input in 0..count {
+                send(input);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn outputs_in_order() {
+        for workers in [1, 4] {
+            let mut outputs = vec![];
+
+            let result = run_in_order(
+                workers,
+                produce(100),
+                |input| {
+                    // the first inputs are processed last
+                    thread::sleep(Duration::from_micros(100 - input));
+                    input * 2
+                },
+                |output| outputs.push(output),
+            );
+
+            assert!(result.is_ok());
+            assert_eq!(outputs, (0..100).map(|input| input * 2).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn producer_error() {
+        let mut outputs = vec![];
+
+        let result = run_in_order(
+            4,
+            |send| {
+                send(1);
+                send(2);
+                Err(Error::new(ErrorKind::Other, "unreadable dump"))
+            },
+            |input| input,
+            |output| outputs.push(output),
+        );
+
+        assert!(result.is_err());
+        // the inputs sent before the error are processed
+        assert_eq!(outputs, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid input")]
+    fn worker_panic() {
+        let _ = run_in_order(
+            4,
+            produce(100),
+            |input| {
+                if input == 42 {
+                    panic!("invalid input");
+                }
+
+                input
+            },
+            |_| {},
+        );
+    }
+}
@@ -1,14 +1,57 @@
+use std::collections::VecDeque;
 use std::io::{Error, ErrorKind};
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc};
 use std::thread;
 
-use crate::datastore::Datastore;
+use crate::datastore::{ChunkQueries, Datastore, Dump, DumpChunk, DumpStatus};
+use crate::leak_detector::LeakDetector;
 use crate::source::SourceOptions;
 use crate::tasks::{MaxBytes, Message, Task, TransferredBytes};
-use crate::types::{to_bytes, Queries};
+use crate::types::{to_bytes, Bytes, Queries};
 use crate::Source;
 
-type DataMessage = (u16, Queries);
+/// chunk part, original queries and transformed queries
+type DataMessage = (u16, ChunkQueries, Queries);
+/// chunk part, original queries and compressed and encrypted chunk
+type EncodedMessage = (u16, ChunkQueries, Bytes);
+
+/// size of the buffer in memory used and re-used to upload data into the datastore
+pub const DEFAULT_CHUNK_SIZE: usize = 100 * 1024 * 1024;
+
+/// position of an interrupted dump: the chunks recorded in the index, in the order of their part number
+#[derive(Debug, PartialEq, Clone)]
+pub struct ResumePoint {
+    pub chunks: Vec<DumpChunk>,
+}
+
+impl ResumePoint {
+    /// the chunks written before their number of queries and their fingerprint were recorded can't be resumed
+    pub fn new(dump: &Dump) -> Result<Self, Error> {
+        if dump
+            .chunks
+            .iter()
+            .any(|chunk| chunk.queries == 0 || chunk.fingerprint.is_empty())
+        {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "dump '{}' can't be resumed - its chunks were written by a previous version",
+                    dump.directory_name
+                ),
+            ));
+        }
+
+        let mut chunks = dump.chunks.clone();
+        chunks.sort_by_key(|chunk| chunk.part);
+
+        Ok(ResumePoint { chunks })
+    }
+
+    /// the last chunk written
+    pub fn chunk_part(&self) -> u16 {
+        self.chunks.last().map_or(0, |chunk| chunk.part)
+    }
+}
 
 /// FullDumpTask is a wrapping struct to execute the synchronization between a *Source* and a *Datastore*
 pub struct FullDumpTask<'a, S>
@@ -18,6 +61,9 @@ where
     source: S,
     datastore: Box<dyn Datastore>,
     options: SourceOptions<'a>,
+    chunk_size: usize,
+    leak_detector: Option<&'a mut LeakDetector>,
+    resume_point: Option<ResumePoint>,
 }
 
 impl<'a, S> FullDumpTask<'a, S>
@@ -29,8 +75,30 @@ where
             source,
             datastore,
             options,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            leak_detector: None,
+            resume_point: None,
         }
     }
+
+    /// size in bytes of the chunks written to the datastore - `DEFAULT_CHUNK_SIZE` when None
+    pub fn with_chunk_size(mut self, chunk_size: Option<usize>) -> Self {
+        self.chunk_size = chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+        self
+    }
+
+    /// inspect the original and transformed queries for leaks before the last chunk is written
+    pub fn with_leak_detector(mut self, leak_detector: Option<&'a mut LeakDetector>) -> Self {
+        self.leak_detector = leak_detector;
+        self
+    }
+
+    /// skip the queries already written by an interrupted dump and write the next chunks after its last one -
+    /// the source must read its tables and rows in the same order
+    pub fn with_resume_point(mut self, resume_point: Option<ResumePoint>) -> Self {
+        self.resume_point = resume_point;
+        self
+    }
 }
 
 impl<'a, S> Task for FullDumpTask<'a, S>
@@ -45,36 +113,76 @@ where
         let _ = self.source.init()?;
 
         let (tx, rx) = mpsc::sync_channel::<Message<DataMessage>>(1);
-        let datastore = self.datastore;
+        let (encoded_tx, encoded_rx) = mpsc::sync_channel::<Message<EncodedMessage>>(1);
+        let datastore: Arc<dyn Datastore> = Arc::from(self.datastore);
 
-        let join_handle = thread::spawn(move || -> Result<(), Error> {
-            // managing Datastore (S3) upload here
-            let datastore = datastore;
+        if self.resume_point.is_some() {
+            let _ = datastore.set_dump_status(DumpStatus::InProgress)?;
+        }
 
+        // compressing and encrypting a chunk while the previous one is uploaded
+        let encode_datastore = datastore.clone();
+        let encode_join_handle = thread::spawn(move || -> Result<(), Error> {
             loop {
-                let result = match rx.recv() {
-                    Ok(Message::Data((chunk_part, queries))) => Ok((chunk_part, queries)),
-                    Ok(Message::EOF) => break,
-                    Err(err) => Err(Error::new(ErrorKind::Other, format!("{}", err))),
+                let (chunk_part, original_queries, queries) = match rx.recv() {
+                    Ok(Message::Data(message)) => message,
+                    Ok(Message::EOF) | Err(_) => break,
                 };
 
-                if let Ok((chunk_part, queries)) = result {
-                    let _ = match datastore.write(chunk_part, to_bytes(queries)) {
-                        Ok(_) => {}
-                        Err(err) => return Err(Error::new(ErrorKind::Other, format!("{}", err))),
-                    };
+                let data = encode_datastore.encode_chunk(to_bytes(queries))?;
+
+                if encoded_tx
+                    .send(Message::Data((chunk_part, original_queries, data)))
+                    .is_err()
+                {
+                    // the upload failed
+                    break;
                 }
             }
 
+            let _ = encoded_tx.send(Message::EOF);
             Ok(())
         });
 
-        // buffer of 100MB in memory to use and re-use to upload data into datastore
-        let buffer_size = 100 * 1024 * 1024;
+        let upload_datastore = datastore.clone();
+        let join_handle = thread::spawn(move || -> Result<(), Error> {
+            // managing Datastore (S3) upload here
+            loop {
+                let (chunk_part, original_queries, data) = match encoded_rx.recv() {
+                    Ok(Message::Data(message)) => message,
+                    Ok(Message::EOF) | Err(_) => break,
+                };
+
+                let _ = upload_datastore.write_chunk(chunk_part, &original_queries, data)?;
+            }
+
+            Ok(())
+        });
+
+        // wait for end of the compression, encryption and upload executions
+        let wait = || -> Result<(), Error> {
+            let encode_result = encode_join_handle.join().unwrap();
+            let upload_result = join_handle.join().unwrap();
+            encode_result.and(upload_result)
+        };
+
+        // buffer in memory to use and re-use to upload data into datastore
+        let buffer_size = self.chunk_size;
         let mut queries = vec![];
+        let mut original_queries = ChunkQueries::default();
         let mut consumed_buffer_size = 0usize;
         let mut total_transferred_bytes = 0usize;
-        let mut chunk_part = 0u16;
+        let mut chunk_part = self
+            .resume_point
+            .as_ref()
+            .map_or(0, |point| point.chunk_part());
+        // chunks written before the dump was interrupted - their queries are read again and skipped
+        let mut chunks_to_skip = self
+            .resume_point
+            .map_or(VecDeque::new(), |point| VecDeque::from(point.chunks));
+        let mut skipped_queries = ChunkQueries::default();
+        let mut mismatching_chunk_part = None;
+        let mut too_many_chunks = false;
 
         // init progress
         progress_callback(
@@ -82,13 +190,50 @@ where
             buffer_size * (chunk_part as usize + 1),
         );
 
-        let _ = self.source.read(self.options, |_original_query, query| {
-            if consumed_buffer_size + query.data().len() > buffer_size {
+        let mut leak_detector = self.leak_detector;
+
+        let read_result = self.source.read(self.options, |original_query, query| {
+            if let Some(leak_detector) = leak_detector.as_mut() {
+                leak_detector.inspect(&original_query, &query);
+            }
+
+            if too_many_chunks || mismatching_chunk_part.is_some() {
+                return;
+            }
+
+            // the query is in a chunk written before the dump was interrupted
+            if let Some(chunk) = chunks_to_skip.front() {
+                skipped_queries.push(&original_query);
+
+                if skipped_queries.count() == chunk.queries {
+                    // the source must read the same queries as the interrupted dump
+                    if skipped_queries.fingerprint() != chunk.fingerprint {
+                        mismatching_chunk_part = Some(chunk.part);
+                    }
+
+                    let _ = chunks_to_skip.pop_front();
+                    skipped_queries = ChunkQueries::default();
+                }
+
+                return;
+            }
+
+            if consumed_buffer_size + query.data().len() > buffer_size && !queries.is_empty() {
+                // the last part number is kept for the last chunk
+                if chunk_part == u16::MAX - 1 {
+                    too_many_chunks = true;
+                    return;
+                }
+
                 chunk_part += 1;
                 consumed_buffer_size = 0;
                 // TODO .clone() - look if we do not consume more mem
 
-                let message = Message::Data((chunk_part, queries.clone()));
+                let message = Message::Data((
+                    chunk_part,
+                    std::mem::take(&mut original_queries),
+                    queries.clone(),
+                ));
 
                 let _ = tx.send(message); // FIXME catch SendError?
                 let _ = queries.clear();
@@ -100,17 +245,60 @@ where
                 total_transferred_bytes,
                 buffer_size * (chunk_part as usize + 1),
             );
+            original_queries.push(&original_query);
             queries.push(query);
-        })?;
+        });
+
+        let check_result = match (read_result, mismatching_chunk_part) {
+            (Err(err), _) => Err(err),
+            (Ok(_), Some(chunk_part)) => Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "the source differs from chunk {} of the interrupted dump - it can't be resumed",
+                    chunk_part
+                ),
+            )),
+            (Ok(_), None) if !chunks_to_skip.is_empty() => Err(Error::new(
+                ErrorKind::Other,
+                "the source holds less queries than the interrupted dump - it can't be resumed",
+            )),
+            (Ok(_), None) if too_many_chunks => Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "the dump is split in more than {} chunks - increase <source.chunk_size>",
+                    u16::MAX
+                ),
+            )),
+            (Ok(_), None) => match leak_detector {
+                Some(leak_detector) => leak_detector.check(),
+                None => Ok(()),
+            },
+        };
+
+        if let Err(err) = check_result {
+            // the last chunk is not written
+            let _ = tx.send(Message::EOF);
+            let _ = wait();
+            let _ = datastore.set_dump_status(DumpStatus::Failed);
+            return Err(err);
+        }
 
         progress_callback(total_transferred_bytes, total_transferred_bytes);
 
-        chunk_part += 1;
-        let _ = tx.send(Message::Data((chunk_part, queries)));
+        // a resumed dump can have all its queries written already
+        if !queries.is_empty() || chunk_part == 0 {
+            chunk_part += 1;
+            let _ = tx.send(Message::Data((chunk_part, original_queries, queries)));
+        }
+
         let _ = tx.send(Message::EOF);
-        // wait for end of upload execution
-        join_handle.join().unwrap()?;
 
-        Ok(())
+        if let Err(err) = wait() {
+            let _ = datastore.set_dump_status(DumpStatus::Failed);
+            return Err(err);
+        }
+
+        // the dump is only visible as `latest` once all its chunks are written
+        datastore.set_dump_status(DumpStatus::Completed)
     }
 }
@@ -62,21 +62,20 @@ where
             let datastore = datastore;
             let read_options = read_options;
 
-            let _ = match datastore.read(&read_options, &mut |data| {
+            let _ = datastore.read(&read_options, &mut |data| {
                 let _ = tx.send(Message::Data(data));
-            }) {
-                Ok(_) => {}
-                Err(err) => panic!("{:?}", err),
-            };
+            })?;
 
             let _ = tx.send(Message::EOF);
+            Ok::<(), Error>(())
         });
 
         loop {
             let data = match rx.recv() {
                 Ok(Message::Data(data)) => data,
                 Ok(Message::EOF) => break,
-                Err(err) => panic!("{:?}", err), // FIXME what should I do here?
+                // the download stopped on an error - returned by the join below
+                Err(_) => break,
             };
 
             progress_callback(data.len(), dump.size);
@@ -85,7 +84,10 @@ where
         }
 
         // wait for end of download execution
-        let _ = join_handle.join(); // FIXME catch result here
+        let _ = join_handle.join().unwrap()?;
+
+        // the destination is finished once the whole dump is downloaded
+        let _ = self.destination.finish()?;
 
         progress_callback(dump.size, dump.size);
 
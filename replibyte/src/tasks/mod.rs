@@ -12,7 +12,7 @@ pub trait Task {
 
 /// inter-thread message for Source/Destination and Datastore
 #[derive(Debug, Clone)]
-enum Message<T> {
+pub(crate) enum Message<T> {
     Data(T),
     EOF,
 }
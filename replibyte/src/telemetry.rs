@@ -1,5 +1,5 @@
 use crate::config::{ConnectionUri, TransformerTypeConfig};
-use crate::{Config, DumpCommand, RestoreCommand, SubCommand, TransformerCommand};
+use crate::{Config, DumpCommand, RestoreCommand, SourceCommand, SubCommand, TransformerCommand};
 use chrono::{NaiveDateTime, Utc};
 use reqwest::blocking::Client as HttpClient;
 use reqwest::header::CONTENT_TYPE;
@@ -87,13 +87,17 @@ impl TelemetryClient {
                         ConnectionUri::Postgres(_, _, _, _, _) => "postgresql",
                         ConnectionUri::Mysql(_, _, _, _, _) => "mysql",
                         ConnectionUri::MongoDB(_, _) => "mongodb",
+                        ConnectionUri::Sqlite(_) => "sqlite",
                     }
                     .to_string(),
                 );
 
                 props.insert(
                     "compression_used".to_string(),
-                    x.compression.unwrap_or(true).to_string(),
+                    x.compression()
+                        .map(|options| options.is_some())
+                        .unwrap_or(true)
+                        .to_string(),
                 );
 
                 props.insert("skip_tables_used".to_string(), x.skip.is_some().to_string());
@@ -110,7 +114,7 @@ impl TelemetryClient {
                         for column in &transformer.columns {
                             transformers.insert(match column.transformer {
                                 TransformerTypeConfig::Random => "random",
-                                TransformerTypeConfig::RandomDate => "random-date",
+                                TransformerTypeConfig::RandomDate(_) => "random-date",
                                 TransformerTypeConfig::FirstName => "first-name",
                                 TransformerTypeConfig::Email => "email",
                                 TransformerTypeConfig::KeepFirstChar => "keep-first-char",
@@ -119,6 +123,7 @@ impl TelemetryClient {
                                 TransformerTypeConfig::Redacted(_) => "redacted",
                                 TransformerTypeConfig::Transient => "transient",
                                 TransformerTypeConfig::CustomWasm(_) => "custom-wasm",
+                                TransformerTypeConfig::Json(_) => "json",
                             });
                         }
                     }
@@ -136,6 +141,7 @@ impl TelemetryClient {
                 DumpCommand::List => "dump-list",
                 DumpCommand::Create(_) => "dump-create",
                 DumpCommand::Delete(_) => "dump-delete",
+                DumpCommand::Verify(_) => "dump-verify",
                 DumpCommand::Restore(restore_cmd) => match restore_cmd {
                     RestoreCommand::Local(_) => "dump-restore-local",
                     RestoreCommand::Remote(_) => "dump-restore-remote",
@@ -143,6 +149,10 @@ impl TelemetryClient {
             },
             SubCommand::Transformer(cmd) => match cmd {
                 TransformerCommand::List => "transformer-list",
+                TransformerCommand::Preview(_) => "transformer-preview",
+            },
+            SubCommand::Source(cmd) => match cmd {
+                SourceCommand::Scan(_) => "source-scan",
             },
         };
 
@@ -1,4 +1,4 @@
-use crate::transformer::Transformer;
+use crate::transformer::{rng, DeterministicKey, Transformer};
 use crate::types::Column;
 use fake::faker::creditcard::raw::CreditCardNumber;
 use fake::locales::EN;
@@ -9,10 +9,16 @@ pub struct CreditCardTransformer {
     database_name: String,
     table_name: String,
     column_name: String,
+    deterministic_key: Option<DeterministicKey>,
 }
 
 impl CreditCardTransformer {
-    pub fn new<S>(database_name: S, table_name: S, column_name: S) -> Self
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
     where
         S: Into<String>,
     {
@@ -20,6 +26,7 @@ impl CreditCardTransformer {
             database_name: database_name.into(),
             table_name: table_name.into(),
             column_name: column_name.into(),
+            deterministic_key,
         }
     }
 }
@@ -30,6 +37,7 @@ impl Default for CreditCardTransformer {
             database_name: String::default(),
             table_name: String::default(),
             column_name: String::default(),
+            deterministic_key: None,
         }
     }
 }
@@ -57,8 +65,9 @@ impl Transformer for CreditCardTransformer {
 
     fn transform(&self, column: Column) -> Column {
         match column {
-            Column::StringValue(column_name, _value) => {
-                Column::StringValue(column_name, CreditCardNumber(EN).fake())
+            Column::StringValue(column_name, value) => {
+                let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                Column::StringValue(column_name, CreditCardNumber(EN).fake_with_rng(&mut rng))
             }
             column => column,
         }
@@ -83,6 +92,6 @@ mod tests {
     }
 
     fn get_transformer() -> CreditCardTransformer {
-        CreditCardTransformer::new("github", "users", "credit_card")
+        CreditCardTransformer::new("github", "users", "credit_card", None)
     }
 }
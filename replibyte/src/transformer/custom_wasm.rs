@@ -1,4 +1,5 @@
 use std::io::{self, ErrorKind};
+use std::sync::Mutex;
 
 use crate::transformer::Transformer;
 use crate::types::Column;
@@ -17,6 +18,8 @@ struct WasmConfig {
     wasi_env: WasiEnv,
     import_object: ImportObject,
     module: Module,
+    // the stdin and stdout pipes are shared - one call at a time
+    call_lock: Mutex<()>,
 }
 pub struct CustomWasmTransformer {
     database_name: String,
@@ -60,10 +63,13 @@ impl CustomWasmTransformer {
                 wasi_env,
                 import_object,
                 module,
+                call_lock: Mutex::new(()),
             },
         })
     }
     fn call_wasm_module(&self, value: &str) -> Result<String, WasmError> {
+        let _call_guard = self.wasm_config.call_lock.lock().unwrap();
+
         // Create a new wasm instance from the wasm configuration
         let instance = Instance::new(&self.wasm_config.module, &self.wasm_config.import_object)?;
         // Access WasiState in a nested scope to ensure we're not holding
@@ -121,6 +127,7 @@ impl Default for CustomWasmTransformer {
                     ],
                 )
                 .unwrap(),
+                call_lock: Mutex::new(()),
             },
         }
     }
@@ -182,7 +189,22 @@ impl Transformer for CustomWasmTransformer {
                     .parse::<bool>()
                     .expect("Wasm module failed to return a boolean"),
             ),
-            Column::None(column_name) => Column::None(column_name),
+            Column::DateValue(column_name, value) => Column::DateValue(
+                column_name,
+                self.call_wasm_module(value.as_str())
+                    .expect("Wasm module call failed"),
+            ),
+            Column::JsonValue(column_name, value) => Column::JsonValue(
+                column_name,
+                self.call_wasm_module(value.as_str())
+                    .expect("Wasm module call failed"),
+            ),
+            Column::UuidValue(column_name, value) => Column::UuidValue(
+                column_name,
+                self.call_wasm_module(value.as_str())
+                    .expect("Wasm module call failed"),
+            ),
+            column => column,
         }
     }
 }
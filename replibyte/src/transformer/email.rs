@@ -1,4 +1,4 @@
-use crate::transformer::Transformer;
+use crate::transformer::{rng, DeterministicKey, Transformer};
 use crate::types::Column;
 use fake::faker::internet::raw::SafeEmail;
 use fake::locales::EN;
@@ -9,10 +9,16 @@ pub struct EmailTransformer {
     database_name: String,
     table_name: String,
     column_name: String,
+    deterministic_key: Option<DeterministicKey>,
 }
 
 impl EmailTransformer {
-    pub fn new<S>(database_name: S, table_name: S, column_name: S) -> Self
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
     where
         S: Into<String>,
     {
@@ -20,6 +26,7 @@ impl EmailTransformer {
             database_name: database_name.into(),
             table_name: table_name.into(),
             column_name: column_name.into(),
+            deterministic_key,
         }
     }
 }
@@ -30,6 +37,7 @@ impl Default for EmailTransformer {
             database_name: String::default(),
             table_name: String::default(),
             column_name: String::default(),
+            deterministic_key: None,
         }
     }
 }
@@ -60,7 +68,10 @@ impl Transformer for EmailTransformer {
             Column::StringValue(column_name, value) => {
                 let new_value = match value.len() {
                     len if len == 0 => value,
-                    _ => SafeEmail(EN).fake(),
+                    _ => {
+                        let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                        SafeEmail(EN).fake_with_rng(&mut rng)
+                    }
                 };
 
                 Column::StringValue(column_name, new_value)
@@ -72,7 +83,8 @@ impl Transformer for EmailTransformer {
 
 #[cfg(test)]
 mod tests {
-    use crate::{transformer::Transformer, types::Column};
+    use crate::transformer::{DeterministicKey, Transformer};
+    use crate::types::Column;
 
     use super::EmailTransformer;
 
@@ -120,7 +132,34 @@ mod tests {
         assert_ne!(transformed_value, "john.doe@company.com".to_string());
     }
 
+    #[test]
+    fn transform_email_deterministically() {
+        let key = DeterministicKey::new("secret");
+        let users = EmailTransformer::new("github", "users", "email", Some(key.clone()));
+        let orders = EmailTransformer::new("github", "orders", "customer_email", Some(key));
+
+        let column = Column::StringValue("email".to_string(), "john.doe@company.com".to_string());
+        let a = users.transform(column);
+        let a = a.string_value().unwrap();
+
+        let column = Column::StringValue(
+            "customer_email".to_string(),
+            "john.doe@company.com".to_string(),
+        );
+        let b = orders.transform(column);
+        let b = b.string_value().unwrap();
+
+        assert_ne!(a, "john.doe@company.com");
+        assert_eq!(a, b);
+
+        let column = Column::StringValue("email".to_string(), "jane.doe@company.com".to_string());
+        let c = users.transform(column);
+        let c = c.string_value().unwrap();
+
+        assert_ne!(a, c);
+    }
+
     fn get_transformer() -> EmailTransformer {
-        EmailTransformer::new("github", "users", "email")
+        EmailTransformer::new("github", "users", "email", None)
     }
 }
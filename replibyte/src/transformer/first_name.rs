@@ -1,4 +1,4 @@
-use crate::transformer::Transformer;
+use crate::transformer::{rng, DeterministicKey, Transformer};
 use crate::types::Column;
 use fake::faker::name::raw::FirstName;
 use fake::locales::EN;
@@ -9,10 +9,16 @@ pub struct FirstNameTransformer {
     database_name: String,
     table_name: String,
     column_name: String,
+    deterministic_key: Option<DeterministicKey>,
 }
 
 impl FirstNameTransformer {
-    pub fn new<S>(database_name: S, table_name: S, column_name: S) -> Self
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
     where
         S: Into<String>,
     {
@@ -20,6 +26,7 @@ impl FirstNameTransformer {
             database_name: database_name.into(),
             table_name: table_name.into(),
             column_name: column_name.into(),
+            deterministic_key,
         }
     }
 }
@@ -30,6 +37,7 @@ impl Default for FirstNameTransformer {
             database_name: String::default(),
             table_name: String::default(),
             column_name: String::default(),
+            deterministic_key: None,
         }
     }
 }
@@ -57,22 +65,17 @@ impl Transformer for FirstNameTransformer {
 
     fn transform(&self, column: Column) -> Column {
         match column {
-            Column::NumberValue(column_name, value) => Column::NumberValue(column_name, value),
-            Column::FloatNumberValue(column_name, value) => {
-                Column::FloatNumberValue(column_name, value)
-            }
             Column::StringValue(column_name, value) => {
                 let new_value = if value == "" {
                     "".to_string()
                 } else {
-                    FirstName(EN).fake()
+                    let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                    FirstName(EN).fake_with_rng(&mut rng)
                 };
 
                 Column::StringValue(column_name, new_value)
             }
-            Column::CharValue(column_name, value) => Column::CharValue(column_name, value),
-            Column::BooleanValue(column_name, value) => Column::BooleanValue(column_name, value),
-            Column::None(column_name) => Column::None(column_name),
+            column => column,
         }
     }
 }
@@ -128,6 +131,6 @@ mod tests {
     }
 
     fn get_transformer() -> FirstNameTransformer {
-        FirstNameTransformer::new("github", "users", "first_name")
+        FirstNameTransformer::new("github", "users", "first_name", None)
     }
 }
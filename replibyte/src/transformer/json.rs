@@ -0,0 +1,438 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use crate::config::TransformerTypeConfig;
+use crate::transformer::Transformer;
+use crate::types::Column;
+
+/// This transformer applies other transformers to values inside a JSON document.
+pub struct JsonTransformer {
+    database_name: String,
+    table_name: String,
+    column_name: String,
+    paths: Vec<(JsonPath, Box<dyn Transformer>)>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct JsonTransformerOptions {
+    pub paths: Vec<JsonPathConfig>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct JsonPathConfig {
+    // e.g. `address.street`, `emails[*]`, `orders.$[].card_number`
+    pub path: String,
+
+    #[serde(flatten)]
+    pub transformer: TransformerTypeConfig,
+}
+
+impl Default for JsonTransformer {
+    fn default() -> Self {
+        JsonTransformer {
+            database_name: String::default(),
+            table_name: String::default(),
+            column_name: String::default(),
+            paths: vec![],
+        }
+    }
+}
+
+impl JsonTransformer {
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        paths: Vec<(JsonPath, Box<dyn Transformer>)>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        JsonTransformer {
+            database_name: database_name.into(),
+            table_name: table_name.into(),
+            column_name: column_name.into(),
+            paths,
+        }
+    }
+
+    fn transform_document(&self, document: String, row: &[Column]) -> String {
+        let mut value = match serde_json::from_str::<Value>(document.as_str()) {
+            Ok(value) => value,
+            // not a JSON document - keep it as it is
+            Err(_) => return document,
+        };
+
+        let original_value = value.clone();
+
+        for (path, transformer) in &self.paths {
+            path.transform(&mut value, &mut |value| {
+                let column = to_column(self.column_name.as_str(), value);
+                *value = to_value(transformer.transform_with_row(column, row));
+            });
+        }
+
+        if value == original_value {
+            // nothing changed - keep the original formatting
+            return document;
+        }
+
+        value.to_string()
+    }
+}
+
+impl Transformer for JsonTransformer {
+    fn id(&self) -> &str {
+        "json"
+    }
+
+    fn description(&self) -> &str {
+        "Apply transformers to values inside a JSON document (string or JSON). [{\"name\": \"Lucas\"}]->[{\"name\": \"Georges\"}]"
+    }
+
+    fn database_name(&self) -> &str {
+        self.database_name.as_str()
+    }
+
+    fn table_name(&self) -> &str {
+        self.table_name.as_str()
+    }
+
+    fn column_name(&self) -> &str {
+        self.column_name.as_str()
+    }
+
+    fn transform(&self, column: Column) -> Column {
+        self.transform_with_row(column, &[])
+    }
+
+    fn transform_with_row(&self, column: Column, row: &[Column]) -> Column {
+        match column {
+            Column::StringValue(column_name, value) => {
+                Column::StringValue(column_name, self.transform_document(value, row))
+            }
+            Column::JsonValue(column_name, value) => {
+                Column::JsonValue(column_name, self.transform_document(value, row))
+            }
+            column => column,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+    // all the items of an array or all the values of an object
+    Wildcard,
+}
+
+/// JSONPath-like selector: `$.address.street`, `emails[*]`, `emails.$[]`, `orders[0].items[*].name`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<PathSegment>,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> Result<Self, String> {
+        let path = path.trim();
+
+        let mut segments = vec![];
+        let mut key = String::new();
+        let mut chars = path.strip_prefix('$').unwrap_or(path).chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '.' => push_key(&mut segments, &mut key),
+                // `$[]` - wildcard of the MongoDB syntax
+                '$' if key.is_empty() && chars.peek() == Some(&'[') => {}
+                '[' => {
+                    push_key(&mut segments, &mut key);
+
+                    let mut selector = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(ch) => selector.push(ch),
+                            None => return Err(format!("missing ']' in JSON path '{}'", path)),
+                        }
+                    }
+
+                    let selector = selector.trim();
+                    let segment = match selector {
+                        "" | "*" => PathSegment::Wildcard,
+                        selector
+                            if selector.len() > 1
+                                && (selector.starts_with('\'') && selector.ends_with('\'')
+                                    || selector.starts_with('"') && selector.ends_with('"')) =>
+                        {
+                            PathSegment::Key(selector[1..selector.len() - 1].to_string())
+                        }
+                        selector => match selector.parse::<usize>() {
+                            Ok(idx) => PathSegment::Index(idx),
+                            Err(_) => {
+                                return Err(format!(
+                                    "invalid selector '[{}]' in JSON path '{}'",
+                                    selector, path
+                                ))
+                            }
+                        },
+                    };
+
+                    segments.push(segment);
+                }
+                ch => key.push(ch),
+            }
+        }
+
+        push_key(&mut segments, &mut key);
+
+        if segments.is_empty() {
+            return Err(format!("empty JSON path '{}'", path));
+        }
+
+        Ok(JsonPath { segments })
+    }
+
+    /// call `f` on every value matching the path
+    fn transform<F: FnMut(&mut Value)>(&self, value: &mut Value, f: &mut F) {
+        transform_segments(&self.segments, value, f)
+    }
+}
+
+fn push_key(segments: &mut Vec<PathSegment>, key: &mut String) {
+    if key.is_empty() {
+        return;
+    }
+
+    if key == "*" {
+        segments.push(PathSegment::Wildcard);
+    } else {
+        segments.push(PathSegment::Key(key.clone()));
+    }
+
+    key.clear();
+}
+
+fn transform_segments<F: FnMut(&mut Value)>(
+    segments: &[PathSegment],
+    value: &mut Value,
+    f: &mut F,
+) {
+    let (segment, segments) = match segments.split_first() {
+        Some(x) => x,
+        None => return f(value),
+    };
+
+    match (segment, value) {
+        (PathSegment::Key(key), Value::Object(object)) => {
+            if let Some(value) = object.get_mut(key) {
+                transform_segments(segments, value, f);
+            }
+        }
+        (PathSegment::Index(idx), Value::Array(array)) => {
+            if let Some(value) = array.get_mut(*idx) {
+                transform_segments(segments, value, f);
+            }
+        }
+        (PathSegment::Wildcard, Value::Array(array)) => {
+            for value in array.iter_mut() {
+                transform_segments(segments, value, f);
+            }
+        }
+        (PathSegment::Wildcard, Value::Object(object)) => {
+            for value in object.values_mut() {
+                transform_segments(segments, value, f);
+            }
+        }
+        // the path does not exist in this document
+        _ => {}
+    }
+}
+
+fn to_column(column_name: &str, value: &Value) -> Column {
+    let column_name = column_name.to_string();
+
+    match value {
+        Value::Null => Column::None(column_name),
+        Value::Bool(value) => Column::BooleanValue(column_name, *value),
+        Value::Number(number) => match number.as_i64() {
+            Some(value) => Column::NumberValue(column_name, value as i128),
+            None => Column::FloatNumberValue(column_name, number.as_f64().unwrap_or_default()),
+        },
+        Value::String(value) => Column::StringValue(column_name, value.clone()),
+        // nested document or array
+        value => Column::JsonValue(column_name, value.to_string()),
+    }
+}
+
+fn to_value(column: Column) -> Value {
+    match column {
+        Column::NumberValue(_, value) => match i64::try_from(value) {
+            Ok(value) => Value::Number(value.into()),
+            Err(_) => Value::String(value.to_string()),
+        },
+        Column::FloatNumberValue(_, value) => match Number::from_f64(value) {
+            Some(number) => Value::Number(number),
+            None => Value::Null,
+        },
+        Column::StringValue(_, value) => Value::String(value),
+        Column::CharValue(_, value) => Value::String(value.to_string()),
+        Column::BooleanValue(_, value) => Value::Bool(value),
+        Column::JsonValue(_, value) => {
+            serde_json::from_str(value.as_str()).unwrap_or(Value::String(value))
+        }
+        Column::DateValue(_, value)
+        | Column::UuidValue(_, value)
+        | Column::ArrayValue(_, value)
+        | Column::RawValue(_, value) => Value::String(value),
+        Column::BytesValue(_, value) => Value::String(crate::types::encode_hex(&value)),
+        Column::None(_) => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::transformer::json::{JsonPath, JsonTransformer, PathSegment};
+    use crate::transformer::redacted::{RedactedTransformer, RedactedTransformerOptions};
+    use crate::transformer::transient::TransientTransformer;
+    use crate::transformer::Transformer;
+    use crate::types::Column;
+
+    fn redacted() -> Box<dyn Transformer> {
+        Box::new(RedactedTransformer::new(
+            "public",
+            "customers",
+            "profile",
+            RedactedTransformerOptions::default(),
+        ))
+    }
+
+    fn get_transformer(paths: Vec<&str>) -> JsonTransformer {
+        JsonTransformer::new(
+            "public",
+            "customers",
+            "profile",
+            paths
+                .into_iter()
+                .map(|path| (JsonPath::parse(path).unwrap(), redacted()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parse_path() {
+        assert_eq!(
+            JsonPath::parse("$.address.street").unwrap().segments,
+            vec![
+                PathSegment::Key("address".to_string()),
+                PathSegment::Key("street".to_string())
+            ]
+        );
+        assert_eq!(
+            JsonPath::parse("orders[0].items[*]['card number']")
+                .unwrap()
+                .segments,
+            vec![
+                PathSegment::Key("orders".to_string()),
+                PathSegment::Index(0),
+                PathSegment::Key("items".to_string()),
+                PathSegment::Wildcard,
+                PathSegment::Key("card number".to_string())
+            ]
+        );
+        assert_eq!(
+            JsonPath::parse("emails.$[]").unwrap().segments,
+            vec![
+                PathSegment::Key("emails".to_string()),
+                PathSegment::Wildcard
+            ]
+        );
+        assert_eq!(
+            JsonPath::parse("*.street").unwrap().segments,
+            vec![
+                PathSegment::Wildcard,
+                PathSegment::Key("street".to_string())
+            ]
+        );
+        assert!(JsonPath::parse("emails[").is_err());
+        assert!(JsonPath::parse("emails[first]").is_err());
+        assert!(JsonPath::parse("$").is_err());
+    }
+
+    #[test]
+    fn transform_nested_values() {
+        let transformer = get_transformer(vec!["address.street", "emails[*]", "age"]);
+
+        let column = Column::JsonValue(
+            "profile".to_string(),
+            r#"{"name": "John", "address": {"street": "1 Infinite Loop", "city": "Cupertino"}, "emails": ["john@doe.com", "j@doe.com"], "age": 42}"#.to_string(),
+        );
+
+        let transformed_column = transformer.transform(column);
+        let value: Value = serde_json::from_str(transformed_column.json_value().unwrap()).unwrap();
+
+        assert_eq!(value["name"], "John");
+        assert_eq!(value["address"]["street"], "1 I**********");
+        assert_eq!(value["address"]["city"], "Cupertino");
+        assert_eq!(value["emails"][0], "joh**********");
+        assert_eq!(value["emails"][1], "j@d**********");
+        // redacted does not transform numbers
+        assert_eq!(value["age"], 42);
+
+        // keys keep their order
+        assert!(transformed_column
+            .json_value()
+            .unwrap()
+            .starts_with(r#"{"name":"John","address""#));
+    }
+
+    #[test]
+    fn transform_array_of_objects() {
+        let transformer = get_transformer(vec!["$[*].card_number", "missing.path"]);
+
+        let column = Column::StringValue(
+            "payments".to_string(),
+            r#"[{"card_number": "4242424242424242"}, {"card_number": null}, {"amount": 10}]"#
+                .to_string(),
+        );
+
+        let transformed_column = transformer.transform(column);
+
+        assert_eq!(
+            transformed_column.string_value().unwrap(),
+            r#"[{"card_number":"424**********"},{"card_number":null},{"amount":10}]"#
+        );
+    }
+
+    #[test]
+    fn keep_unchanged_documents() {
+        let transformer = JsonTransformer::new(
+            "public",
+            "customers",
+            "profile",
+            vec![(
+                JsonPath::parse("name").unwrap(),
+                Box::new(TransientTransformer::default()) as Box<dyn Transformer>,
+            )],
+        );
+
+        let document = r#"{"name": "John",  "age": 42}"#;
+        let column = Column::JsonValue("profile".to_string(), document.to_string());
+        assert_eq!(
+            transformer.transform(column).json_value().unwrap(),
+            document
+        );
+
+        let column = Column::StringValue("profile".to_string(), "not json".to_string());
+        assert_eq!(
+            transformer.transform(column).string_value().unwrap(),
+            "not json"
+        );
+
+        let column = Column::None("profile".to_string());
+        assert!(matches!(transformer.transform(column), Column::None(_)));
+    }
+}
@@ -2,19 +2,27 @@ use crate::transformer::credit_card::CreditCardTransformer;
 use crate::transformer::custom_wasm::CustomWasmTransformer;
 use crate::transformer::email::EmailTransformer;
 use crate::transformer::first_name::FirstNameTransformer;
+use crate::transformer::json::JsonTransformer;
 use crate::transformer::keep_first_char::KeepFirstCharTransformer;
 use crate::transformer::phone_number::PhoneNumberTransformer;
 use crate::transformer::random::RandomTransformer;
+use crate::transformer::random_date::RandomDateTransformer;
 use crate::transformer::redacted::RedactedTransformer;
 use crate::transformer::transient::TransientTransformer;
 use crate::types::Column;
+use hmac::{Hmac, Mac};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::Sha256;
 
 pub mod credit_card;
 pub mod email;
 pub mod first_name;
+pub mod json;
 pub mod keep_first_char;
 pub mod phone_number;
 pub mod random;
+pub mod random_date;
 pub mod redacted;
 pub mod transient;
 
@@ -28,16 +36,55 @@ pub fn transformers() -> Vec<Box<dyn Transformer>> {
         Box::new(FirstNameTransformer::default()),
         Box::new(PhoneNumberTransformer::default()),
         Box::new(RandomTransformer::default()),
+        Box::new(RandomDateTransformer::default()),
         Box::new(KeepFirstCharTransformer::default()),
         Box::new(TransientTransformer::default()),
         Box::new(CreditCardTransformer::default()),
         Box::new(RedactedTransformer::default()),
+        Box::new(JsonTransformer::default()),
         Box::new(CustomWasmTransformer::default()),
     ]
 }
 
+/// Secret used by transformers running in deterministic mode.
+/// The same original value always gives the same fake value, whatever the table, the column or the dump run.
+#[derive(Clone)]
+pub struct DeterministicKey {
+    secret: Vec<u8>,
+}
+
+impl DeterministicKey {
+    pub fn new<S: Into<String>>(secret: S) -> Self {
+        DeterministicKey {
+            secret: secret.into().into_bytes(),
+        }
+    }
+
+    /// return a random generator seeded with HMAC-SHA256(secret, value)
+    pub fn rng(&self, value: &[u8]) -> ChaCha20Rng {
+        // HMAC accepts keys of any size
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_slice()).unwrap();
+        mac.update(value);
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(mac.finalize().into_bytes().as_slice());
+
+        ChaCha20Rng::from_seed(seed)
+    }
+}
+
+/// Return the random generator to use to transform `value`.
+/// It is seeded from `value` when a deterministic key is set, otherwise it is the thread local one.
+pub fn rng(deterministic_key: Option<&DeterministicKey>, value: &[u8]) -> Box<dyn RngCore> {
+    match deterministic_key {
+        Some(key) => Box::new(key.rng(value)),
+        None => Box::new(rand::thread_rng()),
+    }
+}
+
 /// Trait to implement to create a custom Transformer.
-pub trait Transformer {
+/// Transformers are shared by the threads transforming the dump.
+pub trait Transformer: Send + Sync {
     fn id(&self) -> &str;
     fn description(&self) -> &str;
     fn database_name(&self) -> &str;
@@ -84,4 +131,32 @@ pub trait Transformer {
     }
 
     fn transform(&self, column: Column) -> Column;
+
+    /// Same as `transform` but with the original columns of the row the column belongs to.
+    /// Override it when the new value depends on other columns of the row.
+    fn transform_with_row(&self, column: Column, _row: &[Column]) -> Column {
+        self.transform(column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transformer::DeterministicKey;
+    use rand::Rng;
+
+    #[test]
+    fn deterministic_key_rng() {
+        let key = DeterministicKey::new("my secret");
+
+        let a = key.rng(b"john.doe@company.com").gen::<u64>();
+        let b = key.rng(b"john.doe@company.com").gen::<u64>();
+        assert_eq!(a, b);
+
+        let c = key.rng(b"jane.doe@company.com").gen::<u64>();
+        assert_ne!(a, c);
+
+        let other_key = DeterministicKey::new("another secret");
+        let d = other_key.rng(b"john.doe@company.com").gen::<u64>();
+        assert_ne!(a, d);
+    }
 }
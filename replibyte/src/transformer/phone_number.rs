@@ -1,4 +1,4 @@
-use crate::transformer::Transformer;
+use crate::transformer::{rng, DeterministicKey, Transformer};
 use crate::types::Column;
 use fake::faker::phone_number::raw::PhoneNumber;
 use fake::locales::EN;
@@ -9,10 +9,16 @@ pub struct PhoneNumberTransformer {
     database_name: String,
     table_name: String,
     column_name: String,
+    deterministic_key: Option<DeterministicKey>,
 }
 
 impl PhoneNumberTransformer {
-    pub fn new<S>(database_name: S, table_name: S, column_name: S) -> Self
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
     where
         S: Into<String>,
     {
@@ -20,6 +26,7 @@ impl PhoneNumberTransformer {
             database_name: database_name.into(),
             table_name: table_name.into(),
             column_name: column_name.into(),
+            deterministic_key,
         }
     }
 }
@@ -30,6 +37,7 @@ impl Default for PhoneNumberTransformer {
             database_name: String::default(),
             table_name: String::default(),
             column_name: String::default(),
+            deterministic_key: None,
         }
     }
 }
@@ -57,8 +65,9 @@ impl Transformer for PhoneNumberTransformer {
 
     fn transform(&self, column: Column) -> Column {
         match column {
-            Column::StringValue(column_name, _) => {
-                Column::StringValue(column_name, PhoneNumber(EN).fake())
+            Column::StringValue(column_name, value) => {
+                let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                Column::StringValue(column_name, PhoneNumber(EN).fake_with_rng(&mut rng))
             }
             column => column,
         }
@@ -67,7 +76,8 @@ impl Transformer for PhoneNumberTransformer {
 
 #[cfg(test)]
 mod tests {
-    use crate::{transformer::Transformer, types::Column};
+    use crate::transformer::{DeterministicKey, Transformer};
+    use crate::types::Column;
 
     use super::PhoneNumberTransformer;
 
@@ -82,7 +92,38 @@ mod tests {
         assert_ne!(transformed_value, "+123456789".to_string());
     }
 
+    #[test]
+    fn transform_string_with_a_phone_number_deterministically() {
+        let transformer = PhoneNumberTransformer::new(
+            "github",
+            "users",
+            "phone_number",
+            Some(DeterministicKey::new("secret")),
+        );
+
+        let a = transformer
+            .transform(Column::StringValue(
+                "phone_number".to_string(),
+                "+123456789".to_string(),
+            ))
+            .string_value()
+            .unwrap()
+            .to_string();
+
+        let b = transformer
+            .transform(Column::StringValue(
+                "phone_number".to_string(),
+                "+123456789".to_string(),
+            ))
+            .string_value()
+            .unwrap()
+            .to_string();
+
+        assert_ne!(a, "+123456789".to_string());
+        assert_eq!(a, b);
+    }
+
     fn get_transformer() -> PhoneNumberTransformer {
-        PhoneNumberTransformer::new("github", "users", "phone_number")
+        PhoneNumberTransformer::new("github", "users", "phone_number", None)
     }
 }
@@ -1,5 +1,5 @@
-use crate::transformer::Transformer;
-use crate::types::Column;
+use crate::transformer::{rng, DeterministicKey, Transformer};
+use crate::types::{encode_hex, Column};
 use rand::distributions::Alphanumeric;
 use rand::Rng;
 
@@ -8,10 +8,16 @@ pub struct RandomTransformer {
     database_name: String,
     table_name: String,
     column_name: String,
+    deterministic_key: Option<DeterministicKey>,
 }
 
 impl RandomTransformer {
-    pub fn new<S>(database_name: S, table_name: S, column_name: S) -> Self
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
     where
         S: Into<String>,
     {
@@ -19,6 +25,7 @@ impl RandomTransformer {
             table_name: table_name.into(),
             column_name: column_name.into(),
             database_name: database_name.into(),
+            deterministic_key,
         }
     }
 }
@@ -29,6 +36,7 @@ impl Default for RandomTransformer {
             database_name: String::default(),
             table_name: String::default(),
             column_name: String::default(),
+            deterministic_key: None,
         }
     }
 }
@@ -55,17 +63,19 @@ impl Transformer for RandomTransformer {
     }
 
     fn transform(&self, column: Column) -> Column {
-        let mut random = rand::thread_rng();
+        let key = self.deterministic_key.as_ref();
 
         match column {
-            Column::NumberValue(column_name, _) => {
+            Column::NumberValue(column_name, value) => {
+                let mut random = rng(key, value.to_string().as_bytes());
                 Column::NumberValue(column_name, random.gen::<i128>())
             }
-            Column::FloatNumberValue(column_name, _) => {
+            Column::FloatNumberValue(column_name, value) => {
+                let mut random = rng(key, value.to_string().as_bytes());
                 Column::FloatNumberValue(column_name, random.gen::<f64>())
             }
             Column::StringValue(column_name, value) => {
-                let new_value = random
+                let new_value = rng(key, value.as_bytes())
                     .sample_iter(&Alphanumeric)
                     .take(value.len())
                     .map(char::from)
@@ -73,11 +83,37 @@ impl Transformer for RandomTransformer {
 
                 Column::StringValue(column_name, new_value)
             }
-            Column::CharValue(column_name, _) => {
+            Column::CharValue(column_name, value) => {
+                let mut random = rng(key, value.to_string().as_bytes());
                 Column::CharValue(column_name, random.gen::<char>())
             }
-            Column::BooleanValue(column_name, value) => Column::BooleanValue(column_name, value),
-            Column::None(column_name) => Column::None(column_name),
+            Column::UuidValue(column_name, value) => {
+                let mut random = rng(key, value.as_bytes());
+                Column::UuidValue(column_name, random_uuid(&mut random))
+            }
+            Column::BytesValue(column_name, value) => {
+                let mut random = rng(key, value.as_slice());
+                let new_value = (0..value.len()).map(|_| random.gen::<u8>()).collect();
+                Column::BytesValue(column_name, new_value)
+            }
+            column => column,
         }
     }
 }
+
+/// random (version 4) UUID in its hyphenated form
+fn random_uuid<R: Rng>(random: &mut R) -> String {
+    let mut bytes = random.gen::<[u8; 16]>();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = encode_hex(&bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
@@ -0,0 +1,448 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use lazy_static::lazy_static;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+use crate::transformer::{rng, DeterministicKey, Transformer};
+use crate::types::{encode_hex, Column};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+lazy_static! {
+    // used to shift the dates of an entity when no deterministic key is set - shared by all the columns of a dump run
+    static ref RUN_KEY: DeterministicKey = random_key();
+}
+
+/// This struct is dedicated to randomizing or shifting dates and timestamps.
+#[derive(Default)]
+pub struct RandomDateTransformer {
+    database_name: String,
+    table_name: String,
+    column_name: String,
+    options: RandomDateTransformerOptions,
+    deterministic_key: Option<DeterministicKey>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "kebab-case")]
+#[serde(tag = "mode")]
+pub enum RandomDateTransformerOptions {
+    /// random date between `from` and `to` (included)
+    Range {
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    },
+    /// shift the date of a random number of days between -`days` and +`days`
+    Shift { days: u32 },
+    /// shift all the dates of the same entity (rows with the same `entity_column` value) by the same number of days
+    EntityShift { days: u32, entity_column: String },
+}
+
+impl Default for RandomDateTransformerOptions {
+    fn default() -> Self {
+        RandomDateTransformerOptions::Range {
+            from: None,
+            to: None,
+        }
+    }
+}
+
+impl RandomDateTransformer {
+    pub fn new<S>(
+        database_name: S,
+        table_name: S,
+        column_name: S,
+        options: RandomDateTransformerOptions,
+        deterministic_key: Option<DeterministicKey>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        RandomDateTransformer {
+            database_name: database_name.into(),
+            table_name: table_name.into(),
+            column_name: column_name.into(),
+            options,
+            deterministic_key,
+        }
+    }
+
+    fn transform_date(&self, date: DateValue, value: &str, entity: Option<&[u8]>) -> DateValue {
+        match &self.options {
+            RandomDateTransformerOptions::Range { from, to } => {
+                let from = from.unwrap_or_else(|| NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+                let to = to.unwrap_or_else(|| chrono::Utc::now().naive_utc().date());
+                let (from, to) = if from <= to { (from, to) } else { (to, from) };
+
+                let from = from.and_hms_opt(0, 0, 0).unwrap();
+                let to = to.and_hms_opt(23, 59, 59).unwrap();
+
+                let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                let seconds = rng.gen_range(0..=(to - from).num_seconds());
+
+                date.with_datetime(from + Duration::seconds(seconds))
+            }
+            RandomDateTransformerOptions::Shift { days } => {
+                let mut rng = rng(self.deterministic_key.as_ref(), value.as_bytes());
+                let days = *days as i64;
+
+                date.shift(Duration::days(rng.gen_range(-days..=days)))
+            }
+            RandomDateTransformerOptions::EntityShift { days, .. } => {
+                let key = self.deterministic_key.as_ref().unwrap_or(&RUN_KEY);
+                // without entity, all the dates of the column are shifted by the same number of days
+                let entity = entity.unwrap_or(self.column_name.as_bytes());
+                let days = *days as i64;
+
+                date.shift(Duration::days(key.rng(entity).gen_range(-days..=days)))
+            }
+        }
+    }
+
+    /// transform a date, a timestamp or a string containing one of them
+    fn transform_value(&self, value: String, row: &[Column]) -> String {
+        let date = match DateValue::parse(value.as_str()) {
+            Some(date) => date,
+            // not a date (or a special value like 'infinity') - keep it as it is
+            None => return value,
+        };
+
+        let entity = match &self.options {
+            RandomDateTransformerOptions::EntityShift { entity_column, .. } => row
+                .iter()
+                .find(|column| column.name() == entity_column.as_str())
+                .and_then(entity_value),
+            _ => None,
+        };
+
+        let new_date =
+            self.transform_date(date, value.as_str(), entity.as_ref().map(|e| e.as_bytes()));
+
+        new_date.to_string()
+    }
+}
+
+impl Transformer for RandomDateTransformer {
+    fn id(&self) -> &str {
+        "random-date"
+    }
+
+    fn description(&self) -> &str {
+        "Randomize or shift a date or a timestamp (date or string). [2022-05-01 10:00:00]->[2019-11-23 17:32:05]"
+    }
+
+    fn database_name(&self) -> &str {
+        self.database_name.as_str()
+    }
+
+    fn table_name(&self) -> &str {
+        self.table_name.as_str()
+    }
+
+    fn column_name(&self) -> &str {
+        self.column_name.as_str()
+    }
+
+    fn transform(&self, column: Column) -> Column {
+        self.transform_with_row(column, &[])
+    }
+
+    fn transform_with_row(&self, column: Column, row: &[Column]) -> Column {
+        match column {
+            Column::StringValue(column_name, value) => {
+                Column::StringValue(column_name, self.transform_value(value, row))
+            }
+            Column::DateValue(column_name, value) => {
+                Column::DateValue(column_name, self.transform_value(value, row))
+            }
+            column => column,
+        }
+    }
+}
+
+/// Date and timestamp literals as they are written by pg_dump and mysqldump.
+#[derive(Debug, PartialEq)]
+enum DateValue {
+    // 2022-05-01
+    Date(NaiveDate),
+    // 2022-05-01 10:00:00.123456
+    Timestamp(NaiveDateTime),
+    // 2022-05-01 10:00:00.123456+02 - the offset is kept as it is
+    TimestampTz(NaiveDateTime, String),
+}
+
+impl DateValue {
+    fn parse(value: &str) -> Option<Self> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, DATE_FORMAT) {
+            return Some(DateValue::Date(date));
+        }
+
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, TIMESTAMP_FORMAT) {
+            return Some(DateValue::Timestamp(datetime));
+        }
+
+        // the offset is after the time part: +02, -05:30, +0200
+        let offset_idx = value.rfind(['+', '-']).filter(|idx| *idx > 10)?;
+        let (datetime, offset) = value.split_at(offset_idx);
+
+        let offset_digits = &offset[1..];
+        if offset_digits.is_empty()
+            || offset_digits.len() > 5
+            || !offset_digits.chars().all(|c| c.is_ascii_digit() || c == ':')
+        {
+            return None;
+        }
+
+        NaiveDateTime::parse_from_str(datetime, TIMESTAMP_FORMAT)
+            .ok()
+            .map(|datetime| DateValue::TimestampTz(datetime, offset.to_string()))
+    }
+
+    /// shift the date by `duration` - the date is kept as it is when the shifted one is out of range
+    fn shift(self, duration: Duration) -> Self {
+        match self {
+            DateValue::Date(date) => {
+                DateValue::Date(date.checked_add_signed(duration).unwrap_or(date))
+            }
+            DateValue::Timestamp(datetime) => {
+                DateValue::Timestamp(datetime.checked_add_signed(duration).unwrap_or(datetime))
+            }
+            DateValue::TimestampTz(datetime, offset) => DateValue::TimestampTz(
+                datetime.checked_add_signed(duration).unwrap_or(datetime),
+                offset,
+            ),
+        }
+    }
+
+    fn with_datetime(self, new_datetime: NaiveDateTime) -> Self {
+        match self {
+            DateValue::Date(_) => DateValue::Date(new_datetime.date()),
+            DateValue::Timestamp(_) => DateValue::Timestamp(new_datetime),
+            DateValue::TimestampTz(_, offset) => DateValue::TimestampTz(new_datetime, offset),
+        }
+    }
+}
+
+impl Display for DateValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateValue::Date(date) => write!(f, "{}", date.format(DATE_FORMAT)),
+            DateValue::Timestamp(datetime) => write!(f, "{}", datetime.format(TIMESTAMP_FORMAT)),
+            DateValue::TimestampTz(datetime, offset) => {
+                write!(f, "{}{}", datetime.format(TIMESTAMP_FORMAT), offset)
+            }
+        }
+    }
+}
+
+fn entity_value(column: &Column) -> Option<String> {
+    match column {
+        Column::NumberValue(_, value) => Some(value.to_string()),
+        Column::FloatNumberValue(_, value) => Some(value.to_string()),
+        Column::StringValue(_, value) => Some(value.to_string()),
+        Column::CharValue(_, value) => Some(value.to_string()),
+        Column::BooleanValue(_, value) => Some(value.to_string()),
+        Column::DateValue(_, value) => Some(value.to_string()),
+        Column::JsonValue(_, value) => Some(value.to_string()),
+        Column::UuidValue(_, value) => Some(value.to_string()),
+        Column::BytesValue(_, value) => Some(encode_hex(value)),
+        Column::ArrayValue(_, value) => Some(value.to_string()),
+        Column::RawValue(_, value) => Some(value.to_string()),
+        Column::None(_) => None,
+    }
+}
+
+fn random_key() -> DeterministicKey {
+    DeterministicKey::new(
+        rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect::<String>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use crate::transformer::{DeterministicKey, Transformer};
+    use crate::types::Column;
+
+    use super::{DateValue, RandomDateTransformer, RandomDateTransformerOptions};
+
+    #[test]
+    fn parse_date_values() {
+        assert_eq!(
+            DateValue::parse("2022-05-01").unwrap().to_string(),
+            "2022-05-01"
+        );
+        assert_eq!(
+            DateValue::parse("2022-05-01 10:00:00").unwrap().to_string(),
+            "2022-05-01 10:00:00"
+        );
+        assert_eq!(
+            DateValue::parse("2022-05-01 10:00:00.123456")
+                .unwrap()
+                .to_string(),
+            "2022-05-01 10:00:00.123456"
+        );
+        assert_eq!(
+            DateValue::parse("2022-05-01 10:00:00+02").unwrap().to_string(),
+            "2022-05-01 10:00:00+02"
+        );
+        assert_eq!(
+            DateValue::parse("2022-05-01 10:00:00.5-05:30")
+                .unwrap()
+                .to_string(),
+            "2022-05-01 10:00:00.500-05:30"
+        );
+        assert_eq!(DateValue::parse("infinity"), None);
+        assert_eq!(DateValue::parse("0000-00-00"), None);
+        assert_eq!(DateValue::parse("hello world"), None);
+    }
+
+    #[test]
+    fn transform_date_in_range() {
+        let from = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2000, 12, 31).unwrap();
+        let transformer = get_transformer(RandomDateTransformerOptions::Range {
+            from: Some(from),
+            to: Some(to),
+        });
+
+        let column = Column::StringValue("created_at".to_string(), "2022-05-01".to_string());
+        let transformed_column = transformer.transform(column);
+        let transformed_value = transformed_column.string_value().unwrap();
+        let date = NaiveDate::parse_from_str(transformed_value, "%Y-%m-%d").unwrap();
+
+        assert!(date >= from && date <= to);
+
+        let column = Column::StringValue(
+            "created_at".to_string(),
+            "2022-05-01 10:00:00+02".to_string(),
+        );
+        let transformed_column = transformer.transform(column);
+        let transformed_value = transformed_column.string_value().unwrap();
+
+        assert!(transformed_value.starts_with("2000-"));
+        assert!(transformed_value.ends_with("+02"));
+    }
+
+    #[test]
+    fn transform_typed_date() {
+        let transformer = get_transformer(RandomDateTransformerOptions::Shift { days: 10 });
+
+        let column = Column::DateValue("created_at".to_string(), "2022-05-15".to_string());
+        let transformed_column = transformer.transform(column);
+        let transformed_value = transformed_column.date_value().unwrap();
+
+        assert!(transformed_value >= "2022-05-05");
+        assert!(transformed_value <= "2022-05-25");
+    }
+
+    #[test]
+    fn transform_date_with_shift() {
+        let transformer = get_transformer(RandomDateTransformerOptions::Shift { days: 10 });
+
+        let column = Column::StringValue(
+            "created_at".to_string(),
+            "2022-05-15 10:00:00".to_string(),
+        );
+        let transformed_column = transformer.transform(column);
+        let transformed_value = transformed_column.string_value().unwrap();
+
+        assert!(transformed_value >= "2022-05-05 10:00:00");
+        assert!(transformed_value <= "2022-05-25 10:00:00");
+        assert!(transformed_value.ends_with("10:00:00"));
+    }
+
+    #[test]
+    fn transform_date_with_entity_shift() {
+        let transformer = RandomDateTransformer::new(
+            "github",
+            "events",
+            "created_at",
+            RandomDateTransformerOptions::EntityShift {
+                days: 365,
+                entity_column: "user_id".to_string(),
+            },
+            Some(DeterministicKey::new("secret")),
+        );
+
+        let first_event = transformer.transform_with_row(
+            Column::StringValue("created_at".to_string(), "2022-05-01".to_string()),
+            &[Column::NumberValue("user_id".to_string(), 42)],
+        );
+        let first_event =
+            NaiveDate::parse_from_str(first_event.string_value().unwrap(), "%Y-%m-%d").unwrap();
+
+        let second_event = transformer.transform_with_row(
+            Column::StringValue("created_at".to_string(), "2022-05-11".to_string()),
+            &[Column::NumberValue("user_id".to_string(), 42)],
+        );
+        let second_event =
+            NaiveDate::parse_from_str(second_event.string_value().unwrap(), "%Y-%m-%d").unwrap();
+
+        // the interval between the events of the same user is preserved
+        assert_eq!((second_event - first_event).num_days(), 10);
+    }
+
+    #[test]
+    fn transform_date_of_the_same_entity_in_several_columns() {
+        let options = RandomDateTransformerOptions::EntityShift {
+            days: 365,
+            entity_column: "user_id".to_string(),
+        };
+        let created_at =
+            RandomDateTransformer::new("github", "users", "created_at", options.clone(), None);
+        let updated_at = RandomDateTransformer::new("github", "users", "updated_at", options, None);
+        let row = [Column::NumberValue("user_id".to_string(), 42)];
+
+        let created_at = created_at.transform_with_row(
+            Column::StringValue("created_at".to_string(), "2022-05-01".to_string()),
+            &row,
+        );
+        let created_at =
+            NaiveDate::parse_from_str(created_at.string_value().unwrap(), "%Y-%m-%d").unwrap();
+
+        let updated_at = updated_at.transform_with_row(
+            Column::StringValue("updated_at".to_string(), "2022-05-11".to_string()),
+            &row,
+        );
+        let updated_at =
+            NaiveDate::parse_from_str(updated_at.string_value().unwrap(), "%Y-%m-%d").unwrap();
+
+        // without deterministic key, the columns of a dump run share the same shift
+        assert_eq!((updated_at - created_at).num_days(), 10);
+    }
+
+    #[test]
+    fn shift_out_of_range_dates() {
+        let date = DateValue::parse("2022-05-01").unwrap();
+        assert_eq!(
+            date.shift(Duration::days(i32::MAX as i64)).to_string(),
+            "2022-05-01"
+        );
+    }
+
+    #[test]
+    fn transform_non_date_values() {
+        let transformer = get_transformer(RandomDateTransformerOptions::default());
+
+        let column = Column::StringValue("created_at".to_string(), "infinity".to_string());
+        let transformed_column = transformer.transform(column);
+        assert_eq!(transformed_column.string_value().unwrap(), "infinity");
+
+        let column = Column::None("created_at".to_string());
+        let transformed_column = transformer.transform(column);
+        assert!(matches!(transformed_column, Column::None(_)));
+    }
+
+    fn get_transformer(options: RandomDateTransformerOptions) -> RandomDateTransformer {
+        RandomDateTransformer::new("github", "users", "created_at", options, None)
+    }
+}
@@ -36,6 +36,17 @@ pub enum Column {
     StringValue(String, String),
     CharValue(String, char),
     BooleanValue(String, bool),
+    /// date, time or timestamp - as it is written in the dump, e.g. `2022-05-01 10:00:00+02`
+    DateValue(String, String),
+    /// JSON document - as it is written in the dump
+    JsonValue(String, String),
+    UuidValue(String, String),
+    BytesValue(String, Vec<u8>),
+    /// PostgreSQL array literal - as it is written in the dump, e.g. `{1,2,3}`
+    ArrayValue(String, String),
+    /// value which can't be typed (e.g. a function call) - written back as it is
+    RawValue(String, String),
+    /// NULL
     None(String),
 }
 
@@ -47,6 +58,12 @@ impl Column {
             Column::StringValue(name, _) => name.as_str(),
             Column::CharValue(name, _) => name.as_str(),
             Column::BooleanValue(name, _) => name.as_str(),
+            Column::DateValue(name, _) => name.as_str(),
+            Column::JsonValue(name, _) => name.as_str(),
+            Column::UuidValue(name, _) => name.as_str(),
+            Column::BytesValue(name, _) => name.as_str(),
+            Column::ArrayValue(name, _) => name.as_str(),
+            Column::RawValue(name, _) => name.as_str(),
             Column::None(name) => name.as_str(),
         }
     }
@@ -85,4 +102,137 @@ impl Column {
             _ => None,
         }
     }
+
+    pub fn date_value(&self) -> Option<&str> {
+        match self {
+            Column::DateValue(_, value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn json_value(&self) -> Option<&str> {
+        match self {
+            Column::JsonValue(_, value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn uuid_value(&self) -> Option<&str> {
+        match self {
+            Column::UuidValue(_, value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn bytes_value(&self) -> Option<&[u8]> {
+        match self {
+            Column::BytesValue(_, value) => Some(value.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn array_value(&self) -> Option<&str> {
+        match self {
+            Column::ArrayValue(_, value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Type of a column, from the type declared in its `CREATE TABLE` query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnType {
+    Number,
+    FloatNumber,
+    Boolean,
+    String,
+    Date,
+    Json,
+    Uuid,
+    Bytes,
+    Array,
+    Unknown,
+}
+
+impl ColumnType {
+    /// `sql_type` is a PostgreSQL or MySQL type without its modifiers, e.g. `character varying`
+    pub fn from_sql_type(sql_type: &str) -> Self {
+        let sql_type = sql_type.to_lowercase();
+
+        if sql_type.ends_with("[]") {
+            return ColumnType::Array;
+        }
+
+        // MySQL `int unsigned`, `double precision`...
+        let base_type = sql_type.split_whitespace().next().unwrap_or_default();
+
+        match base_type {
+            "smallint" | "integer" | "int" | "bigint" | "int2" | "int4" | "int8" | "tinyint"
+            | "mediumint" | "smallserial" | "serial" | "bigserial" | "serial2" | "serial4"
+            | "serial8" | "year" => ColumnType::Number,
+            "real" | "float" | "float4" | "float8" | "double" | "numeric" | "decimal" | "dec" => {
+                ColumnType::FloatNumber
+            }
+            "boolean" | "bool" => ColumnType::Boolean,
+            "char" | "character" | "varchar" | "nchar" | "nvarchar" | "text" | "bpchar"
+            | "citext" | "tinytext" | "mediumtext" | "longtext" | "enum" | "set" => {
+                ColumnType::String
+            }
+            "date" | "time" | "timetz" | "timestamp" | "timestamptz" | "datetime" => {
+                ColumnType::Date
+            }
+            "json" | "jsonb" => ColumnType::Json,
+            "uuid" => ColumnType::Uuid,
+            "bytea" | "binary" | "varbinary" | "blob" | "tinyblob" | "mediumblob" | "longblob" => {
+                ColumnType::Bytes
+            }
+            _ => ColumnType::Unknown,
+        }
+    }
+}
+
+/// Encode bytes in lowercase hexadecimal
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{encode_hex, ColumnType};
+
+    #[test]
+    fn column_type_from_sql_type() {
+        assert_eq!(ColumnType::from_sql_type("integer"), ColumnType::Number);
+        assert_eq!(
+            ColumnType::from_sql_type("int unsigned"),
+            ColumnType::Number
+        );
+        assert_eq!(
+            ColumnType::from_sql_type("double precision"),
+            ColumnType::FloatNumber
+        );
+        assert_eq!(
+            ColumnType::from_sql_type("character varying"),
+            ColumnType::String
+        );
+        assert_eq!(
+            ColumnType::from_sql_type("timestamp with time zone"),
+            ColumnType::Date
+        );
+        assert_eq!(ColumnType::from_sql_type("DATETIME"), ColumnType::Date);
+        assert_eq!(ColumnType::from_sql_type("jsonb"), ColumnType::Json);
+        assert_eq!(ColumnType::from_sql_type("uuid"), ColumnType::Uuid);
+        assert_eq!(ColumnType::from_sql_type("bytea"), ColumnType::Bytes);
+        assert_eq!(ColumnType::from_sql_type("integer[]"), ColumnType::Array);
+        assert_eq!(
+            ColumnType::from_sql_type("public.mood"),
+            ColumnType::Unknown
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(encode_hex(&[0x01, 0xab, 0xff]), "01abff");
+        assert_eq!(encode_hex(&[]), "");
+    }
 }
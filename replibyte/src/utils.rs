@@ -44,6 +44,15 @@ pub fn binary_exists(binary_name: &str) -> Result<(), Error> {
     Ok(())
 }
 
+// stop a process which does not need to run until its end
+pub fn kill_command(process: &mut Child) -> Result<(), Error> {
+    // the process may have already exited
+    let _ = process.kill();
+    let _ = process.wait()?;
+
+    Ok(())
+}
+
 // wait for the end of a process and handle errors
 pub fn wait_for_command(process: &mut Child) -> Result<(), Error> {
     match process.wait() {
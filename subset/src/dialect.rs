@@ -0,0 +1,805 @@
+use crate::dedup::does_line_exist_and_set;
+use crate::predicate::{matches_all, Predicate};
+use crate::{
+    child_relations_by_database_and_table_name, utils, PassthroughTable, Progress, Subset,
+    SubsetOptions, SubsetStrategy, SubsetTable, SubsetTableRelation,
+};
+use dump_parser::utils::{list_sql_queries_from_dump_reader, ListQueryResult};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind, Read};
+use std::marker::PhantomData;
+use std::path::Path;
+
+pub type Database = String;
+pub type Table = String;
+
+#[derive(Debug)]
+pub struct ForeignKey {
+    pub from_database: String,
+    pub from_table: String,
+    pub from_property: String,
+    pub to_database: String,
+    pub to_table: String,
+    pub to_property: String,
+}
+
+pub(crate) struct TableStats {
+    pub(crate) database: String,
+    pub(crate) table: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) total_rows: usize,
+    pub(crate) first_insert_into_row_index: usize,
+    pub(crate) last_insert_into_row_index: usize,
+}
+
+/// Parsing hooks of a database dump - the subset algorithm is the same for every database.
+/// `database` is the current database of the dump when the query is read: the database of the
+/// last `USE <database>;` statement, or the database of the subset strategy.
+pub trait Dialect {
+    type Tokens;
+
+    fn tokenize(query: &str) -> Self::Tokens;
+
+    /// the database set by a `USE <database>;` statement
+    fn use_database_name(_query: &str) -> Option<Database> {
+        None
+    }
+
+    fn create_table_name(tokens: &Self::Tokens, database: &str) -> Option<(Database, Table)>;
+
+    fn insert_into_table_name(tokens: &Self::Tokens, database: &str) -> Option<(Database, Table)>;
+
+    fn insert_into_column_names(tokens: &Self::Tokens) -> Vec<String>;
+
+    fn insert_into_column_values(tokens: &Self::Tokens) -> Vec<String>;
+
+    /// the foreign keys declared by the query
+    fn foreign_keys(tokens: &Self::Tokens, database: &str) -> Vec<ForeignKey>;
+}
+
+pub struct DumpSubset<'a, D: Dialect> {
+    subset_table_by_database_and_table_name: HashMap<(Database, Table), SubsetTable>,
+    child_relations_by_database_and_table_name:
+        HashMap<(Database, Table), Vec<SubsetTableRelation>>,
+    dump: &'a Path,
+    subset_strategy: SubsetStrategy<'a>,
+    subset_options: SubsetOptions<'a>,
+    dialect: PhantomData<D>,
+}
+
+impl<'a, D: Dialect> DumpSubset<'a, D> {
+    pub fn new(
+        dump: &'a Path,
+        subset_strategy: SubsetStrategy<'a>,
+        subset_options: SubsetOptions<'a>,
+    ) -> Result<Self, Error> {
+        let subset_table_by_database_and_table_name =
+            get_subset_table_by_database_and_table_name::<D, _>(
+                BufReader::new(File::open(dump)?),
+                subset_strategy.database(),
+            )?;
+
+        Ok(DumpSubset {
+            child_relations_by_database_and_table_name: child_relations_by_database_and_table_name(
+                subset_table_by_database_and_table_name.values(),
+            ),
+            subset_table_by_database_and_table_name,
+            dump,
+            subset_strategy,
+            subset_options,
+            dialect: PhantomData,
+        })
+    }
+
+    fn dump_reader(&self) -> BufReader<File> {
+        BufReader::new(File::open(self.dump).unwrap())
+    }
+
+    /// database of the rows that are not preceded by a `USE <database>;` statement
+    fn default_database(&self) -> &str {
+        self.subset_strategy.database()
+    }
+
+    fn reference_rows(
+        &self,
+        table_stats: &HashMap<(Database, Table), TableStats>,
+    ) -> Result<Vec<String>, Error> {
+        let reference_table_stats = |database: &str, table: &str| {
+            table_stats
+                .get(&(database.to_string(), table.to_string()))
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("table {}.{} does not exist in the dump", database, table),
+                    )
+                })
+        };
+
+        match &self.subset_strategy {
+            SubsetStrategy::RandomPercent {
+                database,
+                table,
+                percent,
+                seed,
+            } => {
+                let table_stats = reference_table_stats(database, table)?;
+
+                Ok(list_random_insert_into_rows::<D, _>(
+                    utils::percent_of_rows(table_stats.total_rows, *percent),
+                    *seed,
+                    table_stats,
+                    self.dump_reader(),
+                    self.default_database(),
+                )?)
+            }
+            SubsetStrategy::RandomRows {
+                database,
+                table,
+                rows,
+                seed,
+            } => Ok(list_random_insert_into_rows::<D, _>(
+                *rows,
+                *seed,
+                reference_table_stats(database, table)?,
+                self.dump_reader(),
+                self.default_database(),
+            )?),
+            SubsetStrategy::Predicate {
+                database,
+                table,
+                column,
+                predicates,
+            } => Ok(list_predicate_insert_into_rows::<D, _>(
+                column,
+                predicates,
+                reference_table_stats(database, table)?,
+                self.dump_reader(),
+                self.default_database(),
+            )?),
+        }
+    }
+
+    /// `database` is the database of the row.
+    /// `child_depth` is the number of relations followed from a reference row to its children to reach this row.
+    /// It is `None` when the row has been reached from a child row - children of referenced rows are not followed.
+    fn visits<F: FnMut(String)>(
+        &self,
+        database: &str,
+        row: String,
+        child_depth: Option<usize>,
+        table_stats: &HashMap<(Database, Table), TableStats>,
+        data: &mut F,
+    ) -> Result<(), Error> {
+        // tokenize `INSERT INTO ...` row
+        let row_tokens = D::tokenize(row.as_str());
+
+        // find the database and table names from this row
+        let (row_database, row_table) = match D::insert_into_table_name(&row_tokens, database) {
+            Some(database_and_table) => database_and_table,
+            None => return Ok(()),
+        };
+
+        if self.subset_options.passthrough_tables.is_empty()
+            || !self
+                .subset_options
+                .passthrough_tables
+                .contains(&PassthroughTable::new(
+                    row_database.as_str(),
+                    row_table.as_str(),
+                ))
+        {
+            // only insert if the row is not from passthrough tables list
+            // otherwise we'll have duplicated rows
+            data(format!("{}\n", row));
+        }
+
+        // find the subset table from this row
+        let row_subset_table = match self
+            .subset_table_by_database_and_table_name
+            .get(&(row_database.to_string(), row_table.to_string()))
+        {
+            Some(subset_table) => subset_table,
+            None => return Ok(()),
+        };
+
+        let row_column_names = D::insert_into_column_names(&row_tokens);
+        let row_column_values = D::insert_into_column_values(&row_tokens);
+
+        for row_relation in &row_subset_table.relations {
+            let column = row_relation.from_property.as_str();
+            // find the value from the current row for the relation column
+            let column_idx = match row_column_names.iter().position(|x| *x == column) {
+                Some(idx) => idx,
+                None => continue,
+            };
+
+            let value = row_column_values.get(column_idx).unwrap();
+
+            if value == "NULL" {
+                // nothing is referenced
+                continue;
+            }
+
+            let database_and_table_tuple =
+                (row_relation.database.clone(), row_relation.table.clone());
+
+            // find the table stats for this row
+            let row_relation_table_stats = match table_stats.get(&database_and_table_tuple) {
+                Some(table_stats) => table_stats,
+                None => continue, // the referenced table has not been dumped
+            };
+
+            // TODO break acyclic graph
+            let row_clb = |row: &str| match self.visits(
+                row_relation.database.as_str(),
+                row.to_string(),
+                None,
+                table_stats,
+                data,
+            ) {
+                Ok(_) => {}
+                Err(err) => {
+                    panic!("{}", err);
+                }
+            };
+
+            let _ = filter_insert_into_rows::<D, _, _>(
+                row_relation.to_property.as_str(),
+                value.as_str(),
+                self.dump_reader(),
+                self.default_database(),
+                row_relation_table_stats,
+                row_clb,
+            )?;
+        }
+
+        let child_depth = match child_depth {
+            Some(child_depth) if child_depth < self.subset_options.children_max_depth => {
+                child_depth
+            }
+            _ => return Ok(()),
+        };
+
+        let child_relations = match self
+            .child_relations_by_database_and_table_name
+            .get(&(row_database.to_string(), row_table.to_string()))
+        {
+            Some(child_relations) => child_relations,
+            None => return Ok(()),
+        };
+
+        for child_relation in child_relations {
+            let column = child_relation.from_property.as_str();
+            // find the value from the current row referenced by the children
+            let value = match row_column_names.iter().position(|x| *x == column) {
+                Some(column_idx) => row_column_values.get(column_idx).unwrap(),
+                None => continue,
+            };
+
+            // find the table stats for the children - the table can be empty
+            let child_table_stats = match table_stats.get(&(
+                child_relation.database.clone(),
+                child_relation.table.clone(),
+            )) {
+                Some(child_table_stats) if child_table_stats.total_rows > 0 => child_table_stats,
+                _ => continue,
+            };
+
+            let row_clb = |row: &str| match self.visits(
+                child_relation.database.as_str(),
+                row.to_string(),
+                Some(child_depth + 1),
+                table_stats,
+                data,
+            ) {
+                Ok(_) => {}
+                Err(err) => {
+                    panic!("{}", err);
+                }
+            };
+
+            let _ = filter_insert_into_rows::<D, _, _>(
+                child_relation.to_property.as_str(),
+                value.as_str(),
+                self.dump_reader(),
+                self.default_database(),
+                child_table_stats,
+                row_clb,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, D: Dialect> Subset for DumpSubset<'a, D> {
+    /// Return every subset rows
+    /// Algorithm used:
+    /// 1. find the reference table and take the X rows from this table with the appropriate SubsetStrategy
+    /// 2. iterate over each row and their relations (0 to N relations)
+    /// 3. for each rows from each relations, filter on the id from the parent related row id. (equivalent `SELECT * FROM table_1 INNER JOIN ... WHERE table_1.id = 'xxx';`
+    /// 4. do it recursively for table_1.relations[*].relations[*]... but the algo stops when reaching the end or reach a cyclic ref.
+    /// 5. if `children_max_depth` is set, do the same from each reference row to the rows referencing it (children) - and their children up to the max depth.
+    ///
+    /// Notes:
+    /// a. the algo must visits all the tables, even the one that has no relations.
+    fn read<F: FnMut(String), P: FnMut(Progress)>(
+        &self,
+        mut data: F,
+        progress: P,
+    ) -> Result<(), Error> {
+        let temp_dir = tempfile::tempdir()?;
+
+        let _ = read(
+            self,
+            |line| {
+                let group_hash =
+                    match get_insert_into_md5_hash::<D>(line.as_str(), self.default_database()) {
+                        Some(group_hash) => group_hash,
+                        None => {
+                            data(line);
+                            return;
+                        }
+                    };
+
+                // Dedup INSERT INTO queries
+                // check if the line has not already been sent
+                match does_line_exist_and_set(temp_dir.path(), &group_hash, line.as_str()) {
+                    Ok(does_line_exist) => {
+                        if !does_line_exist {
+                            data(line);
+                        }
+                    }
+                    Err(err) => {
+                        panic!("{}", err);
+                    }
+                }
+            },
+            progress,
+        )?;
+
+        Ok(())
+    }
+}
+
+fn read<D: Dialect, F: FnMut(String), P: FnMut(Progress)>(
+    dump_subset: &DumpSubset<D>,
+    mut data: F,
+    mut progress: P,
+) -> Result<(), Error> {
+    let table_stats = table_stats_by_database_and_table_name::<D, _>(
+        dump_subset.dump_reader(),
+        dump_subset.default_database(),
+    )?;
+    let rows = dump_subset.reference_rows(&table_stats)?;
+
+    let table_stats_values = table_stats.values().collect::<Vec<_>>();
+    let first_footer_row_idx = first_footer_row_idx(&table_stats_values);
+
+    // send schema header
+    let _ = dump_header(dump_subset.dump_reader(), first_footer_row_idx, |row| {
+        data(row.to_string());
+    })?;
+
+    let total_rows = table_stats_values
+        .iter()
+        .fold(0usize, |acc, y| acc + y.total_rows);
+
+    let total_rows_to_process = rows.len();
+    let mut processed_rows = 0usize;
+
+    progress(Progress {
+        total_rows,
+        total_rows_to_process,
+        processed_rows,
+        last_process_time: 0,
+    });
+
+    let reference_database = dump_subset.subset_strategy.database();
+
+    // send INSERT INTO rows
+    for row in rows {
+        let start_time = utils::epoch_millis();
+        let _ = dump_subset.visits(reference_database, row, Some(0), &table_stats, &mut data)?;
+
+        processed_rows += 1;
+
+        progress(Progress {
+            total_rows,
+            total_rows_to_process,
+            processed_rows,
+            last_process_time: utils::epoch_millis() - start_time,
+        });
+    }
+
+    for passthrough_table in dump_subset.subset_options.passthrough_tables {
+        // copy all rows from passthrough tables
+        for table_stats in &table_stats_values {
+            if table_stats.database.as_str() == passthrough_table.database
+                && table_stats.table.as_str() == passthrough_table.table
+            {
+                let _ = list_insert_into_rows::<D, _, _>(
+                    dump_subset.dump_reader(),
+                    dump_subset.default_database(),
+                    table_stats,
+                    |row| {
+                        data(row.to_string());
+                    },
+                )?;
+            }
+        }
+    }
+
+    // send schema footer
+    let _ = dump_footer(dump_subset.dump_reader(), first_footer_row_idx, |row| {
+        data(row.to_string());
+    })?;
+
+    Ok(())
+}
+
+/// group of the `INSERT INTO ...` row to dedup - `None` if the line is not an `INSERT INTO ...` row
+fn get_insert_into_md5_hash<D: Dialect>(query: &str, default_database: &str) -> Option<String> {
+    if !is_insert_into_query(query) {
+        return None;
+    }
+
+    let tokens = D::tokenize(query);
+    let (database, table) = D::insert_into_table_name(&tokens, default_database)?;
+    let key = format!("{}-{}", database, table);
+    let digest = md5::compute(key.as_bytes());
+    Some(format!("{:x}", digest))
+}
+
+/// list the dump queries with the database they belong to.
+/// a dump sets the current database with a `USE <database>;` statement when it contains several databases.
+fn list_sql_queries_with_database_from_dump_reader<D: Dialect, R: Read, F>(
+    dump_reader: BufReader<R>,
+    default_database: &str,
+    mut query: F,
+) -> Result<(), Error>
+where
+    F: FnMut(&str, &str) -> ListQueryResult,
+{
+    let mut database = default_database.to_string();
+
+    let _ = list_sql_queries_from_dump_reader(dump_reader, |q| {
+        if let Some(use_database) = D::use_database_name(q) {
+            database = use_database;
+        }
+
+        query(database.as_str(), q)
+    })?;
+
+    Ok(())
+}
+
+/// pick `rows` random rows from the table - the same seed always picks the same rows
+pub(crate) fn list_random_insert_into_rows<D: Dialect, R: Read>(
+    rows: usize,
+    seed: Option<u64>,
+    table_stats: &TableStats,
+    dump_reader: BufReader<R>,
+    default_database: &str,
+) -> Result<Vec<String>, Error> {
+    let mut insert_into_rows = vec![];
+
+    if rows == 0 || table_stats.total_rows == 0 {
+        return Ok(insert_into_rows);
+    }
+
+    let row_indexes = utils::random_row_indexes(table_stats.total_rows, rows, seed);
+
+    let mut row_idx = 0usize;
+    let _ = list_insert_into_rows::<D, _, _>(dump_reader, default_database, table_stats, |row| {
+        if row_indexes.contains(&row_idx) {
+            insert_into_rows.push(row.to_string());
+        }
+
+        row_idx += 1;
+    })?;
+
+    Ok(insert_into_rows)
+}
+
+pub(crate) fn list_predicate_insert_into_rows<D: Dialect, R: Read>(
+    column: &str,
+    predicates: &[Predicate],
+    table_stats: &TableStats,
+    dump_reader: BufReader<R>,
+    default_database: &str,
+) -> Result<Vec<String>, Error> {
+    let column_idx = match table_stats
+        .columns
+        .iter()
+        .position(|r| r.as_str() == column)
+    {
+        Some(idx) => idx,
+        None => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "table {} does not contain column {}",
+                    table_stats.table, column
+                ),
+            ));
+        }
+    };
+
+    let mut insert_into_rows = vec![];
+    let _ = list_insert_into_rows::<D, _, _>(dump_reader, default_database, table_stats, |row| {
+        let tokens = D::tokenize(row);
+        let column_values = D::insert_into_column_values(&tokens);
+
+        if let Some(value) = column_values.get(column_idx) {
+            if matches_all(predicates, value.as_str()) {
+                insert_into_rows.push(row.to_string());
+            }
+        }
+    })?;
+
+    Ok(insert_into_rows)
+}
+
+fn list_insert_into_rows<D: Dialect, R: Read, F: FnMut(&str)>(
+    dump_reader: BufReader<R>,
+    default_database: &str,
+    table_stats: &TableStats,
+    mut rows: F,
+) -> Result<(), Error> {
+    let _ = filter_table_insert_into_rows::<D, _, _>(
+        dump_reader,
+        default_database,
+        table_stats,
+        |query, _tokens| rows(query),
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn filter_insert_into_rows<D: Dialect, R: Read, F: FnMut(&str)>(
+    column: &str,
+    value: &str,
+    dump_reader: BufReader<R>,
+    default_database: &str,
+    table_stats: &TableStats,
+    mut rows: F,
+) -> Result<(), Error> {
+    let column_idx = match table_stats
+        .columns
+        .iter()
+        .position(|r| r.as_str() == column)
+    {
+        Some(idx) => idx,
+        None => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "table {} does not contain column {}",
+                    table_stats.table, column
+                ),
+            ));
+        }
+    };
+
+    let _ = filter_table_insert_into_rows::<D, _, _>(
+        dump_reader,
+        default_database,
+        table_stats,
+        |query, tokens| {
+            let column_values = D::insert_into_column_values(tokens);
+
+            if column_values.get(column_idx).map(|x| x.as_str()) == Some(value) {
+                rows(query)
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// send the `INSERT INTO ...` rows of the table with their tokens
+fn filter_table_insert_into_rows<D: Dialect, R: Read, F: FnMut(&str, &D::Tokens)>(
+    dump_reader: BufReader<R>,
+    default_database: &str,
+    table_stats: &TableStats,
+    mut rows: F,
+) -> Result<(), Error> {
+    let mut query_idx = 0usize;
+    let _ = list_sql_queries_with_database_from_dump_reader::<D, _, _>(
+        dump_reader,
+        default_database,
+        |database, query| {
+            let mut query_res = ListQueryResult::Continue;
+
+            // optimization to avoid tokenizing unnecessary queries -- it's a 13x optim (benched)
+            if query_idx >= table_stats.first_insert_into_row_index
+                && query_idx <= table_stats.last_insert_into_row_index
+            {
+                let tokens = D::tokenize(query);
+
+                if let Some((row_database, row_table)) =
+                    D::insert_into_table_name(&tokens, database)
+                {
+                    if row_database == table_stats.database && row_table == table_stats.table {
+                        rows(query, &tokens);
+                    }
+                }
+            }
+
+            if query_idx > table_stats.last_insert_into_row_index {
+                // early break to avoid parsing the dump while we have already parsed all the table rows
+                query_res = ListQueryResult::Break;
+            }
+
+            query_idx += 1;
+            query_res
+        },
+    )?;
+
+    Ok(())
+}
+
+/// return the first row index from dump footer (with generated table stats)
+pub(crate) fn first_footer_row_idx(table_stats_values: &[&TableStats]) -> usize {
+    table_stats_values
+        .iter()
+        .max_by_key(|ts| ts.last_insert_into_row_index)
+        .map(|ts| ts.last_insert_into_row_index)
+        .unwrap_or(0)
+        + 1
+}
+
+/// Get dump header - everything that is not an `INSERT INTO ...` row before the last `INSERT INTO ...` row
+/// pg_dump exports the `CREATE TABLE ...` rows before all the `INSERT INTO ...` rows,
+/// while mysqldump exports each table one after the other: `CREATE TABLE ...` and then its `INSERT INTO ...` rows.
+/// this function return all the `CREATE TABLE ...` rows, and the statements disabling the foreign key checks.
+pub(crate) fn dump_header<R: Read, F: FnMut(&str)>(
+    dump_reader: BufReader<R>,
+    first_footer_row_idx: usize,
+    mut rows: F,
+) -> Result<(), Error> {
+    let mut query_idx = 0usize;
+    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
+        let mut query_res = ListQueryResult::Continue;
+
+        if query_idx < first_footer_row_idx && !is_insert_into_query(query) {
+            rows(query)
+        }
+
+        if query_idx >= first_footer_row_idx {
+            query_res = ListQueryResult::Break;
+        }
+
+        query_idx += 1;
+        query_res
+    })?;
+
+    Ok(())
+}
+
+/// Get dump footer - everything after the last `INSERT INTO ...` row
+/// this function return the `ALTER TABLE ...` rows of pg_dump, and the statements restoring the foreign key checks of mysqldump.
+pub(crate) fn dump_footer<R: Read, F: FnMut(&str)>(
+    dump_reader: BufReader<R>,
+    first_footer_row_idx: usize,
+    mut rows: F,
+) -> Result<(), Error> {
+    let mut query_idx = 0usize;
+    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
+        if query_idx >= first_footer_row_idx {
+            rows(query)
+        }
+
+        query_idx += 1;
+        ListQueryResult::Continue
+    })?;
+
+    Ok(())
+}
+
+fn is_insert_into_query(query: &str) -> bool {
+    let query = query.trim_start();
+    query.len() > 11 && query[0..11].eq_ignore_ascii_case("INSERT INTO")
+}
+
+pub(crate) fn table_stats_by_database_and_table_name<D: Dialect, R: Read>(
+    dump_reader: BufReader<R>,
+    default_database: &str,
+) -> Result<HashMap<(Database, Table), TableStats>, Error> {
+    let mut table_stats_by_database_and_table_name =
+        HashMap::<(Database, Table), TableStats>::new();
+
+    let mut query_idx = 0usize;
+    let _ = list_sql_queries_with_database_from_dump_reader::<D, _, _>(
+        dump_reader,
+        default_database,
+        |database, query| {
+            let tokens = D::tokenize(query);
+
+            if let Some((database, table)) = D::create_table_name(&tokens, database) {
+                let _ = table_stats_by_database_and_table_name.insert(
+                    (database.clone(), table.clone()),
+                    TableStats {
+                        database,
+                        table,
+                        columns: vec![],
+                        total_rows: 0,
+                        first_insert_into_row_index: 0,
+                        last_insert_into_row_index: 0,
+                    },
+                );
+            }
+
+            if let Some(database_and_table) = D::insert_into_table_name(&tokens, database) {
+                match table_stats_by_database_and_table_name.get_mut(&database_and_table) {
+                    Some(table_stats) => {
+                        if table_stats.total_rows == 0 {
+                            // I assume that the INSERT INTO row has all the column set
+                            table_stats.columns = D::insert_into_column_names(&tokens);
+                        }
+
+                        if table_stats.first_insert_into_row_index == 0 {
+                            table_stats.first_insert_into_row_index = query_idx;
+                        }
+
+                        table_stats.last_insert_into_row_index = query_idx;
+                        table_stats.total_rows += 1;
+                    }
+                    None => {
+                        // should not happen because INSERT INTO must come after CREATE TABLE
+                        println!("Query: {}", query);
+                        panic!("Unexpected: INSERT INTO happened before CREATE TABLE while creating table_stats structure")
+                    }
+                }
+            }
+
+            query_idx += 1;
+            ListQueryResult::Continue
+        },
+    )?;
+
+    Ok(table_stats_by_database_and_table_name)
+}
+
+pub(crate) fn get_subset_table_by_database_and_table_name<D: Dialect, R: Read>(
+    dump_reader: BufReader<R>,
+    default_database: &str,
+) -> Result<HashMap<(Database, Table), SubsetTable>, Error> {
+    let mut subset_table_by_database_and_table_name =
+        HashMap::<(Database, Table), SubsetTable>::new();
+
+    list_sql_queries_with_database_from_dump_reader::<D, _, _>(
+        dump_reader,
+        default_database,
+        |database, query| {
+            let tokens = D::tokenize(query);
+
+            if let Some((database, table)) = D::create_table_name(&tokens, database) {
+                // add table into index
+                let _ = subset_table_by_database_and_table_name.insert(
+                    (database.clone(), table.clone()),
+                    SubsetTable::new(database, table, vec![]),
+                );
+            }
+
+            for fk in D::foreign_keys(&tokens, database) {
+                if let Some(subset_table) = subset_table_by_database_and_table_name
+                    .get_mut(&(fk.from_database, fk.from_table))
+                {
+                    subset_table.relations.push(SubsetTableRelation::new(
+                        fk.to_database,
+                        fk.to_table,
+                        fk.from_property,
+                        fk.to_property,
+                    ));
+                }
+            }
+
+            ListQueryResult::Continue
+        },
+    )?;
+
+    Ok(subset_table_by_database_and_table_name)
+}
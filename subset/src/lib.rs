@@ -1,12 +1,110 @@
-use std::collections::HashSet;
+use crate::predicate::Predicate;
+use std::collections::{HashMap, HashSet};
 use std::io::Error;
 
 mod dedup;
+pub mod dialect;
+pub mod mysql;
 pub mod postgres;
+pub mod predicate;
 mod utils;
 
 pub type Bytes = Vec<u8>;
 
+pub enum SubsetStrategy<'a> {
+    RandomPercent {
+        database: &'a str,
+        table: &'a str,
+        percent: u8,
+        seed: Option<u64>,
+    },
+    RandomRows {
+        database: &'a str,
+        table: &'a str,
+        rows: usize,
+        seed: Option<u64>,
+    },
+    /// all the rows of the reference table with a column value matching all the predicates
+    Predicate {
+        database: &'a str,
+        table: &'a str,
+        column: &'a str,
+        predicates: Vec<Predicate>,
+    },
+}
+
+impl<'a> SubsetStrategy<'a> {
+    pub fn random(database: &'a str, table: &'a str, percent: u8) -> Self {
+        SubsetStrategy::RandomPercent {
+            database,
+            table,
+            percent,
+            seed: None,
+        }
+    }
+
+    pub fn random_rows(database: &'a str, table: &'a str, rows: usize) -> Self {
+        SubsetStrategy::RandomRows {
+            database,
+            table,
+            rows,
+            seed: None,
+        }
+    }
+
+    /// pick the same random rows for the same seed
+    pub fn with_seed(self, seed: u64) -> Self {
+        match self {
+            SubsetStrategy::RandomPercent {
+                database,
+                table,
+                percent,
+                ..
+            } => SubsetStrategy::RandomPercent {
+                database,
+                table,
+                percent,
+                seed: Some(seed),
+            },
+            SubsetStrategy::RandomRows {
+                database,
+                table,
+                rows,
+                ..
+            } => SubsetStrategy::RandomRows {
+                database,
+                table,
+                rows,
+                seed: Some(seed),
+            },
+            strategy => strategy,
+        }
+    }
+
+    pub fn predicate(
+        database: &'a str,
+        table: &'a str,
+        column: &'a str,
+        predicates: Vec<Predicate>,
+    ) -> Self {
+        SubsetStrategy::Predicate {
+            database,
+            table,
+            column,
+            predicates,
+        }
+    }
+
+    /// the database of the reference table
+    pub fn database(&self) -> &'a str {
+        match self {
+            SubsetStrategy::RandomPercent { database, .. } => *database,
+            SubsetStrategy::RandomRows { database, .. } => *database,
+            SubsetStrategy::Predicate { database, .. } => *database,
+        }
+    }
+}
+
 pub trait Subset {
     fn read<F: FnMut(String), P: FnMut(Progress)>(&self, data: F, progress: P)
         -> Result<(), Error>;
@@ -46,11 +144,24 @@ impl<'a> PassthroughTable<'a> {
 
 pub struct SubsetOptions<'a> {
     pub passthrough_tables: &'a HashSet<PassthroughTable<'a>>,
+    // follow the relations from the referenced rows to the rows referencing them (children) up to this depth
+    // 0 means that only the referenced (parent) rows are followed
+    pub children_max_depth: usize,
 }
 
 impl<'a> SubsetOptions<'a> {
     pub fn new(passthrough_tables: &'a HashSet<PassthroughTable<'a>>) -> Self {
-        SubsetOptions { passthrough_tables }
+        SubsetOptions {
+            passthrough_tables,
+            children_max_depth: 0,
+        }
+    }
+
+    pub fn with_children_max_depth(self, children_max_depth: usize) -> Self {
+        SubsetOptions {
+            children_max_depth,
+            ..self
+        }
     }
 }
 
@@ -121,3 +232,28 @@ impl SubsetTableRelation {
         }
     }
 }
+
+/// Reverse the relations of the subset tables.
+/// Return, by (database, table), the relations to the tables referencing it (children)
+/// where `from_property` is the referenced column and `to_property` the referencing one.
+pub(crate) fn child_relations_by_database_and_table_name<'a, I: Iterator<Item = &'a SubsetTable>>(
+    subset_tables: I,
+) -> HashMap<(String, String), Vec<SubsetTableRelation>> {
+    let mut child_relations = HashMap::<(String, String), Vec<SubsetTableRelation>>::new();
+
+    for subset_table in subset_tables {
+        for relation in &subset_table.relations {
+            child_relations
+                .entry((relation.database.clone(), relation.table.clone()))
+                .or_insert_with(Vec::new)
+                .push(SubsetTableRelation::new(
+                    subset_table.database.as_str(),
+                    subset_table.table.as_str(),
+                    relation.to_property.as_str(),
+                    relation.from_property.as_str(),
+                ));
+        }
+    }
+
+    child_relations
+}
@@ -0,0 +1,530 @@
+use crate::dialect::{Database, Dialect, DumpSubset, ForeignKey, Table};
+use dump_parser::mysql::{
+    get_column_names_from_insert_into_query, get_column_values_str_from_insert_into_query,
+    get_tokens_from_query_str, match_keyword_at_position, trim_pre_whitespaces, Keyword, Token,
+};
+
+/// MySQL dump made by mysqldump:
+/// the tables are not qualified by their database, and the foreign keys are declared inside the `CREATE TABLE ...` statements.
+pub struct Mysql;
+
+pub type MysqlSubset<'a> = DumpSubset<'a, Mysql>;
+
+impl Dialect for Mysql {
+    type Tokens = Vec<Token>;
+
+    fn tokenize(query: &str) -> Vec<Token> {
+        get_tokens(query)
+    }
+
+    fn use_database_name(query: &str) -> Option<Database> {
+        get_use_database_name(query)
+    }
+
+    fn create_table_name(tokens: &Vec<Token>, database: &str) -> Option<(Database, Table)> {
+        get_create_table_table_name(tokens).map(|table| (database.to_string(), table))
+    }
+
+    fn insert_into_table_name(tokens: &Vec<Token>, database: &str) -> Option<(Database, Table)> {
+        get_insert_into_table_name(tokens).map(|table| (database.to_string(), table))
+    }
+
+    fn insert_into_column_names(tokens: &Vec<Token>) -> Vec<String> {
+        get_column_names_from_insert_into_query(tokens)
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+    }
+
+    fn insert_into_column_values(tokens: &Vec<Token>) -> Vec<String> {
+        get_column_values_str_from_insert_into_query(tokens)
+    }
+
+    fn foreign_keys(tokens: &Vec<Token>, database: &str) -> Vec<ForeignKey> {
+        get_create_table_foreign_keys(tokens, database)
+    }
+}
+
+fn get_tokens(query: &str) -> Vec<Token> {
+    trim_pre_whitespaces(get_tokens_from_query_str(query))
+}
+
+fn get_use_database_name(query: &str) -> Option<Database> {
+    let query = query.trim_start();
+
+    if query.len() < 4 || !query[0..4].eq_ignore_ascii_case("USE ") {
+        return None;
+    }
+
+    let database = query[4..]
+        .trim()
+        .trim_end_matches(';')
+        .trim()
+        .trim_matches('`');
+
+    if database.is_empty() {
+        return None;
+    }
+
+    Some(database.to_string())
+}
+
+/// table names are quoted with backticks by mysqldump - they are tokenized as single quoted strings
+fn get_name_at_position(tokens: &Vec<Token>, pos: usize) -> Option<&str> {
+    match tokens.get(pos) {
+        Some(Token::Word(word)) => Some(word.value.as_str()),
+        Some(Token::SingleQuotedString(name)) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+fn get_create_table_table_name(tokens: &Vec<Token>) -> Option<Table> {
+    if match_keyword_at_position(Keyword::Create, &tokens, 0)
+        && match_keyword_at_position(Keyword::Table, &tokens, 2)
+    {
+        return get_name_at_position(&tokens, 4).map(|table| table.to_string());
+    }
+
+    None
+}
+
+fn get_insert_into_table_name(tokens: &Vec<Token>) -> Option<Table> {
+    if match_keyword_at_position(Keyword::Insert, &tokens, 0)
+        && match_keyword_at_position(Keyword::Into, &tokens, 2)
+    {
+        return get_name_at_position(&tokens, 4).map(|table| table.to_string());
+    }
+
+    None
+}
+
+/// find the foreign keys from a `CREATE TABLE ...` statement:
+/// `CONSTRAINT <name> FOREIGN KEY (<column>) REFERENCES [<database>.]<table> (<column>)`
+/// Note: foreign keys on multiple columns are not supported
+fn get_create_table_foreign_keys(tokens: &Vec<Token>, database: &str) -> Vec<ForeignKey> {
+    let from_table = match get_create_table_table_name(tokens) {
+        Some(table) => table,
+        None => return vec![],
+    };
+
+    let tokens = tokens
+        .iter()
+        .filter(|token| !matches!(token, Token::Whitespace(_)))
+        .collect::<Vec<_>>();
+
+    let is_keyword = |idx: usize, keyword: Keyword| match tokens.get(idx) {
+        Some(Token::Word(word)) => word.keyword == keyword,
+        _ => false,
+    };
+
+    // names between the next parenthesis
+    let names_in_parens = |idx: usize| -> (Vec<String>, usize) {
+        let mut names = vec![];
+        let mut idx = idx;
+
+        if !matches!(tokens.get(idx), Some(Token::LParen)) {
+            return (names, idx);
+        }
+
+        idx += 1;
+        while let Some(token) = tokens.get(idx) {
+            idx += 1;
+            match token {
+                Token::RParen => break,
+                Token::Word(word) => names.push(word.value.clone()),
+                Token::SingleQuotedString(name) => names.push(name.clone()),
+                _ => {}
+            }
+        }
+
+        (names, idx)
+    };
+
+    let mut foreign_keys = vec![];
+
+    for idx in 0..tokens.len() {
+        if !is_keyword(idx, Keyword::Foreign) || !is_keyword(idx + 1, Keyword::Key) {
+            continue;
+        }
+
+        let (from_properties, next_idx) = names_in_parens(idx + 2);
+
+        if !is_keyword(next_idx, Keyword::References) {
+            continue;
+        }
+
+        // [<database>.]<table>
+        let mut table_names = vec![];
+        let mut next_idx = next_idx + 1;
+        while let Some(token) = tokens.get(next_idx) {
+            match token {
+                Token::LParen => break,
+                Token::Word(word) => table_names.push(word.value.clone()),
+                Token::SingleQuotedString(name) => table_names.push(name.clone()),
+                _ => {}
+            }
+            next_idx += 1;
+        }
+
+        let (to_properties, _) = names_in_parens(next_idx);
+
+        if from_properties.len() != 1 || to_properties.len() != 1 {
+            continue;
+        }
+
+        let (to_database, to_table) = match table_names.as_slice() {
+            [table] => (database.to_string(), table.clone()),
+            [database, table] => (database.clone(), table.clone()),
+            _ => continue,
+        };
+
+        foreign_keys.push(ForeignKey {
+            from_database: database.to_string(),
+            from_table: from_table.clone(),
+            from_property: from_properties[0].clone(),
+            to_database,
+            to_table,
+            to_property: to_properties[0].clone(),
+        });
+    }
+
+    foreign_keys
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dialect::{
+        dump_footer, dump_header, filter_insert_into_rows, first_footer_row_idx,
+        get_subset_table_by_database_and_table_name, list_predicate_insert_into_rows,
+        list_random_insert_into_rows, table_stats_by_database_and_table_name,
+    };
+    use crate::mysql::{
+        get_create_table_foreign_keys, get_create_table_table_name, get_use_database_name, Mysql,
+        MysqlSubset,
+    };
+    use crate::predicate::Predicate;
+    use crate::utils::percent_of_rows;
+    use crate::{PassthroughTable, Subset, SubsetOptions, SubsetStrategy};
+    use dump_parser::mysql::Tokenizer;
+    use std::collections::HashSet;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::{Path, PathBuf};
+
+    fn dump_path() -> PathBuf {
+        Path::new("db").join("mysql").join("world.sql")
+    }
+
+    fn dump_reader() -> BufReader<File> {
+        BufReader::new(File::open(dump_path()).unwrap())
+    }
+
+    #[test]
+    fn check_statements_with_tokens() {
+        let q = "SELECT * FROM toto;";
+        let tokens = Tokenizer::new(q).tokenize().unwrap();
+        assert_eq!(get_create_table_table_name(&tokens), None);
+        assert!(get_create_table_foreign_keys(&tokens, "world").is_empty());
+
+        let q = r#"CREATE TABLE `city` (
+  `ID` int NOT NULL AUTO_INCREMENT,
+  `Name` char(35) NOT NULL DEFAULT '',
+  `CountryCode` char(3) NOT NULL DEFAULT '',
+  PRIMARY KEY (`ID`),
+  KEY `CountryCode` (`CountryCode`),
+  CONSTRAINT `city_ibfk_1` FOREIGN KEY (`CountryCode`) REFERENCES `country` (`Code`),
+  CONSTRAINT `city_ibfk_2` FOREIGN KEY (`ID`, `Name`) REFERENCES `names` (`ID`, `Name`)
+) ENGINE=InnoDB AUTO_INCREMENT=4080 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;"#;
+
+        let tokens = Tokenizer::new(q).tokenize().unwrap();
+        assert_eq!(
+            get_create_table_table_name(&tokens),
+            Some("city".to_string())
+        );
+
+        // foreign keys on multiple columns are ignored
+        let fks = get_create_table_foreign_keys(&tokens, "world");
+        assert_eq!(fks.len(), 1);
+
+        let fk = fks.first().unwrap();
+        assert_eq!(fk.from_property, "CountryCode".to_string());
+        assert_eq!(fk.to_database, "world".to_string());
+        assert_eq!(fk.to_table, "country".to_string());
+        assert_eq!(fk.to_property, "Code".to_string());
+
+        assert_eq!(
+            get_use_database_name("USE `world`;"),
+            Some("world".to_string())
+        );
+        assert_eq!(
+            get_use_database_name("\nUSE world;"),
+            Some("world".to_string())
+        );
+        assert_eq!(get_use_database_name("UPDATE world SET x = 1;"), None);
+    }
+
+    #[test]
+    fn check_subset_table() {
+        let m = get_subset_table_by_database_and_table_name::<Mysql, _>(dump_reader(), "world")
+            .unwrap();
+        assert_eq!(m.len(), 3);
+
+        let t = m
+            .get(&("world".to_string(), "country".to_string()))
+            .unwrap();
+
+        assert_eq!(t.database, "world".to_string());
+        assert_eq!(t.table, "country".to_string());
+        assert_eq!(t.relations.len(), 0);
+
+        let t = m.get(&("world".to_string(), "city".to_string())).unwrap();
+
+        assert_eq!(t.database, "world".to_string());
+        assert_eq!(t.table, "city".to_string());
+        assert_eq!(t.relations.len(), 1);
+        assert_eq!(t.related_tables().len(), 1);
+        assert!(t.related_tables().contains("country"));
+    }
+
+    #[test]
+    fn check_table_stats() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Mysql, _>(dump_reader(), "world").unwrap();
+        assert_eq!(table_stats.len(), 3);
+
+        let city_table_stats = table_stats
+            .get(&("world".to_string(), "city".to_string()))
+            .unwrap();
+
+        assert_eq!(city_table_stats.total_rows, 4079);
+        assert_eq!(
+            city_table_stats.columns,
+            vec!["ID", "Name", "CountryCode", "District", "Population"]
+        );
+    }
+
+    #[test]
+    fn check_random_rows() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Mysql, _>(dump_reader(), "world").unwrap();
+        let city_table_stats = table_stats
+            .get(&("world".to_string(), "city".to_string()))
+            .unwrap();
+
+        let rows = list_random_insert_into_rows::<Mysql, _>(
+            percent_of_rows(city_table_stats.total_rows, 5),
+            None,
+            city_table_stats,
+            dump_reader(),
+            "world",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 204);
+        assert!(rows.iter().all(|row| row.contains("INSERT INTO `city`")));
+
+        let rows = list_random_insert_into_rows::<Mysql, _>(
+            10,
+            Some(42),
+            city_table_stats,
+            dump_reader(),
+            "world",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 10);
+        assert_eq!(
+            rows,
+            list_random_insert_into_rows::<Mysql, _>(
+                10,
+                Some(42),
+                city_table_stats,
+                dump_reader(),
+                "world"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn check_predicate_of_rows() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Mysql, _>(dump_reader(), "world").unwrap();
+        let city_table_stats = table_stats
+            .get(&("world".to_string(), "city".to_string()))
+            .unwrap();
+
+        let rows = list_predicate_insert_into_rows::<Mysql, _>(
+            "CountryCode",
+            &vec![Predicate::In(vec!["NLD".to_string(), "BEL".to_string()])],
+            city_table_stats,
+            dump_reader(),
+            "world",
+        )
+        .unwrap();
+
+        assert!(!rows.is_empty());
+        assert!(rows
+            .iter()
+            .all(|row| row.contains("'NLD'") || row.contains("'BEL'")));
+
+        let rows = list_predicate_insert_into_rows::<Mysql, _>(
+            "Population",
+            &vec![Predicate::GreaterThan("8000000".to_string())],
+            city_table_stats,
+            dump_reader(),
+            "world",
+        )
+        .unwrap();
+
+        assert!(!rows.is_empty());
+        assert!(rows.len() < 20);
+    }
+
+    #[test]
+    fn check_filter_insert_into_rows() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Mysql, _>(dump_reader(), "world").unwrap();
+        let country_table_stats = table_stats
+            .get(&("world".to_string(), "country".to_string()))
+            .unwrap();
+
+        let mut found_rows = vec![];
+        filter_insert_into_rows::<Mysql, _, _>(
+            "Code",
+            "NLD",
+            dump_reader(),
+            "world",
+            country_table_stats,
+            |row| {
+                found_rows.push(row.to_string());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(found_rows.len(), 1);
+        assert!(found_rows[0].contains("'Netherlands'"));
+    }
+
+    #[test]
+    fn check_header_and_footer_dump() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Mysql, _>(dump_reader(), "world").unwrap();
+        let table_stats_values = table_stats.values().collect::<Vec<_>>();
+        let idx = first_footer_row_idx(&table_stats_values);
+
+        assert!(idx > 0);
+
+        let mut rows = vec![];
+        let _ = dump_header(dump_reader(), idx, |row| {
+            rows.push(row.to_string());
+        })
+        .unwrap();
+
+        assert_eq!(rows.iter().filter(|x| x.contains("INSERT INTO")).count(), 0);
+        assert_eq!(
+            rows.iter().filter(|x| x.contains("CREATE TABLE")).count(),
+            3
+        );
+        assert!(rows.iter().any(|x| x.contains("FOREIGN_KEY_CHECKS=0")));
+
+        let mut rows = vec![];
+        let _ = dump_footer(dump_reader(), idx, |row| {
+            rows.push(row.to_string());
+        })
+        .unwrap();
+
+        assert_eq!(rows.iter().filter(|x| x.contains("INSERT INTO")).count(), 0);
+        assert!(rows
+            .iter()
+            .any(|x| x.contains("FOREIGN_KEY_CHECKS=@OLD_FOREIGN_KEY_CHECKS")));
+    }
+
+    #[test]
+    fn check_mysql_subset() {
+        let path = dump_path();
+        let mut s = HashSet::new();
+        s.insert(PassthroughTable::new("world", "countrylanguage"));
+
+        let mysql_subset = MysqlSubset::new(
+            path.as_path(),
+            SubsetStrategy::random("world", "city", 10),
+            SubsetOptions::new(&s),
+        )
+        .unwrap();
+
+        let mut rows = vec![];
+        let mut total_rows_to_process = 0usize;
+        mysql_subset
+            .read(
+                |row| {
+                    rows.push(row);
+                },
+                |progress| {
+                    total_rows_to_process = progress.total_rows_to_process;
+                },
+            )
+            .unwrap();
+
+        let city_rows = rows
+            .iter()
+            .filter(|row| row.contains("INSERT INTO `city`"))
+            .count();
+
+        assert_eq!(city_rows, total_rows_to_process);
+        assert!(city_rows < 4079);
+
+        // the countries referenced by the cities
+        let country_rows = rows
+            .iter()
+            .filter(|row| row.contains("INSERT INTO `country`"))
+            .count();
+
+        assert!(country_rows > 0);
+        assert!(country_rows < 239);
+
+        // passthrough table
+        let country_language_rows = rows
+            .iter()
+            .filter(|row| row.contains("INSERT INTO `countrylanguage`"))
+            .count();
+
+        assert_eq!(country_language_rows, 984);
+    }
+
+    #[test]
+    fn check_mysql_subset_with_children() {
+        let path = dump_path();
+        let s = HashSet::new();
+
+        let count_rows = |children_max_depth: usize| {
+            let mysql_subset = MysqlSubset::new(
+                path.as_path(),
+                SubsetStrategy::predicate(
+                    "world",
+                    "country",
+                    "Code",
+                    vec![Predicate::Equal("NLD".to_string())],
+                ),
+                SubsetOptions::new(&s).with_children_max_depth(children_max_depth),
+            )
+            .unwrap();
+
+            let mut rows = vec![];
+            mysql_subset
+                .read(|row| rows.push(row), |_progress| {})
+                .unwrap();
+
+            let count = |table: &str| {
+                rows.iter()
+                    .filter(|x| x.contains(format!("INSERT INTO `{}` ", table).as_str()))
+                    .count()
+            };
+
+            (count("country"), count("city"), count("countrylanguage"))
+        };
+
+        assert_eq!(count_rows(0), (1, 0, 0));
+        assert_eq!(count_rows(1), (1, 28, 4));
+    }
+}
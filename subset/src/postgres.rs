@@ -1,572 +1,42 @@
-use crate::dedup::does_line_exist_and_set;
-use crate::postgres::SubsetStrategy::RandomPercent;
-use crate::{
-    utils, PassthroughTable, Progress, Subset, SubsetOptions, SubsetTable, SubsetTableRelation,
-};
+use crate::dialect::{Database, Dialect, DumpSubset, ForeignKey, Table};
 use dump_parser::postgres::{
     get_column_names_from_insert_into_query, get_column_values_str_from_insert_into_query,
-    get_tokens_from_query_str, get_word_value_at_position, match_keyword_at_position,
-    trim_pre_whitespaces, Keyword, Token,
+    get_tokens_from_query_str, get_word_value_at_position, match_keyword_at_position, Keyword,
+    Token,
 };
-use dump_parser::utils::{list_sql_queries_from_dump_reader, ListQueryResult};
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Error, ErrorKind, Read};
-use std::ops::Index;
-use std::path::Path;
-
-type Database = String;
-type Table = String;
-
-#[derive(Debug)]
-struct ForeignKey {
-    from_database: String,
-    from_table: String,
-    from_property: String,
-    to_database: String,
-    to_table: String,
-    to_property: String,
-}
-
-struct TableStats {
-    database: String,
-    table: String,
-    columns: Vec<String>,
-    total_rows: usize,
-    first_insert_into_row_index: usize,
-    last_insert_into_row_index: usize,
-}
-
-pub enum SubsetStrategy<'a> {
-    RandomPercent {
-        database: &'a str,
-        table: &'a str,
-        percent: u8,
-    },
-}
-
-impl<'a> SubsetStrategy<'a> {
-    pub fn random(database: &'a str, table: &'a str, percent: u8) -> Self {
-        RandomPercent {
-            database,
-            table,
-            percent,
-        }
-    }
-}
 
-pub struct PostgresSubset<'a> {
-    subset_table_by_database_and_table_name: HashMap<(Database, Table), SubsetTable>,
-    dump: &'a Path,
-    subset_strategy: SubsetStrategy<'a>,
-    subset_options: SubsetOptions<'a>,
-}
+/// Postgres dump made by pg_dump with `INSERT INTO ...` rows:
+/// the tables are qualified by their schema, and the foreign keys are added by `ALTER TABLE ...` statements.
+pub struct Postgres;
 
-impl<'a> PostgresSubset<'a> {
-    pub fn new(
-        dump: &'a Path,
-        subset_strategy: SubsetStrategy<'a>,
-        subset_options: SubsetOptions<'a>,
-    ) -> Result<Self, Error> {
-        Ok(PostgresSubset {
-            subset_table_by_database_and_table_name: get_subset_table_by_database_and_table_name(
-                BufReader::new(File::open(dump).unwrap()),
-            )?,
-            dump,
-            subset_strategy,
-            subset_options,
-        })
-    }
+pub type PostgresSubset<'a> = DumpSubset<'a, Postgres>;
 
-    fn dump_reader(&self) -> BufReader<File> {
-        BufReader::new(File::open(self.dump).unwrap())
-    }
+impl Dialect for Postgres {
+    type Tokens = Vec<Token>;
 
-    fn reference_rows(
-        &self,
-        table_stats: &HashMap<(Database, Table), TableStats>,
-    ) -> Result<Vec<String>, Error> {
-        match self.subset_strategy {
-            SubsetStrategy::RandomPercent {
-                database,
-                table,
-                percent,
-            } => Ok(list_percent_of_insert_into_rows(
-                percent,
-                table_stats
-                    .get(&(database.to_string(), table.to_string()))
-                    .unwrap(),
-                self.dump_reader(),
-            )?),
-        }
+    fn tokenize(query: &str) -> Vec<Token> {
+        get_tokens_from_query_str(query)
     }
 
-    fn visits<F: FnMut(String)>(
-        &self,
-        row: String,
-        table_stats: &HashMap<(Database, Table), TableStats>,
-        data: &mut F,
-    ) -> Result<(), Error> {
-        data(format!("{}\n", row));
-
-        // tokenize `INSERT INTO ...` row
-        let row_tokens = get_tokens_from_query_str(row.as_str());
-
-        // find the database and table names from this row
-        let (row_database, row_table) =
-            get_insert_into_database_and_table_name(&row_tokens).unwrap();
-
-        if self.subset_options.passthrough_tables.is_empty()
-            || !self
-                .subset_options
-                .passthrough_tables
-                .contains(&PassthroughTable::new(
-                    row_database.as_str(),
-                    row_table.as_str(),
-                ))
-        {
-            // only insert if the row is not from passthrough tables list
-            // otherwise we'll have duplicated rows
-            data(format!("{}\n", row));
-        }
-
-        // find the subset table from this row
-        let row_subset_table = self
-            .subset_table_by_database_and_table_name
-            .get(&(row_database.to_string(), row_table.to_string()))
-            .unwrap();
-
-        let row_column_names = get_column_names_from_insert_into_query(&row_tokens);
-        let row_column_values = get_column_values_str_from_insert_into_query(&row_tokens);
-
-        for row_relation in &row_subset_table.relations {
-            let column = row_relation.from_property.as_str();
-            // find the value from the current row for the relation column
-            let column_idx = row_column_names.iter().position(|x| *x == column).unwrap(); // FIXME unwrap
-            let value = row_column_values.get(column_idx).unwrap();
-
-            let database_and_table_tuple =
-                (row_relation.database.clone(), row_relation.table.clone());
-
-            // find the table stats for this row
-            let row_relation_table_stats = table_stats.get(&database_and_table_tuple).unwrap();
-
-            // TODO break acyclic graph
-            let row_clb = |row: &str| match self.visits(row.to_string(), table_stats, data) {
-                Ok(_) => {}
-                Err(err) => {
-                    panic!("{}", err);
-                }
-            };
-
-            let _ = filter_insert_into_rows(
-                row_relation.to_property.as_str(),
-                value.as_str(),
-                self.dump_reader(),
-                row_relation_table_stats,
-                row_clb,
-            )?;
-        }
-
-        Ok(())
+    fn create_table_name(tokens: &Vec<Token>, _database: &str) -> Option<(Database, Table)> {
+        get_create_table_database_and_table_name(tokens)
     }
-}
-
-impl<'a> Subset for PostgresSubset<'a> {
-    /// Return every subset rows
-    /// Algorithm used:
-    /// 1. find the reference table and take the X rows from this table with the appropriate SubsetStrategy
-    /// 2. iterate over each row and their relations (0 to N relations)
-    /// 3. for each rows from each relations, filter on the id from the parent related row id. (equivalent `SELECT * FROM table_1 INNER JOIN ... WHERE table_1.id = 'xxx';`
-    /// 4. do it recursively for table_1.relations[*].relations[*]... but the algo stops when reaching the end or reach a cyclic ref.
-    ///
-    /// Notes:
-    /// a. the algo must visits all the tables, even the one that has no relations.
-    fn read<F: FnMut(String), P: FnMut(Progress)>(
-        &self,
-        mut data: F,
-        mut progress: P,
-    ) -> Result<(), Error> {
-        let temp_dir = tempfile::tempdir()?;
-
-        let _ = read(
-            self,
-            |line| {
-                if line.contains("INSERT INTO") {
-                    // Dedup INSERT INTO queries
-                    // check if the line has not already been sent
-                    match does_line_exist_and_set(
-                        temp_dir.path(),
-                        &get_insert_into_md5_hash(line.as_str()),
-                        line.as_str(),
-                    ) {
-                        Ok(does_line_exist) => {
-                            if !does_line_exist {
-                                data(line);
-                            }
-                        }
-                        Err(err) => {
-                            panic!("{}", err);
-                        }
-                    }
-                } else {
-                    data(line);
-                }
-            },
-            progress,
-        )?;
 
-        Ok(())
+    fn insert_into_table_name(tokens: &Vec<Token>, _database: &str) -> Option<(Database, Table)> {
+        get_insert_into_database_and_table_name(tokens)
     }
-}
 
-fn read<F: FnMut(String), P: FnMut(Progress)>(
-    postgres_subset: &PostgresSubset,
-    mut data: F,
-    mut progress: P,
-) -> Result<(), Error> {
-    let table_stats = table_stats_by_database_and_table_name(postgres_subset.dump_reader())?;
-    let rows = postgres_subset.reference_rows(&table_stats)?;
-
-    // send schema header
-    let table_stats_values = table_stats.values().collect::<Vec<_>>();
-    let _ = dump_header(
-        postgres_subset.dump_reader(),
-        last_header_row_idx(&table_stats_values),
-        |row| {
-            data(row.to_string());
-        },
-    )?;
-
-    let total_rows = table_stats_values
-        .iter()
-        .fold(0usize, |acc, y| acc + y.total_rows);
-
-    let total_rows_to_process = rows.len();
-    let mut processed_rows = 0usize;
-
-    progress(Progress {
-        total_rows,
-        total_rows_to_process,
-        processed_rows,
-        last_process_time: 0,
-    });
-
-    // send INSERT INTO rows
-    for row in rows {
-        let start_time = utils::epoch_millis();
-        let _ = postgres_subset.visits(row, &table_stats, &mut data)?;
-
-        processed_rows += 1;
-
-        progress(Progress {
-            total_rows,
-            total_rows_to_process,
-            processed_rows,
-            last_process_time: utils::epoch_millis() - start_time,
-        });
+    fn insert_into_column_names(tokens: &Vec<Token>) -> Vec<String> {
+        get_column_names_from_insert_into_query(&trim_tokens(tokens, Keyword::Insert))
     }
 
-    for passthrough_table in postgres_subset.subset_options.passthrough_tables {
-        // copy all rows from passthrough tables
-        for table_stats in &table_stats_values {
-            if table_stats.database.as_str() == passthrough_table.database
-                && table_stats.table.as_str() == passthrough_table.table
-            {
-                let _ = list_insert_into_rows(postgres_subset.dump_reader(), table_stats, |row| {
-                    data(row.to_string());
-                })?;
-            }
-        }
+    fn insert_into_column_values(tokens: &Vec<Token>) -> Vec<String> {
+        get_column_values_str_from_insert_into_query(&trim_tokens(tokens, Keyword::Insert))
     }
 
-    // send schema footer
-    let _ = dump_footer(
-        postgres_subset.dump_reader(),
-        first_footer_row_idx(&table_stats_values),
-        |row| {
-            data(row.to_string());
-        },
-    )?;
-
-    Ok(())
-}
-
-fn get_insert_into_md5_hash(query: &str) -> String {
-    let tokens = get_tokens_from_query_str(query);
-    let tokens = trim_pre_whitespaces(tokens);
-    let database = get_word_value_at_position(&tokens, 4).unwrap();
-    let table = get_word_value_at_position(&tokens, 6).unwrap();
-    let key = format!("{}-{}", database, table);
-    let digest = md5::compute(key.as_bytes());
-    format!("{:x}", digest)
-}
-
-fn list_percent_of_insert_into_rows<R: Read>(
-    percent: u8,
-    table_stats: &TableStats,
-    dump_reader: BufReader<R>,
-) -> Result<Vec<String>, Error> {
-    let mut insert_into_rows = vec![];
-
-    if percent == 0 || table_stats.total_rows == 0 {
-        return Ok(insert_into_rows);
+    fn foreign_keys(tokens: &Vec<Token>, _database: &str) -> Vec<ForeignKey> {
+        get_alter_table_foreign_key(tokens).into_iter().collect()
     }
-
-    let percent = if percent > 100 { 100 } else { percent };
-
-    let total_rows_to_pick = table_stats.total_rows as f32 * percent as f32 / 100.0;
-    let modulo = (table_stats.total_rows as f32 / total_rows_to_pick) as usize;
-
-    let mut counter = 1usize;
-    let _ = list_insert_into_rows(dump_reader, table_stats, |rows| {
-        if counter % modulo == 0 {
-            insert_into_rows.push(rows.to_string());
-        }
-
-        counter += 1;
-    })?;
-
-    Ok(insert_into_rows)
-}
-
-fn list_insert_into_rows<R: Read, F: FnMut(&str)>(
-    dump_reader: BufReader<R>,
-    table_stats: &TableStats,
-    mut rows: F,
-) -> Result<(), Error> {
-    let mut query_idx = 0usize;
-    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
-        let mut query_res = ListQueryResult::Continue;
-
-        // optimization to avoid tokenizing unnecessary queries -- it's a 13x optim (benched)
-        if query_idx >= table_stats.first_insert_into_row_index
-            && query_idx <= table_stats.last_insert_into_row_index
-        {
-            let tokens = get_tokens_from_query_str(query);
-            let tokens = trim_tokens(&tokens, Keyword::Insert);
-
-            if match_keyword_at_position(Keyword::Insert, &tokens, 0)
-                && match_keyword_at_position(Keyword::Into, &tokens, 2)
-                && get_word_value_at_position(&tokens, 4) == Some(table_stats.database.as_str())
-                && get_word_value_at_position(&tokens, 6) == Some(table_stats.table.as_str())
-            {
-                rows(query.as_ref());
-            }
-        }
-
-        if query_idx > table_stats.last_insert_into_row_index {
-            // early break to avoid parsing the dump while we have already parsed all the table rows
-            query_res = ListQueryResult::Break;
-        }
-
-        query_idx += 1;
-        query_res
-    })?;
-
-    Ok(())
-}
-
-fn filter_insert_into_rows<R: Read, F: FnMut(&str)>(
-    column: &str,
-    value: &str,
-    dump_reader: BufReader<R>,
-    table_stats: &TableStats,
-    mut rows: F,
-) -> Result<(), Error> {
-    let column_idx = match table_stats
-        .columns
-        .iter()
-        .position(|r| r.as_str() == column)
-    {
-        Some(idx) => idx,
-        None => {
-            return Err(Error::new(
-                ErrorKind::Other,
-                format!(
-                    "table {} does not contain column {}",
-                    table_stats.table, column
-                ),
-            ));
-        }
-    };
-
-    let mut query_idx = 0usize;
-    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
-        let mut query_res = ListQueryResult::Continue;
-
-        // optimization to avoid tokenizing unnecessary queries -- it's a 13x optim (benched)
-        if query_idx >= table_stats.first_insert_into_row_index
-            && query_idx <= table_stats.last_insert_into_row_index
-        {
-            let tokens = get_tokens_from_query_str(query);
-            let tokens = trim_tokens(&tokens, Keyword::Insert);
-
-            if match_keyword_at_position(Keyword::Insert, &tokens, 0)
-                && match_keyword_at_position(Keyword::Into, &tokens, 2)
-                && get_word_value_at_position(&tokens, 4) == Some(table_stats.database.as_str())
-                && get_word_value_at_position(&tokens, 6) == Some(table_stats.table.as_str())
-            {
-                let column_values = get_column_values_str_from_insert_into_query(&tokens);
-
-                if *column_values.index(column_idx) == value {
-                    rows(query)
-                }
-            }
-        }
-
-        if query_idx > table_stats.last_insert_into_row_index {
-            // early break to avoid parsing the dump while we have already parsed all the table rows
-            query_res = ListQueryResult::Break;
-        }
-
-        query_idx += 1;
-        query_res
-    })?;
-
-    Ok(())
-}
-
-/// return the last row index from dump header (with generated table stats)
-fn last_header_row_idx(table_stats_values: &Vec<&TableStats>) -> usize {
-    table_stats_values
-        .iter()
-        .filter(|ts| ts.first_insert_into_row_index > 0) // first_insert_into_row_index can be equals to 0 if there is no INSERT INTO...
-        .min_by_key(|ts| ts.first_insert_into_row_index)
-        .map(|ts| ts.first_insert_into_row_index)
-        .unwrap()
-        - 1 // FIXME catch this even if it should not happen
-}
-
-/// return the first row index from dump header (with generated table stats)
-fn first_footer_row_idx(table_stats_values: &Vec<&TableStats>) -> usize {
-    table_stats_values
-        .iter()
-        .max_by_key(|ts| ts.last_insert_into_row_index)
-        .map(|ts| ts.last_insert_into_row_index)
-        .unwrap()
-        + 1 // FIXME catch this even if it should not happen
-}
-
-/// Get Postgres dump header - everything before the first `INSERT INTO ...` row
-/// pg_dump export dump data in 3 phases: `CREATE TABLE ...`, `INSERT INTO ...`, and `ALTER TABLE ...`.
-/// this function return all the `CREATE TABLE ...` rows.
-fn dump_header<R: Read, F: FnMut(&str)>(
-    dump_reader: BufReader<R>,
-    last_header_row_idx: usize,
-    mut rows: F,
-) -> Result<(), Error> {
-    let mut query_idx = 0usize;
-    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
-        let mut query_res = ListQueryResult::Continue;
-
-        if query_idx <= last_header_row_idx {
-            rows(query)
-        }
-
-        if query_idx > last_header_row_idx {
-            query_res = ListQueryResult::Break;
-        }
-
-        query_idx += 1;
-        query_res
-    })?;
-
-    Ok(())
-}
-
-/// Get Postgres dump footer - everything after the last `INSERT INTO ...` row
-/// pg_dump export dump data in 3 phases: `CREATE TABLE ...`, `INSERT INTO ...`, and `ALTER TABLE ...`.
-/// this function return all the `ALTER TABLE ...` rows.
-fn dump_footer<R: Read, F: FnMut(&str)>(
-    dump_reader: BufReader<R>,
-    first_footer_row_idx: usize,
-    mut rows: F,
-) -> Result<(), Error> {
-    let mut query_idx = 0usize;
-    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
-        if query_idx >= first_footer_row_idx {
-            rows(query)
-        }
-
-        query_idx += 1;
-        ListQueryResult::Continue
-    })?;
-
-    Ok(())
-}
-
-fn table_stats_by_database_and_table_name<R: Read>(
-    dump_reader: BufReader<R>,
-) -> Result<HashMap<(Database, Table), TableStats>, Error> {
-    let mut table_stats_by_database_and_table_name =
-        HashMap::<(Database, Table), TableStats>::new();
-
-    let mut query_idx = 0usize;
-    let _ = list_sql_queries_from_dump_reader(dump_reader, |query| {
-        let tokens = get_tokens_from_query_str(query);
-
-        let _ = match get_create_table_database_and_table_name(&tokens) {
-            Some((database, table)) => {
-                table_stats_by_database_and_table_name.insert(
-                    (database.clone(), table.clone()),
-                    TableStats {
-                        database,
-                        table,
-                        columns: vec![],
-                        total_rows: 0,
-                        first_insert_into_row_index: 0,
-                        last_insert_into_row_index: 0,
-                    },
-                );
-            }
-            None => {}
-        };
-
-        // remove potential whitespaces
-        let tokens = trim_tokens(&tokens, Keyword::Insert);
-
-        if match_keyword_at_position(Keyword::Insert, &tokens, 0)
-            && match_keyword_at_position(Keyword::Into, &tokens, 2)
-        {
-            if let Some(database) = get_word_value_at_position(&tokens, 4) {
-                if let Some(table) = get_word_value_at_position(&tokens, 6) {
-                    match table_stats_by_database_and_table_name
-                        .get_mut(&(database.to_string(), table.to_string()))
-                    {
-                        Some(table_stats) => {
-                            if table_stats.total_rows == 0 {
-                                // I assume that the INSERT INTO row has all the column set
-                                let columns = get_column_names_from_insert_into_query(&tokens)
-                                    .iter()
-                                    .map(|name| name.to_string())
-                                    .collect::<Vec<_>>();
-
-                                table_stats.columns = columns;
-                            }
-
-                            if table_stats.first_insert_into_row_index == 0 {
-                                table_stats.first_insert_into_row_index = query_idx;
-                            }
-
-                            table_stats.last_insert_into_row_index = query_idx;
-                            table_stats.total_rows += 1;
-                        }
-                        None => {
-                            // should not happen because INSERT INTO must come after CREATE TABLE
-                            println!("Query: {}", query);
-                            panic!("Unexpected: INSERT INTO happened before CREATE TABLE while creating table_stats structure")
-                        }
-                    }
-                }
-            }
-        }
-
-        query_idx += 1;
-        ListQueryResult::Continue
-    })?;
-
-    Ok(table_stats_by_database_and_table_name)
 }
 
 fn trim_tokens(tokens: &Vec<Token>, keyword: Keyword) -> Vec<Token> {
@@ -580,45 +50,6 @@ fn trim_tokens(tokens: &Vec<Token>, keyword: Keyword) -> Vec<Token> {
         .collect::<Vec<_>>()
 }
 
-fn get_subset_table_by_database_and_table_name<R: Read>(
-    dump_reader: BufReader<R>,
-) -> Result<HashMap<(Database, Table), SubsetTable>, Error> {
-    let mut subset_table_by_database_and_table_name =
-        HashMap::<(Database, Table), SubsetTable>::new();
-
-    list_sql_queries_from_dump_reader(dump_reader, |query| {
-        let tokens = get_tokens_from_query_str(query);
-
-        if let Some((database, table)) = get_create_table_database_and_table_name(&tokens) {
-            // add table into index
-            let _ = subset_table_by_database_and_table_name.insert(
-                (database.clone(), table.clone()),
-                SubsetTable::new(database, table, vec![]),
-            );
-        }
-
-        if let Some(fk) = get_alter_table_foreign_key(&tokens) {
-            let _ = match subset_table_by_database_and_table_name
-                .get_mut(&(fk.from_database, fk.from_table))
-            {
-                Some(subset_table) => {
-                    subset_table.relations.push(SubsetTableRelation::new(
-                        fk.to_database,
-                        fk.to_table,
-                        fk.from_property,
-                        fk.to_property,
-                    ));
-                }
-                None => {} // FIXME
-            };
-        }
-
-        ListQueryResult::Continue
-    })?;
-
-    Ok(subset_table_by_database_and_table_name)
-}
-
 fn get_create_table_database_and_table_name(tokens: &Vec<Token>) -> Option<(Database, Table)> {
     let tokens = trim_tokens(&tokens, Keyword::Create);
 
@@ -735,14 +166,18 @@ fn get_alter_table_foreign_key(tokens: &Vec<Token>) -> Option<ForeignKey> {
 
 #[cfg(test)]
 mod tests {
-    use crate::postgres::{
+    use crate::dialect::{
         dump_footer, dump_header, filter_insert_into_rows, first_footer_row_idx,
-        get_alter_table_foreign_key, get_create_table_database_and_table_name,
-        get_subset_table_by_database_and_table_name, last_header_row_idx,
-        list_percent_of_insert_into_rows, table_stats_by_database_and_table_name, PostgresSubset,
-        SubsetStrategy,
+        get_subset_table_by_database_and_table_name, list_predicate_insert_into_rows,
+        list_random_insert_into_rows, table_stats_by_database_and_table_name,
+    };
+    use crate::postgres::{
+        get_alter_table_foreign_key, get_create_table_database_and_table_name, Postgres,
+        PostgresSubset,
     };
-    use crate::{PassthroughTable, Subset, SubsetOptions};
+    use crate::predicate::Predicate;
+    use crate::utils::percent_of_rows;
+    use crate::{PassthroughTable, Subset, SubsetOptions, SubsetStrategy};
     use dump_parser::postgres::Tokenizer;
     use std::collections::HashSet;
     use std::fs::File;
@@ -802,7 +237,8 @@ ALTER TABLE ONLY public.territories
 
     #[test]
     fn check_subset_table() {
-        let m = get_subset_table_by_database_and_table_name(dump_reader()).unwrap();
+        let m = get_subset_table_by_database_and_table_name::<Postgres, _>(dump_reader(), "public")
+            .unwrap();
         assert!(m.len() > 0);
 
         let t = m
@@ -833,35 +269,126 @@ ALTER TABLE ONLY public.territories
 
     #[test]
     fn check_table_stats() {
-        let table_stats = table_stats_by_database_and_table_name(dump_reader()).unwrap();
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
         assert!(table_stats.len() > 0);
         // TODO add more tests to check table.rows size
     }
 
     #[test]
-    fn check_percent_of_rows() {
-        let table_stats = table_stats_by_database_and_table_name(dump_reader()).unwrap();
+    fn check_random_rows() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
         let first_table_stats = table_stats
             .get(&("public".to_string(), "order_details".to_string()))
             .unwrap();
 
-        let rows = list_percent_of_insert_into_rows(5, first_table_stats, dump_reader()).unwrap();
+        let rows = list_random_insert_into_rows::<Postgres, _>(
+            percent_of_rows(first_table_stats.total_rows, 5),
+            None,
+            first_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .unwrap();
+
+        assert!(rows.len() < first_table_stats.total_rows);
+
+        let rows = list_random_insert_into_rows::<Postgres, _>(
+            10,
+            Some(42),
+            first_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .unwrap();
 
-        assert!(rows.len() < first_table_stats.total_rows)
+        assert_eq!(rows.len(), 10);
+        assert_eq!(
+            rows,
+            list_random_insert_into_rows::<Postgres, _>(
+                10,
+                Some(42),
+                first_table_stats,
+                dump_reader(),
+                "public"
+            )
+            .unwrap()
+        );
+
+        let rows = list_random_insert_into_rows::<Postgres, _>(
+            first_table_stats.total_rows + 1,
+            Some(42),
+            first_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), first_table_stats.total_rows);
+    }
+
+    #[test]
+    fn check_predicate_of_rows() {
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
+        let orders_table_stats = table_stats
+            .get(&("public".to_string(), "orders".to_string()))
+            .unwrap();
+
+        let rows = list_predicate_insert_into_rows::<Postgres, _>(
+            "order_id",
+            &vec![Predicate::In(vec![
+                "10248".to_string(),
+                "10250".to_string(),
+                "99999".to_string(),
+            ])],
+            orders_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 2);
+
+        let rows = list_predicate_insert_into_rows::<Postgres, _>(
+            "order_date",
+            &vec![
+                Predicate::GreaterThanOrEqual("1998-04-01".to_string()),
+                Predicate::LessThan("1998-05-01".to_string()),
+            ],
+            orders_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 74);
+
+        assert!(list_predicate_insert_into_rows::<Postgres, _>(
+            "unknown_column",
+            &vec![Predicate::Equal("1".to_string())],
+            orders_table_stats,
+            dump_reader(),
+            "public",
+        )
+        .is_err());
     }
 
     #[test]
     fn check_filter_insert_into_rows() {
-        let table_stats = table_stats_by_database_and_table_name(dump_reader()).unwrap();
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
         let first_table_stats = table_stats
             .get(&("public".to_string(), "order_details".to_string()))
             .unwrap();
 
         let mut found_rows = vec![];
-        filter_insert_into_rows(
+        filter_insert_into_rows::<Postgres, _, _>(
             "product_id",
             "11",
             dump_reader(),
+            "public",
             first_table_stats,
             |row| {
                 found_rows.push(row.to_string());
@@ -874,12 +401,13 @@ ALTER TABLE ONLY public.territories
 
     #[test]
     fn check_header_dump() {
-        let table_stats = table_stats_by_database_and_table_name(dump_reader()).unwrap();
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
 
         assert!(!table_stats.is_empty());
 
         let table_stats_values = table_stats.values().collect::<Vec<_>>();
-        let idx = last_header_row_idx(&table_stats_values);
+        let idx = first_footer_row_idx(&table_stats_values);
 
         assert!(idx > 0);
 
@@ -895,7 +423,8 @@ ALTER TABLE ONLY public.territories
 
     #[test]
     fn check_footer_dump() {
-        let table_stats = table_stats_by_database_and_table_name(dump_reader()).unwrap();
+        let table_stats =
+            table_stats_by_database_and_table_name::<Postgres, _>(dump_reader(), "public").unwrap();
 
         assert!(!table_stats.is_empty());
 
@@ -973,4 +502,41 @@ ALTER TABLE ONLY public.territories
             51
         );
     }
+
+    #[test]
+    fn check_postgres_subset_with_children() {
+        let path = dump_path();
+        let s = HashSet::new();
+
+        let count_rows = |children_max_depth: usize| {
+            let postgres_subset = PostgresSubset::new(
+                path.as_path(),
+                SubsetStrategy::predicate(
+                    "public",
+                    "customers",
+                    "customer_id",
+                    vec![Predicate::Equal("VINET".to_string())],
+                ),
+                SubsetOptions::new(&s).with_children_max_depth(children_max_depth),
+            )
+            .unwrap();
+
+            let mut rows = vec![];
+            postgres_subset
+                .read(|row| rows.push(row), |_progress| {})
+                .unwrap();
+
+            let count = |table: &str| {
+                rows.iter()
+                    .filter(|x| x.contains(format!("INSERT INTO public.{} ", table).as_str()))
+                    .count()
+            };
+
+            (count("customers"), count("orders"), count("order_details"))
+        };
+
+        assert_eq!(count_rows(0), (1, 0, 0));
+        assert_eq!(count_rows(1), (1, 5, 0));
+        assert_eq!(count_rows(2), (1, 5, 10));
+    }
 }
@@ -0,0 +1,277 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use std::cmp::Ordering;
+
+const NULL_VALUE: &str = "NULL";
+
+/// Condition on a column value of an `INSERT INTO ...` row.
+/// Values are compared as numbers, then as booleans, as dates, and then as strings.
+/// Dates can be relative to the current time, e.g. `now`, `now-90d`, `now-12h`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    Equal(String),
+    NotEqual(String),
+    In(Vec<String>),
+    GreaterThan(String),
+    GreaterThanOrEqual(String),
+    LessThan(String),
+    LessThanOrEqual(String),
+}
+
+impl Predicate {
+    pub fn matches(&self, value: &str) -> bool {
+        if value == NULL_VALUE {
+            // NULL never matches - as in SQL
+            return false;
+        }
+
+        match self {
+            Predicate::Equal(expected) => compare(value, expected) == Ordering::Equal,
+            Predicate::NotEqual(expected) => compare(value, expected) != Ordering::Equal,
+            Predicate::In(expected_values) => expected_values
+                .iter()
+                .any(|expected| compare(value, expected) == Ordering::Equal),
+            Predicate::GreaterThan(expected) => compare(value, expected) == Ordering::Greater,
+            Predicate::GreaterThanOrEqual(expected) => {
+                compare(value, expected) != Ordering::Less
+            }
+            Predicate::LessThan(expected) => compare(value, expected) == Ordering::Less,
+            Predicate::LessThanOrEqual(expected) => compare(value, expected) != Ordering::Greater,
+        }
+    }
+
+    /// the same predicate with its relative dates computed from `now` - so the same rows match all along a dump
+    pub fn at(&self, now: NaiveDateTime) -> Predicate {
+        let at = |expected: &String| match expected.trim().strip_prefix("now") {
+            Some(relative) => match parse_relative_datetime(relative.trim(), now) {
+                Some(datetime) => datetime.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+                None => expected.clone(),
+            },
+            None => expected.clone(),
+        };
+
+        match self {
+            Predicate::Equal(expected) => Predicate::Equal(at(expected)),
+            Predicate::NotEqual(expected) => Predicate::NotEqual(at(expected)),
+            Predicate::In(expected_values) => {
+                Predicate::In(expected_values.iter().map(at).collect())
+            }
+            Predicate::GreaterThan(expected) => Predicate::GreaterThan(at(expected)),
+            Predicate::GreaterThanOrEqual(expected) => Predicate::GreaterThanOrEqual(at(expected)),
+            Predicate::LessThan(expected) => Predicate::LessThan(at(expected)),
+            Predicate::LessThanOrEqual(expected) => Predicate::LessThanOrEqual(at(expected)),
+        }
+    }
+}
+
+/// return true if the value matches all the predicates
+pub fn matches_all(predicates: &[Predicate], value: &str) -> bool {
+    predicates.iter().all(|predicate| predicate.matches(value))
+}
+
+fn compare(value: &str, expected: &str) -> Ordering {
+    // integers are compared as they are - large ids are rounded as floats
+    if let (Ok(value), Ok(expected)) = (value.parse::<i128>(), expected.parse::<i128>()) {
+        return value.cmp(&expected);
+    }
+
+    if let (Ok(value), Ok(expected)) = (value.parse::<f64>(), expected.parse::<f64>()) {
+        if let Some(ordering) = value.partial_cmp(&expected) {
+            return ordering;
+        }
+    }
+
+    if let (Some(value), Some(expected)) = (parse_boolean(value), parse_boolean(expected)) {
+        return value.cmp(&expected);
+    }
+
+    if let (Some(value), Some(expected)) = (parse_datetime(value), parse_datetime(expected)) {
+        return value.cmp(&expected);
+    }
+
+    value.cmp(expected)
+}
+
+/// parse a boolean from the formats used by database dumps - `t` and `f` for PostgreSQL COPY blocks, `1` and `0` for MySQL
+fn parse_boolean(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "t" | "true" | "1" => Some(true),
+        "f" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// parse a date (in UTC) from the formats used by database dumps
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+
+    if let Some(relative) = value.strip_prefix("now") {
+        return parse_relative_datetime(relative.trim(), Utc::now().naive_utc());
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.naive_utc());
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%dT%H:%M:%S%.f%#z"] {
+        if let Ok(datetime) = DateTime::parse_from_str(value, format) {
+            return Some(datetime.naive_utc());
+        }
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(datetime);
+        }
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.and_hms(0, 0, 0))
+}
+
+/// parse `[+-]<number><unit>` relative to now - units are `s`, `m`, `h`, `d` and `w`
+fn parse_relative_datetime(relative: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    if relative.is_empty() {
+        return Some(now);
+    }
+
+    let (sign, relative) = match relative.split_at(1) {
+        ("+", relative) => (1, relative.trim()),
+        ("-", relative) => (-1, relative.trim()),
+        _ => return None,
+    };
+
+    if relative.len() < 2 {
+        return None;
+    }
+
+    let (amount, unit) = relative.split_at(relative.len() - 1);
+    let amount = sign * amount.trim().parse::<i64>().ok()?;
+
+    let duration = match unit {
+        "s" => Duration::seconds(amount),
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        "w" => Duration::weeks(amount),
+        _ => return None,
+    };
+
+    now.checked_add_signed(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::predicate::{matches_all, parse_datetime, Predicate};
+    use chrono::{Duration, NaiveDate, Utc};
+
+    #[test]
+    fn equality() {
+        assert!(Predicate::Equal("42".to_string()).matches("42"));
+        assert!(Predicate::Equal("42".to_string()).matches("42.0"));
+        assert!(Predicate::Equal("FRA".to_string()).matches("FRA"));
+        assert!(!Predicate::Equal("FRA".to_string()).matches("NLD"));
+        assert!(!Predicate::Equal("NULL".to_string()).matches("NULL"));
+        assert!(Predicate::NotEqual("FRA".to_string()).matches("NLD"));
+        assert!(!Predicate::NotEqual("FRA".to_string()).matches("NULL"));
+    }
+
+    #[test]
+    fn in_list() {
+        let p = Predicate::In(vec!["1".to_string(), "2".to_string(), "10".to_string()]);
+        assert!(p.matches("1"));
+        assert!(p.matches("10"));
+        assert!(!p.matches("3"));
+        assert!(!Predicate::In(vec![]).matches("1"));
+    }
+
+    #[test]
+    fn ranges() {
+        let range = vec![
+            Predicate::GreaterThanOrEqual("10".to_string()),
+            Predicate::LessThan("20".to_string()),
+        ];
+
+        assert!(!matches_all(&range, "9"));
+        assert!(matches_all(&range, "10"));
+        assert!(matches_all(&range, "19.5"));
+        assert!(!matches_all(&range, "20"));
+        // numbers are not compared as strings
+        assert!(!matches_all(&range, "100"));
+
+        // large integers are not rounded
+        assert!(!Predicate::Equal("9007199254740993".to_string()).matches("9007199254740992"));
+        assert!(Predicate::GreaterThan("9007199254740992".to_string()).matches("9007199254740993"));
+        assert!(Predicate::Equal("9007199254740993".to_string()).matches("9007199254740993"));
+
+        assert!(Predicate::GreaterThan("b".to_string()).matches("c"));
+        assert!(Predicate::LessThanOrEqual("b".to_string()).matches("b"));
+    }
+
+    #[test]
+    fn booleans() {
+        assert!(Predicate::Equal("false".to_string()).matches("f"));
+        assert!(Predicate::Equal("false".to_string()).matches("0"));
+        assert!(Predicate::Equal("true".to_string()).matches("TRUE"));
+        assert!(!Predicate::Equal("true".to_string()).matches("f"));
+        assert!(Predicate::NotEqual("t".to_string()).matches("false"));
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(
+            parse_datetime("2022-04-01"),
+            Some(NaiveDate::from_ymd(2022, 4, 1).and_hms(0, 0, 0))
+        );
+        assert_eq!(
+            parse_datetime("2022-04-01 10:30:00"),
+            Some(NaiveDate::from_ymd(2022, 4, 1).and_hms(10, 30, 0))
+        );
+        assert_eq!(
+            parse_datetime("2022-04-01 10:30:00.123+02"),
+            Some(NaiveDate::from_ymd(2022, 4, 1).and_hms_milli(8, 30, 0, 123))
+        );
+        assert_eq!(
+            parse_datetime("2022-04-01T10:30:00Z"),
+            Some(NaiveDate::from_ymd(2022, 4, 1).and_hms(10, 30, 0))
+        );
+        assert_eq!(parse_datetime("not a date"), None);
+        assert_eq!(parse_datetime("now-90x"), None);
+
+        let p = Predicate::GreaterThanOrEqual("2022-04-01".to_string());
+        assert!(p.matches("2022-04-01 00:00:00+00"));
+        assert!(p.matches("2022-12-01"));
+        assert!(!p.matches("2022-03-31 23:59:59"));
+
+        let last_90_days = Predicate::GreaterThanOrEqual("now-90d".to_string());
+        let today = Utc::now().naive_utc();
+        let fmt = "%Y-%m-%d %H:%M:%S";
+
+        assert!(last_90_days.matches(today.format(fmt).to_string().as_str()));
+        assert!(last_90_days.matches(
+            (today - Duration::days(89))
+                .format(fmt)
+                .to_string()
+                .as_str()
+        ));
+        assert!(!last_90_days.matches(
+            (today - Duration::days(91))
+                .format(fmt)
+                .to_string()
+                .as_str()
+        ));
+
+        // the relative dates are computed once
+        let start = NaiveDate::from_ymd(2022, 4, 1).and_hms(10, 30, 0);
+        assert_eq!(
+            last_90_days.at(start),
+            Predicate::GreaterThanOrEqual("2022-01-01 10:30:00".to_string())
+        );
+        assert_eq!(
+            Predicate::In(vec!["now".to_string(), "FRA".to_string()]).at(start),
+            Predicate::In(vec!["2022-04-01 10:30:00".to_string(), "FRA".to_string()])
+        );
+        assert!(last_90_days.at(start).matches("2022-01-02"));
+        assert!(!last_90_days.at(start).matches("2021-12-31"));
+    }
+}
@@ -1,3 +1,6 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn epoch_millis() -> u128 {
@@ -6,3 +9,56 @@ pub fn epoch_millis() -> u128 {
         .unwrap()
         .as_millis()
 }
+
+/// number of rows representing `percent` of `total_rows` - at least 1 row is picked from a non empty table
+pub fn percent_of_rows(total_rows: usize, percent: u8) -> usize {
+    if percent == 0 || total_rows == 0 {
+        return 0;
+    }
+
+    let percent = if percent > 100 { 100 } else { percent };
+    let rows = (total_rows as f64 * percent as f64 / 100.0).ceil() as usize;
+
+    rows.max(1).min(total_rows)
+}
+
+/// pick `rows` distinct row indexes between 0 and `total_rows` (excluded)
+/// the same seed always picks the same indexes - a random seed is used otherwise
+pub fn random_row_indexes(total_rows: usize, rows: usize, seed: Option<u64>) -> HashSet<usize> {
+    let mut rng = match seed {
+        Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+        None => ChaCha8Rng::from_entropy(),
+    };
+
+    rand::seq::index::sample(&mut rng, total_rows, rows.min(total_rows))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{percent_of_rows, random_row_indexes};
+
+    #[test]
+    fn check_percent_of_rows() {
+        assert_eq!(percent_of_rows(0, 50), 0);
+        assert_eq!(percent_of_rows(100, 0), 0);
+        assert_eq!(percent_of_rows(100, 10), 10);
+        assert_eq!(percent_of_rows(100, 200), 100);
+        // tiny tables are not empty
+        assert_eq!(percent_of_rows(5, 10), 1);
+    }
+
+    #[test]
+    fn check_random_row_indexes() {
+        let indexes = random_row_indexes(1000, 50, Some(42));
+        assert_eq!(indexes.len(), 50);
+        assert!(indexes.iter().all(|idx| *idx < 1000));
+        assert_eq!(indexes, random_row_indexes(1000, 50, Some(42)));
+        assert_ne!(indexes, random_row_indexes(1000, 50, Some(43)));
+
+        assert_eq!(random_row_indexes(10, 50, Some(42)).len(), 10);
+        assert_eq!(random_row_indexes(0, 50, None).len(), 0);
+        assert_eq!(random_row_indexes(1000, 50, None).len(), 50);
+    }
+}